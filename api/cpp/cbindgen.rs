@@ -121,6 +121,7 @@ fn gen_corelib(
         "ClippedImage",
         "TouchArea",
         "FocusScope",
+        "Shortcut",
         "Flickable",
         "Text",
         "Path",
@@ -171,6 +172,8 @@ fn gen_corelib(
         "GraphicsAPI",
         "CloseRequestResponse",
         "StandardListViewItem",
+        "StandardTreeViewItem",
+        "StandardMenuItem",
         "Rgb8Pixel",
         "Rgba8Pixel",
     ];
@@ -182,6 +185,8 @@ fn gen_corelib(
         "ImageCacheKey",
         "Image",
         "Color",
+        "HsvaColor",
+        "HslaColor",
         "PathData",
         "PathElement",
         "Brush",
@@ -194,6 +199,7 @@ fn gen_corelib(
         "slint_property_listener_scope_evaluate",
         "slint_property_listener_scope_is_dirty",
         "PropertyTrackerOpaque",
+        "ChangeTrackerOpaque",
         "CallbackOpaque",
         "WindowAdapterRc",
         "VoidArg",
@@ -206,6 +212,10 @@ fn gen_corelib(
         "slint_color_transparentize",
         "slint_color_mix",
         "slint_color_with_alpha",
+        "slint_color_from_hsva",
+        "slint_color_from_hsla",
+        "slint_color_to_hsva",
+        "slint_color_to_hsla",
         "slint_image_size",
         "slint_image_path",
         "slint_image_load_from_path",
@@ -292,10 +302,14 @@ fn gen_corelib(
             "namespace slint::cbindgen_private { struct ParsedSVG{}; struct HTMLImage{}; using namespace vtable; }",
         ),
         (
-            vec!["Color", "slint_color_brighter", "slint_color_darker",
+            vec!["Color", "HsvaColor", "HslaColor", "slint_color_brighter", "slint_color_darker",
             "slint_color_transparentize",
             "slint_color_mix",
-            "slint_color_with_alpha",],
+            "slint_color_with_alpha",
+            "slint_color_from_hsva",
+            "slint_color_from_hsla",
+            "slint_color_to_hsva",
+            "slint_color_to_hsla",],
             vec![],
             "slint_color_internal.h",
             "",
@@ -332,6 +346,7 @@ fn gen_corelib(
             "slint_windowrc_set_component",
             "slint_windowrc_show_popup",
             "slint_windowrc_close_popup",
+            "slint_windowrc_show_tooltip",
             "slint_windowrc_set_rendering_notifier",
             "slint_windowrc_request_redraw",
             "slint_windowrc_on_close_requested",
@@ -351,6 +366,10 @@ fn gen_corelib(
             "slint_color_transparentize",
             "slint_color_mix",
             "slint_color_with_alpha",
+        "slint_color_from_hsva",
+        "slint_color_from_hsla",
+        "slint_color_to_hsva",
+        "slint_color_to_hsla",
             "slint_image_size",
             "slint_image_path",
             "slint_image_load_from_path",
@@ -414,6 +433,14 @@ fn gen_corelib(
         "StandardListViewItem".to_owned(),
         "/// \\private\nfriend bool operator==(const StandardListViewItem&, const StandardListViewItem&) = default;".into(),
     );
+    public_config.export.body.insert(
+        "StandardTreeViewItem".to_owned(),
+        "/// \\private\nfriend bool operator==(const StandardTreeViewItem&, const StandardTreeViewItem&) = default;".into(),
+    );
+    public_config.export.body.insert(
+        "StandardMenuItem".to_owned(),
+        "/// \\private\nfriend bool operator==(const StandardMenuItem&, const StandardMenuItem&) = default;".into(),
+    );
     public_config.export.body.insert(
         "Rgb8Pixel".to_owned(),
         "/// \\private\nfriend bool operator==(const Rgb8Pixel&, const Rgb8Pixel&) = default;"
@@ -484,6 +511,14 @@ fn gen_corelib(
         .body
         .insert("Flickable".to_owned(), "    inline Flickable(); inline ~Flickable();".into());
     config.export.pre_body.insert("FlickableDataBox".to_owned(), "struct FlickableData;".into());
+    config
+        .export
+        .body
+        .insert("TextInput".to_owned(), "    inline TextInput(); inline ~TextInput();".into());
+    config
+        .export
+        .pre_body
+        .insert("TextInputUndoHistoryBox".to_owned(), "struct TextInputUndoHistory;".into());
     config.export.include.push("TableColumn".into());
     cbindgen::Builder::new()
         .with_config(config)
@@ -549,6 +584,10 @@ fn gen_backend_qt(
         "NativeComboBoxPopup",
         "NativeTabWidget",
         "NativeTab",
+        "NativeMenuFrame",
+        "NativeMenuItem",
+        "NativeMenuBarItem",
+        "NativeToolTip",
         "NativeStyleMetrics",
     ];
 
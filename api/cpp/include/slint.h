@@ -169,7 +169,18 @@ public:
                                                     &parent_item);
     }
 
-    void close_popup() const { cbindgen_private::slint_windowrc_close_popup(&inner); }
+    template<typename Component, typename Parent>
+    void show_tooltip(const Parent *parent_component, cbindgen_private::Point p,
+                      cbindgen_private::ItemRc parent_item) const
+    {
+        auto tooltip = Component::create(parent_component).into_dyn();
+        cbindgen_private::slint_windowrc_show_tooltip(&inner, &tooltip, p, &parent_item);
+    }
+
+    void close_popup(cbindgen_private::ItemRc parent_item) const
+    {
+        cbindgen_private::slint_windowrc_close_popup(&inner, &parent_item);
+    }
 
     template<std::invocable<RenderingState, GraphicsAPI> F>
     std::optional<SetRenderingNotifierError> set_rendering_notifier(F callback) const
@@ -575,6 +586,11 @@ struct Timer
     void restart() { cbindgen_private::slint_timer_restart(id); }
     /// Returns true if the timer is running; false otherwise.
     bool running() const { return cbindgen_private::slint_timer_running(id); }
+    /// Returns the interval of the timer. Zero if the timer was never started.
+    std::chrono::milliseconds interval() const
+    {
+        return std::chrono::milliseconds(cbindgen_private::slint_timer_interval(id));
+    }
 
     /// Call the callback after the given duration.
     template<std::invocable F>
@@ -1227,11 +1243,12 @@ struct SortModelInner : private_api::ModelChangeListener
         for (size_t i = 0; i < sorted_rows.size(); ++i)
             sorted_rows[i] = i;
 
-        std::sort(sorted_rows.begin(), sorted_rows.end(), [this](auto lhs_index, auto rhs_index) {
-            auto lhs_elem = source_model->row_data(lhs_index);
-            auto rhs_elem = source_model->row_data(rhs_index);
-            return comp(*lhs_elem, *rhs_elem);
-        });
+        std::stable_sort(sorted_rows.begin(), sorted_rows.end(),
+                         [this](auto lhs_index, auto rhs_index) {
+                             auto lhs_elem = source_model->row_data(lhs_index);
+                             auto rhs_elem = source_model->row_data(rhs_index);
+                             return comp(*lhs_elem, *rhs_elem);
+                         });
 
         sorted_rows_dirty = false;
     }
@@ -1294,6 +1311,142 @@ private:
     std::shared_ptr<private_api::SortModelInner<ModelData>> inner;
 };
 
+/// A tree of items, that can be shown in a `StandardTreeView` through a FlattenedTreeModel.
+///
+/// The nodes are identified by their path: the index of each ancestor among its siblings,
+/// followed by the index of the node itself. The empty path is the invisible root of the tree,
+/// its children are the top-level items.
+class TreeModel
+{
+public:
+    virtual ~TreeModel() = default;
+
+    /// Returns the number of children of the node at \a path.
+    ///
+    /// This is only called for the root, and for the nodes that are expanded, so the children can
+    /// be loaded lazily.
+    virtual size_t child_count(std::span<const size_t> path) const = 0;
+
+    /// Returns the text of the node at \a path.
+    virtual SharedString text(std::span<const size_t> path) const = 0;
+
+    /// Returns whether the node at \a path has children, and can be expanded.
+    ///
+    /// The default implementation calls child_count(). Re-implement it if loading the children is
+    /// expensive and should only happen when the node is expanded.
+    virtual bool has_children(std::span<const size_t> path) const { return child_count(path) > 0; }
+};
+
+/// The FlattenedTreeModel provides the rows of a TreeModel for a `StandardTreeView`: the
+/// top-level items, followed by the children of each expanded item.
+///
+/// The children of an item are only loaded from the tree when the item is expanded, with
+/// expand(), or when the `StandardTreeView` sets the `expanded` field of its row. Collapsing an
+/// item forgets its children, so they are loaded again when it is expanded again.
+class FlattenedTreeModel : public Model<StandardTreeViewItem>
+{
+public:
+    /// Constructs a new FlattenedTreeModel showing the top-level items of \a tree, all
+    /// collapsed.
+    FlattenedTreeModel(std::shared_ptr<TreeModel> tree) : m_tree(std::move(tree))
+    {
+        load_top_level_rows();
+    }
+
+    size_t row_count() const override { return rows.size(); }
+
+    std::optional<StandardTreeViewItem> row_data(size_t i) const override
+    {
+        if (i >= rows.size())
+            return {};
+        const auto &row = rows[i];
+        return StandardTreeViewItem { m_tree->text(row.path), int32_t(row.path.size()) - 1,
+                                      m_tree->has_children(row.path), row.expanded };
+    }
+
+    /// Expands or collapses the node at row \a i according to the `expanded` field of \a value.
+    /// The other fields are given by the tree, and are ignored.
+    void set_row_data(size_t i, const StandardTreeViewItem &value) override
+    {
+        if (value.expanded) {
+            expand(i);
+        } else {
+            collapse(i);
+        }
+    }
+
+    /// Shows the children of the node at \a row, after loading them from the tree.
+    void expand(size_t row)
+    {
+        if (row >= rows.size() || rows[row].expanded || !m_tree->has_children(rows[row].path))
+            return;
+        auto path = rows[row].path;
+        auto count = m_tree->child_count(path);
+        rows[row].expanded = true;
+        std::vector<Row> children(count);
+        for (size_t i = 0; i < count; ++i) {
+            children[i].path = path;
+            children[i].path.push_back(i);
+        }
+        rows.insert(rows.begin() + row + 1, children.begin(), children.end());
+        this->row_changed(row);
+        if (count > 0)
+            this->row_added(row + 1, count);
+    }
+
+    /// Hides the children of the node at \a row, and of all its descendants.
+    void collapse(size_t row)
+    {
+        if (row >= rows.size() || !rows[row].expanded)
+            return;
+        rows[row].expanded = false;
+        auto level = rows[row].path.size();
+        auto end = std::find_if(rows.begin() + row + 1, rows.end(),
+                                [&](const Row &r) { return r.path.size() <= level; });
+        size_t count = end - (rows.begin() + row + 1);
+        rows.erase(rows.begin() + row + 1, end);
+        this->row_changed(row);
+        if (count > 0)
+            this->row_removed(row + 1, count);
+    }
+
+    /// Reloads the top-level items from the tree, collapsing all items. Call this when the tree
+    /// has changed.
+    void reset()
+    {
+        load_top_level_rows();
+        Model<StandardTreeViewItem>::reset();
+    }
+
+    /// Returns the path in the tree of the node shown at \a row.
+    std::vector<size_t> path(size_t row) const
+    {
+        return row < rows.size() ? rows[row].path : std::vector<size_t> {};
+    }
+
+    /// Returns the tree of this model.
+    std::shared_ptr<TreeModel> tree() const { return m_tree; }
+
+private:
+    struct Row
+    {
+        std::vector<size_t> path;
+        bool expanded = false;
+    };
+
+    void load_top_level_rows()
+    {
+        rows.clear();
+        auto count = m_tree->child_count({});
+        for (size_t i = 0; i < count; ++i) {
+            rows.push_back(Row { { i }, false });
+        }
+    }
+
+    std::shared_ptr<TreeModel> m_tree;
+    std::vector<Row> rows;
+};
+
 namespace private_api {
 
 template<typename C, typename ModelData>
@@ -1481,6 +1634,118 @@ inline SharedString translate(const SharedString &original, const SharedString &
     return result;
 }
 
+inline std::shared_ptr<Model<SharedString>> string_split(const SharedString &s,
+                                                        const SharedString &separator)
+{
+    std::vector<SharedString> result;
+    std::string_view str = s;
+    std::string_view sep = separator;
+    if (sep.empty()) {
+        // split into the utf-8 encoded characters
+        for (size_t pos = 0; pos < str.size();) {
+            size_t len = 1;
+            while (pos + len < str.size()
+                   && (static_cast<unsigned char>(str[pos + len]) & 0xc0) == 0x80)
+                ++len;
+            result.emplace_back(str.substr(pos, len));
+            pos += len;
+        }
+    } else {
+        size_t pos = 0;
+        for (auto next = str.find(sep); next != std::string_view::npos; next = str.find(sep, pos)) {
+            result.emplace_back(str.substr(pos, next - pos));
+            pos = next + sep.size();
+        }
+        result.emplace_back(str.substr(pos));
+    }
+    return std::make_shared<VectorModel<SharedString>>(std::move(result));
+}
+
+/// Returns all the rows of the model, registering a dependency to the row count and to the data
+/// of each row in the current binding.
+template<typename M>
+auto array_tracked_rows(const M &model)
+{
+    std::vector<std::decay_t<decltype(*model->row_data(0))>> result;
+    if (!model) {
+        return result;
+    }
+    model->track_row_count_changes();
+    for (size_t row = 0; row < model->row_count(); ++row) {
+        if (auto data = model->row_data_tracked(row)) {
+            result.push_back(std::move(*data));
+        }
+    }
+    return result;
+}
+
+/// Registers a dependency to the row count and to the data of each row in the current binding,
+/// without reading the rows. The adapter models returned by the array functions have their own
+/// tracker, which is gone when the adapter is only used temporarily within the binding.
+template<typename M>
+void array_track_rows(const M &model)
+{
+    model->track_row_count_changes();
+    for (size_t row = 0; row < model->row_count(); ++row) {
+        model->track_row_data_changes(row);
+    }
+}
+
+template<typename M, typename F>
+auto array_filter(const M &model, F predicate)
+{
+    using T = std::decay_t<decltype(*model->row_data(0))>;
+    if (!model) {
+        return std::shared_ptr<Model<T>>();
+    }
+    array_track_rows(model);
+    return std::shared_ptr<Model<T>>(
+            std::make_shared<FilterModel<T>>(model, std::move(predicate)));
+}
+
+template<typename U, typename M, typename F>
+std::shared_ptr<Model<U>> array_map(const M &model, F map_function)
+{
+    using T = std::decay_t<decltype(*model->row_data(0))>;
+    if (!model) {
+        return nullptr;
+    }
+    array_track_rows(model);
+    return std::make_shared<MapModel<T, U>>(model, std::move(map_function));
+}
+
+template<typename M, typename F>
+auto array_sort_by(const M &model, F key_function)
+{
+    using T = std::decay_t<decltype(*model->row_data(0))>;
+    if (!model) {
+        return std::shared_ptr<Model<T>>();
+    }
+    array_track_rows(model);
+    return std::shared_ptr<Model<T>>(std::make_shared<SortModel<T>>(
+            model, [key_function = std::move(key_function)](const T &a, const T &b) {
+                return key_function(a) < key_function(b);
+            }));
+}
+
+template<typename M, typename F>
+int array_find_index(const M &model, F predicate)
+{
+    auto rows = array_tracked_rows(model);
+    auto it = std::find_if(rows.begin(), rows.end(), predicate);
+    return it == rows.end() ? -1 : int(it - rows.begin());
+}
+
+/// There is no adapter model to reverse a model, so the rows are copied into a VectorModel
+template<typename M>
+auto array_reverse(const M &model)
+{
+    using T = std::decay_t<decltype(*model->row_data(0))>;
+    auto rows = array_tracked_rows(model);
+    std::reverse(rows.begin(), rows.end());
+    return std::shared_ptr<Model<T>>(std::make_shared<VectorModel<T>>(std::move(rows)));
+}
+
 } // namespace private_api
 
 #if !defined(DOXYGEN)
@@ -1493,6 +1758,15 @@ cbindgen_private::Flickable::~Flickable()
     slint_flickable_data_free(&data);
 }
 
+cbindgen_private::TextInput::TextInput()
+{
+    slint_textinput_undo_history_init(&undo_history);
+}
+cbindgen_private::TextInput::~TextInput()
+{
+    slint_textinput_undo_history_free(&undo_history);
+}
+
 cbindgen_private::NativeStyleMetrics::NativeStyleMetrics(void *)
 {
     slint_native_style_metrics_init(this);
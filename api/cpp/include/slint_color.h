@@ -37,6 +37,16 @@ struct RgbaColor
     RgbaColor(const Color &col);
 };
 
+/// HsvaColor stores the hue, saturation, value and alpha components of a color in the
+/// HSV color space. The hue is in degrees between 0 and 360, the other components are
+/// normalized between 0 and 1.
+using HsvaColor = cbindgen_private::types::HsvaColor;
+
+/// HslaColor stores the hue, saturation, lightness and alpha components of a color in the
+/// HSL color space. The hue is in degrees between 0 and 360, the other components are
+/// normalized between 0 and 1.
+using HslaColor = cbindgen_private::types::HslaColor;
+
 /// Color represents a color in the Slint run-time, represented using 8-bit channels for
 /// red, green, blue and the alpha (opacity).
 class Color
@@ -116,6 +126,22 @@ public:
         return Color::from_argb_float(1.0, red, green, blue);
     }
 
+    /// Construct a color from the hue, saturation, value and alpha components of the HSV color
+    /// space. The hue is in degrees, the other components are between 0 and 1.
+    [[nodiscard]] inline static Color from_hsva(float hue, float saturation, float value,
+                                                float alpha);
+
+    /// Construct a color from the hue, saturation, lightness and alpha components of the HSL
+    /// color space. The hue is in degrees, the other components are between 0 and 1.
+    [[nodiscard]] inline static Color from_hsla(float hue, float saturation, float lightness,
+                                                float alpha);
+
+    /// Converts this color to the HSV color space.
+    inline HsvaColor to_hsva() const;
+
+    /// Converts this color to the HSL color space.
+    inline HslaColor to_hsla() const;
+
     /// Converts this color to an RgbaColor struct for easy destructuring.
     inline RgbaColor<uint8_t> to_argb_uint() const;
 
@@ -188,6 +214,35 @@ private:
     friend class Brush;
 };
 
+inline Color Color::from_hsva(float hue, float saturation, float value, float alpha)
+{
+    Color result;
+    cbindgen_private::types::slint_color_from_hsva(hue, saturation, value, alpha, &result.inner);
+    return result;
+}
+
+inline Color Color::from_hsla(float hue, float saturation, float lightness, float alpha)
+{
+    Color result;
+    cbindgen_private::types::slint_color_from_hsla(hue, saturation, lightness, alpha,
+                                                   &result.inner);
+    return result;
+}
+
+inline HsvaColor Color::to_hsva() const
+{
+    HsvaColor result;
+    cbindgen_private::types::slint_color_to_hsva(&inner, &result);
+    return result;
+}
+
+inline HslaColor Color::to_hsla() const
+{
+    HslaColor result;
+    cbindgen_private::types::slint_color_to_hsla(&inner, &result);
+    return result;
+}
+
 inline Color Color::brighter(float factor) const
 {
     Color result;
@@ -4,6 +4,7 @@
 #pragma once
 #include <string_view>
 #include <memory>
+#include <optional>
 
 namespace slint::cbindgen_private {
 struct PropertyAnimation;
@@ -292,4 +293,47 @@ private:
     cbindgen_private::PropertyTrackerOpaque inner;
 };
 
+/// ChangeTracker calls a handler when the value returned by an expression changes.
+/// The handler is not called right away, but later from the event loop, once the bindings
+/// have settled.
+struct ChangeTracker
+{
+    /// Constructs a new change tracker instance, which doesn't track anything yet.
+    ChangeTracker() { cbindgen_private::slint_change_tracker_construct(&inner); }
+    /// Destroys the change tracker.
+    ~ChangeTracker() { cbindgen_private::slint_change_tracker_drop(&inner); }
+    /// The copy constructor is intentionally deleted, change trackers cannot be copied.
+    ChangeTracker(const ChangeTracker &) = delete;
+    /// The assignment operator is intentionally deleted, change trackers cannot be copied.
+    ChangeTracker &operator=(const ChangeTracker &) = delete;
+
+    /// Starts tracking the value returned by \a eval, and calls \a notify when it changes.
+    /// \a eval is invoked right away to get the initial value.
+    template<typename FEval, typename FNotify>
+    void init(FEval eval, FNotify notify) const
+    {
+        using Value = decltype(eval());
+        struct Data
+        {
+            FEval eval;
+            FNotify notify;
+            std::optional<Value> value;
+        };
+        cbindgen_private::slint_change_tracker_init(
+                &inner, new Data { std::move(eval), std::move(notify), std::nullopt },
+                [](void *data) { delete reinterpret_cast<Data *>(data); },
+                [](void *data) -> bool {
+                    auto d = reinterpret_cast<Data *>(data);
+                    auto new_value = d->eval();
+                    bool changed = d->value.has_value() && !(*d->value == new_value);
+                    d->value = std::move(new_value);
+                    return changed;
+                },
+                [](void *data) { reinterpret_cast<Data *>(data)->notify(); });
+    }
+
+private:
+    cbindgen_private::ChangeTrackerOpaque inner;
+};
+
 } // namespace slint::private_api
@@ -223,6 +223,59 @@ inline cbindgen_private::Slice<uint8_t> string_to_slice(std::string_view str)
         const_cast<unsigned char *>(reinterpret_cast<const unsigned char *>(str.data())), str.size()
     };
 }
+
+inline SharedString string_to_uppercase(const SharedString &s)
+{
+    SharedString result;
+    cbindgen_private::slint_shared_string_to_uppercase(&result, &s);
+    return result;
+}
+
+inline SharedString string_to_lowercase(const SharedString &s)
+{
+    SharedString result;
+    cbindgen_private::slint_shared_string_to_lowercase(&result, &s);
+    return result;
+}
+
+inline SharedString string_trim(const SharedString &s)
+{
+    SharedString result;
+    cbindgen_private::slint_shared_string_trim(&result, &s);
+    return result;
+}
+
+inline bool string_contains(std::string_view s, std::string_view needle)
+{
+    return s.find(needle) != std::string_view::npos;
+}
+
+inline int string_character_count(const SharedString &s)
+{
+    return int(cbindgen_private::slint_shared_string_character_count(&s));
+}
+
+inline SharedString string_substring(const SharedString &s, int start, int end)
+{
+    SharedString result;
+    cbindgen_private::slint_shared_string_substring(&result, &s, start, end);
+    return result;
+}
+
+inline SharedString string_replace(const SharedString &s, const SharedString &from,
+                                   const SharedString &to)
+{
+    SharedString result;
+    cbindgen_private::slint_shared_string_replace(&result, &s, &from, &to);
+    return result;
+}
+
+inline SharedString number_to_fixed(double n, int precision)
+{
+    SharedString result;
+    cbindgen_private::slint_shared_string_from_number_fixed(&result, n, precision);
+    return result;
+}
 }
 
 }
@@ -399,3 +399,61 @@ SCENARIO("Sorted Model Change")
     REQUIRE(sorted_model->row_data(2) == 2);
     REQUIRE(sorted_model->row_data(3) == 3);
 }
+
+SCENARIO("Flattened Tree Model")
+{
+    struct Tree : slint::TreeModel
+    {
+        size_t child_count(std::span<const size_t> path) const override
+        {
+            if (path.empty())
+                return 2;
+            if (path.size() == 1 && path[0] == 0)
+                return 2;
+            return 0;
+        }
+        slint::SharedString text(std::span<const size_t> path) const override
+        {
+            std::string text = "n";
+            for (auto i : path)
+                text += std::to_string(i);
+            return slint::SharedString(text);
+        }
+    };
+
+    auto tree_model = std::make_shared<slint::FlattenedTreeModel>(std::make_shared<Tree>());
+
+    auto observer = std::make_shared<ModelObserver>();
+    tree_model->attach_peer(observer);
+
+    REQUIRE(tree_model->row_count() == 2);
+    REQUIRE(tree_model->row_data(0)->text == "n0");
+    REQUIRE(tree_model->row_data(0)->has_children);
+    REQUIRE(!tree_model->row_data(1)->has_children);
+
+    tree_model->expand(0);
+    REQUIRE(observer->added_rows.size() == 1);
+    REQUIRE(observer->added_rows[0] == ModelObserver::Range { 1, 2 });
+    REQUIRE(observer->changed_rows.size() == 1);
+    REQUIRE(observer->changed_rows[0] == 0);
+    observer->clear();
+
+    REQUIRE(tree_model->row_count() == 4);
+    REQUIRE(tree_model->row_data(0)->expanded);
+    REQUIRE(tree_model->row_data(2)->text == "n01");
+    REQUIRE(tree_model->row_data(2)->level == 1);
+    REQUIRE(tree_model->row_data(3)->text == "n1");
+    REQUIRE(tree_model->path(2) == std::vector<size_t> { 0, 1 });
+
+    // Setting the expanded field, as the StandardTreeView does, collapses the item
+    auto item = *tree_model->row_data(0);
+    item.expanded = false;
+    tree_model->set_row_data(0, item);
+    REQUIRE(observer->removed_rows.size() == 1);
+    REQUIRE(observer->removed_rows[0] == ModelObserver::Range { 1, 2 });
+    observer->clear();
+
+    REQUIRE(tree_model->row_count() == 2);
+    REQUIRE(!tree_model->row_data(0)->expanded);
+    REQUIRE(tree_model->row_data(1)->text == "n1");
+}
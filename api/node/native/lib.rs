@@ -224,6 +224,13 @@ fn to_eval_value<'cx>(
                     .collect::<Result<_, _>>()?,
             ))
         }
+        Type::Optional(inner) => {
+            if val.is_a::<JsNull>() || val.is_a::<JsUndefined>() {
+                Ok(Value::None)
+            } else {
+                to_eval_value(val, *inner, cx, persistent_context)
+            }
+        }
         Type::Enumeration(_) => todo!(),
         Type::Invalid
         | Type::Void
@@ -247,6 +254,7 @@ fn to_js_value<'cx>(
     use slint_interpreter::Value;
     Ok(match val {
         Value::Void => JsUndefined::new().as_value(cx),
+        Value::None => JsNull::new().as_value(cx),
         Value::Number(n) => JsNumber::new(cx, n).as_value(cx),
         Value::String(s) => JsString::new(cx, s.as_str()).as_value(cx),
         Value::Bool(b) => JsBoolean::new(cx, b).as_value(cx),
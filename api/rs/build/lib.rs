@@ -101,6 +101,17 @@ pub fn with_include_paths(self, include_paths: Vec<std::path::PathBuf>) -> Self
         Self { config }
     }
 
+    /// Create a new configuration that keeps the type names and ids of the elements in the
+    /// generated code, so that they can be found with the `slint::testing` API.
+    ///
+    /// This is also enabled when the `SLINT_EMIT_DEBUG_INFO` environment variable is set.
+    #[must_use]
+    pub fn with_debug_info(self, enable: bool) -> Self {
+        let mut config = self.config;
+        config.debug_info = enable;
+        Self { config }
+    }
+
     /// Create a new configuration that selects the style to be used for widgets.
     #[must_use]
     pub fn with_style(self, style: String) -> Self {
@@ -268,11 +268,13 @@ struct MyStruct {
 
 pub use i_slint_core::api::*;
 pub use i_slint_core::graphics::{
-    Brush, Color, Image, LoadImageError, Rgb8Pixel, Rgba8Pixel, RgbaColor, SharedPixelBuffer,
+    Brush, Color, HslaColor, HsvaColor, Image, LoadImageError, Rgb8Pixel, Rgba8Pixel, RgbaColor,
+    SharedPixelBuffer,
 };
 pub use i_slint_core::model::{
-    FilterModel, MapModel, Model, ModelExt, ModelNotify, ModelPeer, ModelRc, ModelTracker,
-    SortModel, StandardListViewItem, TableColumn, VecModel,
+    FilterModel, FlattenedTreeModel, MapModel, Model, ModelExt, ModelNotify, ModelPeer, ModelRc,
+    ModelTracker, SortModel, StandardListViewItem, StandardMenuItem, StandardTreeViewItem,
+    TableColumn, TreeModel, VecModel,
 };
 pub use i_slint_core::sharedvector::SharedVector;
 pub use i_slint_core::timers::{Timer, TimerMode};
@@ -347,6 +349,49 @@ pub mod platform {
     pub use i_slint_core::platform::*;
 }
 
+/// This module contains functions to render windows headless and compare them with reference
+/// images, as well as to find elements and interact with them, for use in tests.
+///
+/// Call [`testing::init()`] at the beginning of the test, before creating any component. The windows are
+/// then not shown on the screen, and [`testing::take_snapshot()`] renders their current contents with the
+/// software renderer. With the `software-renderer-systemfonts` feature, call `testing::init_with_font_metrics()`
+/// instead to lay out the text with the real fonts.
+///
+/// ```rust,no_run
+/// slint::slint!{ export component App inherits Window { width: 100px; height: 100px; } }
+/// slint::testing::init();
+/// let app = App::new().unwrap();
+/// let snapshot = slint::testing::take_snapshot(app.window()).unwrap();
+/// slint::testing::compare_with_reference_png(&snapshot, "tests/references/app.png", 2).unwrap();
+/// ```
+///
+/// [`testing::ElementHandle`] finds the elements of a component by id, type name, or accessibility
+/// properties, and simulates clicks and key presses on them. Finding elements by id or type name
+/// requires the .slint code to be compiled with debug info, see
+/// [`slint_build::CompilerConfiguration::with_debug_info()`](https://docs.rs/slint-build/latest/slint_build/struct.CompilerConfiguration.html#method.with_debug_info).
+/// With the [`slint!`] macro, set the `SLINT_EMIT_DEBUG_INFO` environment variable when compiling.
+///
+/// ```rust,no_run
+/// slint::slint!{
+///     import { Button } from "std-widgets.slint";
+///     export component App inherits Window { in-out property <int> count; Button { text: "Ok"; clicked => { count += 1; } } }
+/// }
+/// slint::testing::init();
+/// let app = App::new().unwrap();
+/// let button = slint::testing::ElementHandle::find_by_accessible_label(&app, "Ok");
+/// button[0].click();
+/// assert_eq!(app.get_count(), 1);
+/// ```
+#[cfg(feature = "testing")]
+pub mod testing {
+    #[cfg(feature = "software-renderer-systemfonts")]
+    pub use i_slint_backend_testing::init_with_font_metrics;
+    pub use i_slint_backend_testing::{
+        compare_with_reference_png, init, take_snapshot, AccessibleRole, ElementHandle,
+        ImageComparisonError,
+    };
+}
+
 /// Helper type that helps checking that the generated code is generated for the right version
 #[doc(hidden)]
 #[allow(non_camel_case_types)]
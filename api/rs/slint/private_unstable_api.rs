@@ -198,8 +198,12 @@ pub mod re_exports {
     pub use i_slint_core::layout::*;
     pub use i_slint_core::lengths::{logical_position_to_api, LogicalLength, LogicalPoint};
     pub use i_slint_core::model::*;
-    pub use i_slint_core::properties::{set_state_binding, Property, PropertyTracker, StateInfo};
+    pub use i_slint_core::properties::{
+        set_state_binding, ChangeTracker, Property, PropertyTracker, StateInfo,
+    };
     pub use i_slint_core::slice::Slice;
+    pub use i_slint_core::string;
+    pub use i_slint_core::timers::{Timer, TimerMode};
     pub use i_slint_core::window::{InputMethodRequest, WindowAdapter, WindowInner};
     pub use i_slint_core::Color;
     pub use i_slint_core::ComponentVTable_static;
@@ -0,0 +1,41 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-1.0 OR LicenseRef-Slint-commercial
+
+use slint::platform::{PointerEventButton, WindowEvent};
+use slint::{LogicalPosition, LogicalSize};
+
+slint::slint! {
+    export component TestCase inherits Window {
+        width: 300px;
+        height: 200px;
+        out property <length> narrow-width: narrow.preferred-width;
+        out property <length> wide-width: wide.preferred-width;
+        out property <int> cursor <=> input.cursor-position-byte-offset;
+        narrow := Text { text: "iiii"; font-size: 20px; }
+        wide := Text { text: "WWWW"; font-size: 20px; }
+        input := TextInput {
+            y: 100px;
+            height: 100px;
+            font-size: 20px;
+            text: "first line\nsecond line";
+        }
+    }
+}
+
+#[test]
+fn text_with_font_metrics() {
+    slint::testing::init_with_font_metrics();
+    let ui = TestCase::new().unwrap();
+    ui.window().set_size(LogicalSize::new(300., 200.));
+
+    // Each glyph has its own advance
+    assert!(ui.get_narrow_width() < ui.get_wide_width());
+    assert!(ui.get_narrow_width() > 0.);
+
+    // Clicking on the second line places the cursor in the second line
+    let position = LogicalPosition::new(2., 100. + 35.);
+    let button = PointerEventButton::Left;
+    ui.window().dispatch_event(WindowEvent::PointerPressed { position, button });
+    ui.window().dispatch_event(WindowEvent::PointerReleased { position, button });
+    assert_eq!(ui.get_cursor(), "first line\n".len() as i32);
+}
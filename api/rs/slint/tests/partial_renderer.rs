@@ -190,3 +190,137 @@ fn if_condition() {
     }));
     assert!(!window.draw_if_needed(|_| { unreachable!() }));
 }
+
+#[test]
+fn box_shadow() {
+    slint::slint! {
+        export component Ui inherits Window {
+            in property <length> offset: 10phx;
+            background: black;
+            Rectangle {
+                x: 20phx;
+                y: 20phx;
+                width: 30phx;
+                height: 30phx;
+                background: red;
+                drop-shadow-offset-x: offset;
+                drop-shadow-blur: 5phx;
+                drop-shadow-color: blue;
+            }
+        }
+    }
+
+    slint::platform::set_platform(Box::new(TestPlatform)).ok();
+    let ui = Ui::new().unwrap();
+    let window = WINDOW.with(|x| x.clone());
+    window.set_size(slint::PhysicalSize::new(180, 260));
+    ui.show().unwrap();
+    assert!(window.draw_if_needed(|renderer| {
+        do_test_render_region(renderer, 0, 0, 180, 260);
+    }));
+    assert!(!window.draw_if_needed(|_| { unreachable!() }));
+    ui.set_offset(20.);
+    // The dirty region must contain the old and the new shadow, which extend beyond the
+    // geometry by the offset and the blur (plus one pixel for rounding)
+    assert!(window.draw_if_needed(|renderer| {
+        do_test_render_region(renderer, 20, 20 - 6, 20 + 30 + 20 + 6, 20 + 30 + 6);
+    }));
+    assert!(!window.draw_if_needed(|_| { unreachable!() }));
+}
+
+#[test]
+fn rotation() {
+    slint::slint! {
+        export component Ui inherits Window {
+            in property <angle> angle: 0deg;
+            in property <color> tint: transparent;
+            background: black;
+            Image {
+                x: 50phx;
+                y: 50phx;
+                width: 40phx;
+                height: 20phx;
+                rotation-angle: angle;
+                colorize: tint;
+            }
+        }
+    }
+
+    slint::platform::set_platform(Box::new(TestPlatform)).ok();
+    let ui = Ui::new().unwrap();
+    let window = WINDOW.with(|x| x.clone());
+    window.set_size(slint::PhysicalSize::new(180, 260));
+    ui.show().unwrap();
+    assert!(window.draw_if_needed(|renderer| {
+        do_test_render_region(renderer, 0, 0, 180, 260);
+    }));
+    assert!(!window.draw_if_needed(|_| { unreachable!() }));
+    // The image is rotated around its center (70, 60): the dirty region must contain both the
+    // unrotated and the rotated image
+    ui.set_angle(90.);
+    assert!(window.draw_if_needed(|renderer| {
+        do_test_render_region(renderer, 50, 40, 90, 80);
+    }));
+    assert!(!window.draw_if_needed(|_| { unreachable!() }));
+    // A change of the image itself is in the rotated coordinates
+    ui.set_tint(slint::Color::from_rgb_u8(45, 12, 13));
+    assert!(window.draw_if_needed(|renderer| {
+        do_test_render_region(renderer, 60, 40, 80, 80);
+    }));
+    assert!(!window.draw_if_needed(|_| { unreachable!() }));
+}
+
+#[test]
+fn layer() {
+    slint::slint! {
+        export component Ui inherits Window {
+            in property <color> c: yellow;
+            in property <color> inner: blue;
+            background: black;
+            Rectangle {
+                x: 10phx;
+                y: 10phx;
+                width: 50phx;
+                height: 50phx;
+                cache-rendering-hint: true;
+                background: red;
+                Rectangle {
+                    x: 20phx;
+                    y: 20phx;
+                    width: 10phx;
+                    height: 10phx;
+                    background: inner;
+                }
+            }
+            Rectangle {
+                x: 100phx;
+                y: 120phx;
+                width: 15phx;
+                height: 17phx;
+                background: c;
+            }
+        }
+    }
+
+    slint::platform::set_platform(Box::new(TestPlatform)).ok();
+    let ui = Ui::new().unwrap();
+    let window = WINDOW.with(|x| x.clone());
+    window.set_size(slint::PhysicalSize::new(180, 260));
+    ui.show().unwrap();
+    assert!(window.draw_if_needed(|renderer| {
+        do_test_render_region(renderer, 0, 0, 180, 260);
+    }));
+    // The children of the layer must not be considered dirty when nothing changed
+    assert!(!window.draw_if_needed(|_| { unreachable!() }));
+    ui.set_c(slint::Color::from_rgb_u8(45, 12, 13));
+    assert!(window.draw_if_needed(|renderer| {
+        do_test_render_region(renderer, 100, 120, 100 + 15, 120 + 17);
+    }));
+    assert!(!window.draw_if_needed(|_| { unreachable!() }));
+    // A change in a child of the layer invalidates the layer
+    ui.set_inner(slint::Color::from_rgb_u8(45, 12, 13));
+    assert!(window.draw_if_needed(|renderer| {
+        do_test_render_region(renderer, 10, 10, 10 + 50, 10 + 50);
+    }));
+    assert!(!window.draw_if_needed(|_| { unreachable!() }));
+}
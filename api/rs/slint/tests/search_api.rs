@@ -0,0 +1,59 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-1.0 OR LicenseRef-Slint-commercial
+
+use i_slint_backend_testing::{AccessibleRole, ElementHandle};
+
+slint::slint! {
+    export component TestCase inherits Window {
+        width: 100px;
+        height: 100px;
+        in-out property <int> clicked;
+        in-out property <string> typed <=> input.text;
+        Rectangle {
+            x: 10px;
+            y: 20px;
+            width: 30px;
+            height: 10px;
+            accessible-role: button;
+            accessible-label: "Press";
+            TouchArea {
+                clicked => { root.clicked += 1; }
+            }
+        }
+        input := TextInput {
+            y: 50px;
+            height: 20px;
+            accessible-role: text;
+            accessible-label: "Input";
+            accessible-value: self.text;
+        }
+    }
+}
+
+#[test]
+fn find_and_interact() {
+    i_slint_backend_testing::init();
+    let ui = TestCase::new().unwrap();
+
+    let buttons = ElementHandle::find_by_accessible_role(&ui, AccessibleRole::Button);
+    assert_eq!(buttons.len(), 1);
+    let button = &buttons[0];
+    assert_eq!(button.accessible_label().as_deref(), Some("Press"));
+    assert_eq!(button.absolute_position(), slint::LogicalPosition::new(10., 20.));
+    assert_eq!(button.size(), slint::LogicalSize::new(30., 10.));
+    button.click();
+    button.click();
+    assert_eq!(ui.get_clicked(), 2);
+
+    assert!(ElementHandle::find_by_accessible_label(&ui, "Nothing").is_empty());
+    let input = ElementHandle::find_by_accessible_label(&ui, "Input");
+    assert_eq!(input.len(), 1);
+    assert_eq!(input[0].accessible_role(), Some(AccessibleRole::Text));
+    input[0].type_text("Hello");
+    assert_eq!(ui.get_typed(), "Hello");
+    assert_eq!(input[0].accessible_value().as_deref(), Some("Hello"));
+
+    drop(ui);
+    assert!(!button.is_valid());
+    assert_eq!(button.accessible_label(), None);
+}
@@ -0,0 +1,56 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-1.0 OR LicenseRef-Slint-commercial
+
+use slint::testing::{compare_with_reference_png, take_snapshot, ImageComparisonError};
+use slint::Rgba8Pixel;
+
+slint::slint! {
+    export component TestCase inherits Window {
+        width: 40px;
+        height: 30px;
+        background: blue;
+        in property <color> fill: red;
+        Rectangle {
+            x: 10px;
+            y: 10px;
+            width: 20px;
+            height: 10px;
+            background: fill;
+        }
+    }
+}
+
+#[test]
+fn snapshot_and_compare() {
+    slint::testing::init();
+    let ui = TestCase::new().unwrap();
+
+    let snapshot = take_snapshot(ui.window()).unwrap();
+    assert_eq!((snapshot.width(), snapshot.height()), (40, 30));
+    let pixel = |x: u32, y: u32| snapshot.as_slice()[(y * snapshot.width() + x) as usize];
+    assert_eq!(pixel(0, 0), Rgba8Pixel { r: 0, g: 0, b: 0xff, a: 0xff });
+    assert_eq!(pixel(15, 15), Rgba8Pixel { r: 0xff, g: 0, b: 0, a: 0xff });
+
+    let reference = std::env::temp_dir().join(format!("slint-snapshot-{}.png", std::process::id()));
+    image::save_buffer(&reference, snapshot.as_bytes(), 40, 30, image::ColorType::Rgba8).unwrap();
+    compare_with_reference_png(&snapshot, &reference, 0).unwrap();
+
+    // The snapshot reflects the changes
+    ui.set_fill(slint::Color::from_rgb_u8(0xf8, 0, 0));
+    let snapshot = take_snapshot(ui.window()).unwrap();
+    compare_with_reference_png(&snapshot, &reference, 8).unwrap();
+    match compare_with_reference_png(&snapshot, &reference, 2) {
+        Err(ImageComparisonError::PixelMismatch { count, max_difference, first }) => {
+            assert_eq!(count, 200);
+            assert_eq!(max_difference, 7);
+            assert_eq!(first, (10, 10));
+        }
+        r => panic!("unexpected comparison result {r:?}"),
+    }
+
+    std::fs::remove_file(&reference).unwrap();
+    assert!(matches!(
+        compare_with_reference_png(&snapshot, &reference, 0),
+        Err(ImageComparisonError::ReferenceNotLoaded(_))
+    ));
+}
@@ -84,7 +84,11 @@ pub extern "C" fn slint_qt_get_widget(
     (qt_widgets::NativeComboBoxPopup,
     (qt_widgets::NativeTabWidget,
     (qt_widgets::NativeTab,
-            ()))))))))))))));
+    (qt_widgets::NativeMenuFrame,
+    (qt_widgets::NativeMenuItem,
+    (qt_widgets::NativeMenuBarItem,
+    (qt_widgets::NativeToolTip,
+            ()))))))))))))))))));
 
 #[cfg(not(no_qt))]
 #[rustfmt::skip]
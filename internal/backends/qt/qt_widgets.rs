@@ -146,6 +146,7 @@ pub fn draw(&self, callback: &mut dyn FnMut(u32, u32, &[u8])) {
     #include <QtWidgets/QStyle>
     #include <QtWidgets/QStyleOption>
     #include <QtWidgets/QStyleFactory>
+    #include <QtWidgets/QToolTip>
     #include <QtGui/QPainter>
     #include <QtGui/QClipboard>
     #include <QtCore/QMimeData>
@@ -206,9 +207,15 @@ pub fn draw(&self, callback: &mut dyn FnMut(u32, u32, &[u8])) {
 mod combobox;
 pub use combobox::*;
 
+mod menu;
+pub use menu::*;
+
 mod tabwidget;
 pub use tabwidget::*;
 
+mod tooltip;
+pub use tooltip::*;
+
 mod stylemetrics;
 pub use stylemetrics::*;
 
@@ -0,0 +1,388 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-1.0 OR LicenseRef-Slint-commercial
+
+use i_slint_core::input::FocusEventResult;
+
+use super::*;
+
+#[repr(C)]
+#[derive(FieldOffsets, Default, SlintElement)]
+#[pin]
+pub struct NativeMenuFrame {
+    pub x: Property<LogicalLength>,
+    pub y: Property<LogicalLength>,
+    pub width: Property<LogicalLength>,
+    pub height: Property<LogicalLength>,
+    pub cached_rendering_data: CachedRenderingData,
+}
+
+impl Item for NativeMenuFrame {
+    fn init(self: Pin<&Self>) {}
+
+    fn geometry(self: Pin<&Self>) -> LogicalRect {
+        LogicalRect::new(
+            LogicalPoint::from_lengths(self.x(), self.y()),
+            LogicalSize::from_lengths(self.width(), self.height()),
+        )
+    }
+
+    fn layout_info(
+        self: Pin<&Self>,
+        _orientation: Orientation,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+    ) -> LayoutInfo {
+        Default::default()
+    }
+
+    fn input_event_filter_before_children(
+        self: Pin<&Self>,
+        _: MouseEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> InputEventFilterResult {
+        InputEventFilterResult::ForwardAndIgnore
+    }
+
+    fn input_event(
+        self: Pin<&Self>,
+        _: MouseEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &i_slint_core::items::ItemRc,
+    ) -> InputEventResult {
+        InputEventResult::EventIgnored
+    }
+
+    fn key_event(
+        self: Pin<&Self>,
+        _: &KeyEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> KeyEventResult {
+        KeyEventResult::EventIgnored
+    }
+
+    fn focus_event(
+        self: Pin<&Self>,
+        _: &FocusEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> FocusEventResult {
+        FocusEventResult::FocusIgnored
+    }
+
+    fn_render! { _this dpr size painter widget initial_state =>
+        cpp!(unsafe [
+            painter as "QPainterPtr*",
+            widget as "QWidget*",
+            size as "QSize",
+            dpr as "float",
+            initial_state as "int"
+        ] {
+            ensure_initialized();
+            QStyleOptionFrame option;
+            option.state |= QStyle::State(initial_state);
+            option.rect = QRect(QPoint(), size / dpr);
+            option.state |= QStyle::State_Enabled;
+            option.lineWidth = qApp->style()->pixelMetric(QStyle::PM_MenuPanelWidth, &option, widget);
+            option.midLineWidth = 0;
+
+            painter->get()->fillRect(option.rect, option.palette.window());
+            qApp->style()->drawPrimitive(QStyle::PE_PanelMenu, &option, painter->get(), widget);
+            qApp->style()->drawPrimitive(QStyle::PE_FrameMenu, &option, painter->get(), widget);
+        });
+    }
+}
+
+impl ItemConsts for NativeMenuFrame {
+    const cached_rendering_data_offset: const_field_offset::FieldOffset<Self, CachedRenderingData> =
+        Self::FIELD_OFFSETS.cached_rendering_data.as_unpinned_projection();
+}
+
+declare_item_vtable! {
+fn slint_get_NativeMenuFrameVTable() -> NativeMenuFrameVTable for NativeMenuFrame
+}
+
+#[repr(C)]
+#[derive(FieldOffsets, Default, SlintElement)]
+#[pin]
+pub struct NativeMenuItem {
+    pub x: Property<LogicalLength>,
+    pub y: Property<LogicalLength>,
+    pub width: Property<LogicalLength>,
+    pub height: Property<LogicalLength>,
+    pub text: Property<SharedString>,
+    pub shortcut: Property<SharedString>,
+    pub checkable: Property<bool>,
+    pub checked: Property<bool>,
+    pub is_separator: Property<bool>,
+    pub has_submenu: Property<bool>,
+    pub highlighted: Property<bool>,
+    pub cached_rendering_data: CachedRenderingData,
+}
+
+impl Item for NativeMenuItem {
+    fn init(self: Pin<&Self>) {}
+
+    fn geometry(self: Pin<&Self>) -> LogicalRect {
+        LogicalRect::new(
+            LogicalPoint::from_lengths(self.x(), self.y()),
+            LogicalSize::from_lengths(self.width(), self.height()),
+        )
+    }
+
+    fn layout_info(
+        self: Pin<&Self>,
+        orientation: Orientation,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+    ) -> LayoutInfo {
+        let text: qttypes::QString = self.text().as_str().into();
+        let shortcut: qttypes::QString = self.shortcut().as_str().into();
+        let checkable = self.checkable();
+        let is_separator = self.is_separator();
+        let has_submenu = self.has_submenu();
+        let s = cpp!(unsafe [
+            text as "QString",
+            shortcut as "QString",
+            checkable as "bool",
+            is_separator as "bool",
+            has_submenu as "bool"
+        ] -> qttypes::QSize as "QSize" {
+            ensure_initialized();
+            QStyleOptionMenuItem option;
+            option.text = text;
+            option.text.replace(QChar('&'), QLatin1String("&&"));
+            if (!shortcut.isEmpty()) {
+                option.text += QLatin1Char('\t') + shortcut;
+                option.reservedShortcutWidth = option.fontMetrics.horizontalAdvance(shortcut);
+            }
+            option.menuItemType = is_separator ? QStyleOptionMenuItem::Separator
+                : has_submenu ? QStyleOptionMenuItem::SubMenu : QStyleOptionMenuItem::Normal;
+            option.checkType = checkable ? QStyleOptionMenuItem::NonExclusive : QStyleOptionMenuItem::NotCheckable;
+            return qApp->style()->sizeFromContents(QStyle::CT_MenuItem, &option, option.fontMetrics.size(Qt::TextShowMnemonic, text), nullptr);
+        });
+        let min = match orientation {
+            Orientation::Horizontal => s.width,
+            Orientation::Vertical => s.height,
+        } as f32;
+        LayoutInfo { min, preferred: min, ..LayoutInfo::default() }
+    }
+
+    fn input_event_filter_before_children(
+        self: Pin<&Self>,
+        _: MouseEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> InputEventFilterResult {
+        InputEventFilterResult::ForwardAndIgnore
+    }
+
+    fn input_event(
+        self: Pin<&Self>,
+        _event: MouseEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &i_slint_core::items::ItemRc,
+    ) -> InputEventResult {
+        InputEventResult::EventIgnored
+    }
+
+    fn key_event(
+        self: Pin<&Self>,
+        _: &KeyEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> KeyEventResult {
+        KeyEventResult::EventIgnored
+    }
+
+    fn focus_event(
+        self: Pin<&Self>,
+        _: &FocusEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> FocusEventResult {
+        FocusEventResult::FocusIgnored
+    }
+
+    fn_render! { this dpr size painter widget initial_state =>
+        let text: qttypes::QString = this.text().as_str().into();
+        let shortcut: qttypes::QString = this.shortcut().as_str().into();
+        let checkable = this.checkable();
+        let checked = this.checked();
+        let is_separator = this.is_separator();
+        let has_submenu = this.has_submenu();
+        let highlighted = this.highlighted();
+        cpp!(unsafe [
+            painter as "QPainterPtr*",
+            widget as "QWidget*",
+            size as "QSize",
+            dpr as "float",
+            text as "QString",
+            shortcut as "QString",
+            checkable as "bool",
+            checked as "bool",
+            is_separator as "bool",
+            has_submenu as "bool",
+            highlighted as "bool",
+            initial_state as "int"
+        ] {
+            ensure_initialized();
+            QStyleOptionMenuItem option;
+            option.state |= QStyle::State(initial_state);
+            option.rect = QRect(QPoint(), size / dpr);
+            option.menuRect = option.rect;
+            option.state |= QStyle::State_Enabled;
+            if (highlighted) {
+                option.state |= QStyle::State_Selected;
+            }
+            option.text = text;
+            option.text.replace(QChar('&'), QLatin1String("&&"));
+            if (!shortcut.isEmpty()) {
+                option.text += QLatin1Char('\t') + shortcut;
+                option.reservedShortcutWidth = option.fontMetrics.horizontalAdvance(shortcut);
+            }
+            option.menuItemType = is_separator ? QStyleOptionMenuItem::Separator
+                : has_submenu ? QStyleOptionMenuItem::SubMenu : QStyleOptionMenuItem::Normal;
+            option.checkType = checkable ? QStyleOptionMenuItem::NonExclusive : QStyleOptionMenuItem::NotCheckable;
+            option.checked = checked;
+            qApp->style()->drawControl(QStyle::CE_MenuItem, &option, painter->get(), widget);
+        });
+    }
+}
+
+impl ItemConsts for NativeMenuItem {
+    const cached_rendering_data_offset: const_field_offset::FieldOffset<Self, CachedRenderingData> =
+        Self::FIELD_OFFSETS.cached_rendering_data.as_unpinned_projection();
+}
+
+declare_item_vtable! {
+fn slint_get_NativeMenuItemVTable() -> NativeMenuItemVTable for NativeMenuItem
+}
+
+#[repr(C)]
+#[derive(FieldOffsets, Default, SlintElement)]
+#[pin]
+pub struct NativeMenuBarItem {
+    pub x: Property<LogicalLength>,
+    pub y: Property<LogicalLength>,
+    pub width: Property<LogicalLength>,
+    pub height: Property<LogicalLength>,
+    pub text: Property<SharedString>,
+    pub highlighted: Property<bool>,
+    pub pressed: Property<bool>,
+    pub cached_rendering_data: CachedRenderingData,
+}
+
+impl Item for NativeMenuBarItem {
+    fn init(self: Pin<&Self>) {}
+
+    fn geometry(self: Pin<&Self>) -> LogicalRect {
+        LogicalRect::new(
+            LogicalPoint::from_lengths(self.x(), self.y()),
+            LogicalSize::from_lengths(self.width(), self.height()),
+        )
+    }
+
+    fn layout_info(
+        self: Pin<&Self>,
+        orientation: Orientation,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+    ) -> LayoutInfo {
+        let text: qttypes::QString = self.text().as_str().into();
+        let s = cpp!(unsafe [
+            text as "QString"
+        ] -> qttypes::QSize as "QSize" {
+            ensure_initialized();
+            QStyleOptionMenuItem option;
+            option.text = text;
+            option.text.replace(QChar('&'), QLatin1String("&&"));
+            option.menuItemType = QStyleOptionMenuItem::Normal;
+            auto style = qApp->style();
+            QSize size = option.fontMetrics.size(Qt::TextShowMnemonic, option.text);
+            int margin = 2 * style->pixelMetric(QStyle::PM_MenuBarItemSpacing, &option, nullptr);
+            return style->sizeFromContents(QStyle::CT_MenuBarItem, &option, size, nullptr) + QSize(margin, margin);
+        });
+        let min = match orientation {
+            Orientation::Horizontal => s.width,
+            Orientation::Vertical => s.height,
+        } as f32;
+        LayoutInfo { min, preferred: min, ..LayoutInfo::default() }
+    }
+
+    fn input_event_filter_before_children(
+        self: Pin<&Self>,
+        _: MouseEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> InputEventFilterResult {
+        InputEventFilterResult::ForwardAndIgnore
+    }
+
+    fn input_event(
+        self: Pin<&Self>,
+        _event: MouseEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &i_slint_core::items::ItemRc,
+    ) -> InputEventResult {
+        InputEventResult::EventIgnored
+    }
+
+    fn key_event(
+        self: Pin<&Self>,
+        _: &KeyEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> KeyEventResult {
+        KeyEventResult::EventIgnored
+    }
+
+    fn focus_event(
+        self: Pin<&Self>,
+        _: &FocusEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> FocusEventResult {
+        FocusEventResult::FocusIgnored
+    }
+
+    fn_render! { this dpr size painter widget initial_state =>
+        let text: qttypes::QString = this.text().as_str().into();
+        let highlighted = this.highlighted();
+        let pressed = this.pressed();
+        cpp!(unsafe [
+            painter as "QPainterPtr*",
+            widget as "QWidget*",
+            size as "QSize",
+            dpr as "float",
+            text as "QString",
+            highlighted as "bool",
+            pressed as "bool",
+            initial_state as "int"
+        ] {
+            ensure_initialized();
+            QStyleOptionMenuItem option;
+            option.state |= QStyle::State(initial_state);
+            option.rect = QRect(QPoint(), size / dpr);
+            option.menuRect = option.rect;
+            option.state |= QStyle::State_Enabled;
+            if (highlighted || pressed) {
+                option.state |= QStyle::State_Selected;
+            }
+            if (pressed) {
+                option.state |= QStyle::State_Sunken;
+            }
+            option.text = text;
+            option.text.replace(QChar('&'), QLatin1String("&&"));
+            option.menuItemType = QStyleOptionMenuItem::Normal;
+            qApp->style()->drawControl(QStyle::CE_MenuBarItem, &option, painter->get(), widget);
+        });
+    }
+}
+
+impl ItemConsts for NativeMenuBarItem {
+    const cached_rendering_data_offset: const_field_offset::FieldOffset<Self, CachedRenderingData> =
+        Self::FIELD_OFFSETS.cached_rendering_data.as_unpinned_projection();
+}
+
+declare_item_vtable! {
+fn slint_get_NativeMenuBarItemVTable() -> NativeMenuBarItemVTable for NativeMenuBarItem
+}
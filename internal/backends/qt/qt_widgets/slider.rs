@@ -30,6 +30,7 @@ pub struct NativeSlider {
     pub value: Property<f32>,
     pub minimum: Property<f32>,
     pub maximum: Property<f32>,
+    pub has_hover: Property<bool>,
     pub cached_rendering_data: CachedRenderingData,
     data: Property<NativeSliderData>,
     pub changed: Callback<FloatArg>,
@@ -108,10 +109,11 @@ fn layout_info(
 
     fn input_event_filter_before_children(
         self: Pin<&Self>,
-        _: MouseEvent,
+        event: MouseEvent,
         _window_adapter: &Rc<dyn WindowAdapter>,
         _self_rc: &ItemRc,
     ) -> InputEventFilterResult {
+        Self::FIELD_OFFSETS.has_hover.apply_pin(self).set(!matches!(event, MouseEvent::Exit));
         InputEventFilterResult::ForwardEvent
     }
 
@@ -121,6 +123,9 @@ fn input_event(
         _window_adapter: &Rc<dyn WindowAdapter>,
         _self_rc: &i_slint_core::items::ItemRc,
     ) -> InputEventResult {
+        if matches!(event, MouseEvent::Exit) {
+            Self::FIELD_OFFSETS.has_hover.apply_pin(self).set(false);
+        }
         let size: qttypes::QSize = get_size!(self);
         let enabled = self.enabled();
         let value = self.value();
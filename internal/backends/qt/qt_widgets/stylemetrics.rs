@@ -49,6 +49,8 @@ pub struct NativeStyleMetrics {
     // Tab Bar metrics:
     pub tab_bar_alignment: Property<LayoutAlignment>,
 
+    pub tooltip_delay: Property<i64>,
+
     pub style_change_listener: core::cell::Cell<*const u8>,
 }
 
@@ -75,6 +77,7 @@ pub fn new() -> Pin<Rc<Self>> {
             placeholder_color_disabled: Default::default(),
             dark_color_scheme: Default::default(),
             tab_bar_alignment: Default::default(),
+            tooltip_delay: Default::default(),
             style_change_listener: core::cell::Cell::new(core::ptr::null()),
         })
     }
@@ -177,6 +180,11 @@ fn init_impl(self: Pin<&Self>) {
             3 => LayoutAlignment::End,
             _ => LayoutAlignment::SpaceBetween, // Should not happen! If it does, it should be noticeable;-)
         });
+
+        let tooltip_delay = cpp!(unsafe[] -> i32 as "int" {
+            return qApp->style()->styleHint(QStyle::SH_ToolTip_WakeUpDelay);
+        });
+        self.tooltip_delay.set(tooltip_delay as i64);
     }
 }
 
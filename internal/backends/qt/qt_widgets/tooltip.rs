@@ -0,0 +1,122 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-1.0 OR LicenseRef-Slint-commercial
+
+use i_slint_core::input::FocusEventResult;
+
+use super::*;
+
+#[repr(C)]
+#[derive(FieldOffsets, Default, SlintElement)]
+#[pin]
+pub struct NativeToolTip {
+    pub x: Property<LogicalLength>,
+    pub y: Property<LogicalLength>,
+    pub width: Property<LogicalLength>,
+    pub height: Property<LogicalLength>,
+    pub text: Property<SharedString>,
+    pub cached_rendering_data: CachedRenderingData,
+}
+
+impl Item for NativeToolTip {
+    fn init(self: Pin<&Self>) {}
+
+    fn geometry(self: Pin<&Self>) -> LogicalRect {
+        LogicalRect::new(
+            LogicalPoint::from_lengths(self.x(), self.y()),
+            LogicalSize::from_lengths(self.width(), self.height()),
+        )
+    }
+
+    fn layout_info(
+        self: Pin<&Self>,
+        orientation: Orientation,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+    ) -> LayoutInfo {
+        let text: qttypes::QString = self.text().as_str().into();
+        let s = cpp!(unsafe [
+            text as "QString"
+        ] -> qttypes::QSize as "QSize" {
+            ensure_initialized();
+            auto style = qApp->style();
+            QFontMetrics fm(QToolTip::font());
+            int margin = style->pixelMetric(QStyle::PM_ToolTipLabelFrameWidth, nullptr, nullptr);
+            // Same extra space as QTipLabel
+            return fm.size(Qt::TextExpandTabs, text) + QSize(2 * margin + 4, 2 * margin);
+        });
+        let min = match orientation {
+            Orientation::Horizontal => s.width,
+            Orientation::Vertical => s.height,
+        } as f32;
+        LayoutInfo { min, preferred: min, ..LayoutInfo::default() }
+    }
+
+    fn input_event_filter_before_children(
+        self: Pin<&Self>,
+        _: MouseEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> InputEventFilterResult {
+        InputEventFilterResult::ForwardAndIgnore
+    }
+
+    fn input_event(
+        self: Pin<&Self>,
+        _event: MouseEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &i_slint_core::items::ItemRc,
+    ) -> InputEventResult {
+        InputEventResult::EventIgnored
+    }
+
+    fn key_event(
+        self: Pin<&Self>,
+        _: &KeyEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> KeyEventResult {
+        KeyEventResult::EventIgnored
+    }
+
+    fn focus_event(
+        self: Pin<&Self>,
+        _: &FocusEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> FocusEventResult {
+        FocusEventResult::FocusIgnored
+    }
+
+    fn_render! { this dpr size painter widget initial_state =>
+        let text: qttypes::QString = this.text().as_str().into();
+        cpp!(unsafe [
+            painter as "QPainterPtr*",
+            widget as "QWidget*",
+            size as "QSize",
+            dpr as "float",
+            text as "QString",
+            initial_state as "int"
+        ] {
+            ensure_initialized();
+            auto style = qApp->style();
+            QStyleOptionFrame option;
+            option.state |= QStyle::State(initial_state);
+            option.rect = QRect(QPoint(), size / dpr);
+            option.palette = QToolTip::palette();
+            style->drawPrimitive(QStyle::PE_PanelTipLabel, &option, painter->get(), widget);
+            int margin = style->pixelMetric(QStyle::PM_ToolTipLabelFrameWidth, &option, widget);
+            painter->get()->setFont(QToolTip::font());
+            painter->get()->setPen(QToolTip::palette().color(QPalette::ToolTipText));
+            painter->get()->drawText(option.rect.adjusted(margin + 2, margin, -margin - 2, -margin),
+                Qt::AlignLeft | Qt::AlignVCenter | Qt::TextExpandTabs, text);
+        });
+    }
+}
+
+impl ItemConsts for NativeToolTip {
+    const cached_rendering_data_offset: const_field_offset::FieldOffset<Self, CachedRenderingData> =
+        Self::FIELD_OFFSETS.cached_rendering_data.as_unpinned_projection();
+}
+
+declare_item_vtable! {
+fn slint_get_NativeToolTipVTable() -> NativeToolTipVTable for NativeToolTip
+}
@@ -1787,8 +1787,13 @@ fn input_method_request(&self, request: i_slint_core::window::InputMethodRequest
         let widget_ptr = self.widget_ptr();
         match request {
             i_slint_core::window::InputMethodRequest::Enable { input_type, .. } => {
-                let enable: bool = matches!(input_type, i_slint_core::items::InputType::Text);
-                cpp! {unsafe [widget_ptr as "QWidget*", enable as "bool"] {
+                use i_slint_core::items::InputType;
+                let enable: bool = !matches!(input_type, InputType::Password);
+                let number: bool = matches!(input_type, InputType::Number);
+                let decimal: bool = matches!(input_type, InputType::Decimal);
+                cpp! {unsafe [widget_ptr as "QWidget*", enable as "bool", number as "bool", decimal as "bool"] {
+                    widget_ptr->setInputMethodHints(number ? Qt::ImhDigitsOnly
+                            : decimal ? Qt::ImhFormattedNumbersOnly : Qt::ImhNone);
                     widget_ptr->setAttribute(Qt::WA_InputMethodEnabled, enable);
                 }};
             }
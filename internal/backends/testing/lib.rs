@@ -8,6 +8,7 @@
 use i_slint_core::graphics::FontRequest;
 use i_slint_core::lengths::{LogicalLength, LogicalPoint, LogicalRect, LogicalSize, ScaleFactor};
 use i_slint_core::renderer::{Renderer, RendererSealed};
+use i_slint_core::software_renderer::{RepaintBufferType, SoftwareRenderer};
 use i_slint_core::window::WindowAdapterInternal;
 use i_slint_core::window::{InputMethodRequest, WindowAdapter};
 
@@ -19,6 +20,8 @@
 #[derive(Default)]
 pub struct TestingBackend {
     clipboard: Mutex<Option<String>>,
+    /// When true, the windows measure the text with the fonts of the software renderer
+    font_metrics: bool,
 }
 
 impl i_slint_core::platform::Platform for TestingBackend {
@@ -30,6 +33,8 @@ fn create_window_adapter(
             shown: false.into(),
             size: Default::default(),
             ime_requests: Default::default(),
+            renderer: SoftwareRenderer::new_without_window(RepaintBufferType::NewBuffer),
+            font_metrics: self.font_metrics,
         }))
     }
 
@@ -58,6 +63,16 @@ pub struct TestingWindow {
     shown: core::cell::Cell<bool>,
     size: core::cell::Cell<PhysicalSize>,
     pub ime_requests: RefCell<Vec<InputMethodRequest>>,
+    /// The software renderer used for [`take_snapshot`], and for the text metrics if enabled
+    /// with [`init_with_font_metrics`].
+    renderer: SoftwareRenderer,
+    font_metrics: bool,
+}
+
+impl TestingWindow {
+    fn font_renderer(&self) -> Option<&SoftwareRenderer> {
+        self.font_metrics.then_some(&self.renderer)
+    }
 }
 
 impl WindowAdapterInternal for TestingWindow {
@@ -109,54 +124,96 @@ fn internal(&self, _: i_slint_core::InternalToken) -> Option<&dyn WindowAdapterI
 impl RendererSealed for TestingWindow {
     fn text_size(
         &self,
-        _font_request: i_slint_core::graphics::FontRequest,
+        font_request: i_slint_core::graphics::FontRequest,
         text: &str,
-        _max_width: Option<LogicalLength>,
-        _scale_factor: ScaleFactor,
+        max_width: Option<LogicalLength>,
+        scale_factor: ScaleFactor,
     ) -> LogicalSize {
+        if let Some(renderer) = self.font_renderer() {
+            return renderer.text_size(font_request, text, max_width, scale_factor);
+        }
         LogicalSize::new(text.len() as f32 * 10., 10.)
     }
 
-    // this works only for single line text
+    // without font metrics, this works only for single line text
     fn text_input_byte_offset_for_position(
         &self,
         text_input: Pin<&i_slint_core::items::TextInput>,
         pos: LogicalPoint,
-        _font_request: FontRequest,
-        _scale_factor: ScaleFactor,
+        font_request: FontRequest,
+        scale_factor: ScaleFactor,
     ) -> usize {
+        if let Some(renderer) = self.font_renderer() {
+            return renderer.text_input_byte_offset_for_position(
+                text_input,
+                pos,
+                font_request,
+                scale_factor,
+            );
+        }
         let text_len = text_input.text().len();
         let result = pos.x / 10.;
         result.min(text_len as f32).max(0.) as usize
     }
 
-    // this works only for single line text
+    // without font metrics, this works only for single line text
     fn text_input_cursor_rect_for_byte_offset(
         &self,
-        _text_input: Pin<&i_slint_core::items::TextInput>,
+        text_input: Pin<&i_slint_core::items::TextInput>,
         byte_offset: usize,
-        _font_request: FontRequest,
-        _scale_factor: ScaleFactor,
+        font_request: FontRequest,
+        scale_factor: ScaleFactor,
     ) -> LogicalRect {
+        if let Some(renderer) = self.font_renderer() {
+            return renderer.text_input_cursor_rect_for_byte_offset(
+                text_input,
+                byte_offset,
+                font_request,
+                scale_factor,
+            );
+        }
         LogicalRect::new(Point2D::new(byte_offset as f32 * 10., 0.), Size2D::new(1., 10.))
     }
 
     fn register_font_from_memory(
         &self,
-        _data: &'static [u8],
+        data: &'static [u8],
     ) -> Result<(), Box<dyn std::error::Error>> {
-        Ok(())
+        match self.font_renderer() {
+            Some(renderer) => renderer.register_font_from_memory(data),
+            None => Ok(()),
+        }
     }
 
     fn register_font_from_path(
         &self,
-        _path: &std::path::Path,
+        path: &std::path::Path,
     ) -> Result<(), Box<dyn std::error::Error>> {
-        Ok(())
+        match self.font_renderer() {
+            Some(renderer) => renderer.register_font_from_path(path),
+            None => Ok(()),
+        }
+    }
+
+    fn register_bitmap_font(&self, font_data: &'static i_slint_core::graphics::BitmapFont) {
+        if let Some(renderer) = self.font_renderer() {
+            renderer.register_bitmap_font(font_data);
+        }
     }
 
     fn default_font_size(&self) -> LogicalLength {
-        LogicalLength::new(10.)
+        match self.font_renderer() {
+            Some(renderer) => renderer.default_font_size(),
+            None => LogicalLength::new(10.),
+        }
+    }
+
+    fn free_graphics_resources(
+        &self,
+        component: i_slint_core::component::ComponentRef,
+        items: &mut dyn Iterator<Item = Pin<i_slint_core::items::ItemRef<'_>>>,
+    ) -> Result<(), PlatformError> {
+        self.renderer.free_graphics_resources(component, items)
     }
 }
 
@@ -168,6 +225,28 @@ pub fn init() {
         .expect("platform already initialized");
 }
 
+/// Initialize the testing backend, measuring the text with real glyph metrics.
+///
+/// Unlike [`init()`], where every byte is 10px wide and 10px tall, the text is shaped and
+/// measured with the fonts of the software renderer, so that layouts, the cursor position and
+/// hit testing in multi-line TextInputs behave like in a real application. Nothing is rendered.
+///
+/// Requires the `system-fonts` feature.
+#[cfg(feature = "system-fonts")]
+pub fn init_with_font_metrics() {
+    i_slint_core::platform::set_platform(Box::new(TestingBackend {
+        font_metrics: true,
+        ..Default::default()
+    }))
+    .expect("platform already initialized");
+}
+
+mod search_api;
+pub use i_slint_core::items::AccessibleRole;
+pub use search_api::ElementHandle;
+mod snapshot;
+pub use snapshot::{compare_with_reference_png, take_snapshot, ImageComparisonError};
+
 /// This module contains functions useful for unit tests
 mod for_unit_test {
     use i_slint_core::api::ComponentHandle;
@@ -0,0 +1,217 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-1.0 OR LicenseRef-Slint-commercial
+
+//! API to find the elements of a component and to interact with them in tests
+
+use i_slint_core::accessibility::AccessibleStringProperty;
+use i_slint_core::api::{ComponentHandle, LogicalPosition, LogicalSize};
+use i_slint_core::item_tree::{ItemRc, ItemWeak};
+use i_slint_core::items::AccessibleRole;
+use i_slint_core::platform::{PointerEventButton, WindowAdapter, WindowEvent};
+use i_slint_core::window::WindowInner;
+use i_slint_core::SharedString;
+use std::rc::{Rc, Weak};
+
+/// A handle to an element of a component, to query its geometry and properties, and to interact
+/// with it.
+///
+/// Elements are found with the `find_by_*` functions. The type names and ids of the elements are
+/// only available if the .slint code was compiled with debug info: this is the case with the
+/// interpreter when its `testing` feature is enabled, and can be enabled for the generated Rust code with
+/// `slint_build::CompilerConfiguration::with_debug_info()` or with the `SLINT_EMIT_DEBUG_INFO` environment
+/// variable. The generated C++ code doesn't contain the debug info yet.
+///
+/// The elements of the popups that are currently shown are found too.
+///
+/// The handle doesn't keep the element alive: when the element is destroyed, for example because
+/// the model of its `for` changed, the handle becomes invalid and the functions return default values.
+#[derive(Clone)]
+pub struct ElementHandle {
+    item: ItemWeak,
+    window_adapter: Weak<dyn WindowAdapter>,
+}
+
+impl ElementHandle {
+    fn find_all(
+        component: &impl ComponentHandle,
+        mut predicate: impl FnMut(&ItemRc) -> bool,
+    ) -> Vec<Self> {
+        let window_inner = WindowInner::from_pub(component.window());
+        let window_adapter = Rc::downgrade(&window_inner.window_adapter());
+        let mut result = Vec::new();
+        // The stack is processed from its end: the component comes first, then the popups
+        let mut stack: Vec<_> =
+            window_inner.popup_components().into_iter().rev().map(|c| ItemRc::new(c, 0)).collect();
+        stack.push(ItemRc::new(window_inner.component(), 0));
+        while let Some(item) = stack.pop() {
+            if let Some(sibling) = item.next_sibling() {
+                stack.push(sibling);
+            }
+            if let Some(child) = item.first_child() {
+                stack.push(child);
+            }
+            if predicate(&item) {
+                result
+                    .push(Self { item: item.downgrade(), window_adapter: window_adapter.clone() });
+            }
+        }
+        result
+    }
+
+    /// Returns all the elements of the component, in depth-first order
+    pub fn find_all_elements(component: &impl ComponentHandle) -> Vec<Self> {
+        Self::find_all(component, |_| true)
+    }
+
+    /// Returns the elements with the given id, as written in the .slint file
+    pub fn find_by_element_id(component: &impl ComponentHandle, id: &str) -> Vec<Self> {
+        Self::find_all(component, |item| element_infos(item).any(|(_, elem_id)| elem_id == id))
+    }
+
+    /// Returns the elements of the given type, such as `Button` or `Rectangle`.
+    ///
+    /// An element matches if it is of that type, or if it is the root element of a component of that type.
+    pub fn find_by_element_type_name(
+        component: &impl ComponentHandle,
+        type_name: &str,
+    ) -> Vec<Self> {
+        Self::find_all(component, |item| {
+            element_infos(item).any(|(elem_type, _)| elem_type == type_name)
+        })
+    }
+
+    /// Returns the elements with the given `accessible-role`
+    pub fn find_by_accessible_role(
+        component: &impl ComponentHandle,
+        role: AccessibleRole,
+    ) -> Vec<Self> {
+        Self::find_all(component, |item| item.is_accessible() && item.accessible_role() == role)
+    }
+
+    /// Returns the elements with the given `accessible-label`
+    pub fn find_by_accessible_label(component: &impl ComponentHandle, label: &str) -> Vec<Self> {
+        Self::find_all(component, |item| {
+            item.is_accessible()
+                && item.accessible_string_property(AccessibleStringProperty::Label) == label
+        })
+    }
+
+    /// Returns true if the element still exists
+    pub fn is_valid(&self) -> bool {
+        self.item.upgrade().is_some()
+    }
+
+    /// Returns the id of the element, as written in the .slint file, if it has one
+    pub fn id(&self) -> Option<SharedString> {
+        let item = self.item.upgrade()?;
+        let id = element_infos(&item).map(|(_, id)| id).find(|id| !id.is_empty())?;
+        Some(id.into())
+    }
+
+    /// Returns the type name of the element, as written in the .slint file
+    pub fn type_name(&self) -> Option<SharedString> {
+        let item = self.item.upgrade()?;
+        let type_name = element_infos(&item).next()?.0;
+        Some(type_name.into())
+    }
+
+    /// Returns the `accessible-role` of the element, if it is accessible
+    pub fn accessible_role(&self) -> Option<AccessibleRole> {
+        self.item.upgrade().filter(|item| item.is_accessible()).map(|item| item.accessible_role())
+    }
+
+    fn accessible_string_property(&self, what: AccessibleStringProperty) -> Option<SharedString> {
+        self.item
+            .upgrade()
+            .filter(|item| item.is_accessible())
+            .map(|item| item.accessible_string_property(what))
+    }
+
+    /// Returns the `accessible-label` of the element, if it is accessible
+    pub fn accessible_label(&self) -> Option<SharedString> {
+        self.accessible_string_property(AccessibleStringProperty::Label)
+    }
+
+    /// Returns the `accessible-description` of the element, if it is accessible
+    pub fn accessible_description(&self) -> Option<SharedString> {
+        self.accessible_string_property(AccessibleStringProperty::Description)
+    }
+
+    /// Returns the `accessible-value` of the element, if it is accessible
+    pub fn accessible_value(&self) -> Option<SharedString> {
+        self.accessible_string_property(AccessibleStringProperty::Value)
+    }
+
+    /// Returns the `accessible-checked` state of the element, if it is accessible and checkable
+    pub fn accessible_checked(&self) -> Option<bool> {
+        if self.accessible_string_property(AccessibleStringProperty::Checkable)? != "true" {
+            return None;
+        }
+        Some(self.accessible_string_property(AccessibleStringProperty::Checked)? == "true")
+    }
+
+    /// Returns the position of the element, in logical pixels relative to the window
+    pub fn absolute_position(&self) -> LogicalPosition {
+        let (Some(window_adapter), Some(item)) =
+            (self.window_adapter.upgrade(), self.item.upgrade())
+        else {
+            return Default::default();
+        };
+        let position = WindowInner::from_pub(window_adapter.window())
+            .map_to_window(&item, item.geometry().origin);
+        LogicalPosition::new(position.x as f32, position.y as f32)
+    }
+
+    /// Returns the size of the element, in logical pixels
+    pub fn size(&self) -> LogicalSize {
+        self.item.upgrade().map_or_else(Default::default, |item| {
+            let size = item.geometry().size;
+            LogicalSize::new(size.width as f32, size.height as f32)
+        })
+    }
+
+    /// Simulates a click with the left mouse button in the center of the element
+    pub fn click(&self) {
+        let Some(window_adapter) = self.window_adapter.upgrade() else { return };
+        if !self.is_valid() {
+            return;
+        }
+        let position = self.absolute_position();
+        let size = self.size();
+        let position =
+            LogicalPosition::new(position.x + size.width / 2., position.y + size.height / 2.);
+        let button = PointerEventButton::Left;
+        let window = window_adapter.window();
+        window.dispatch_event(WindowEvent::PointerPressed { position, button });
+        window.dispatch_event(WindowEvent::PointerReleased { position, button });
+    }
+
+    /// Gives the keyboard focus to the element, or to its first focusable child
+    pub fn focus(&self) {
+        let (Some(window_adapter), Some(item)) =
+            (self.window_adapter.upgrade(), self.item.upgrade())
+        else {
+            return;
+        };
+        WindowInner::from_pub(window_adapter.window()).set_focus_item(&item);
+    }
+
+    /// Gives the keyboard focus to the element and simulates typing the given text
+    pub fn type_text(&self, text: &str) {
+        self.focus();
+        if let Some(window_adapter) = self.window_adapter.upgrade() {
+            i_slint_core::tests::send_keyboard_string_sequence(&text.into(), &window_adapter);
+        }
+    }
+}
+
+/// Returns the (type name, id) pairs of the elements the item comes from
+fn element_infos(item: &ItemRc) -> impl Iterator<Item = (String, String)> {
+    let infos = item.element_infos().unwrap_or_default();
+    let infos: Vec<_> = infos
+        .lines()
+        .filter_map(|line| line.split_once("::"))
+        .map(|(type_name, id)| (type_name.to_string(), id.to_string()))
+        .collect();
+    infos.into_iter()
+}
@@ -0,0 +1,144 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-1.0 OR LicenseRef-Slint-commercial
+
+//! Headless rendering of the windows of the testing backend, and comparison with reference images
+
+use i_slint_core::api::{PhysicalSize, Window};
+use i_slint_core::graphics::{Rgba8Pixel, SharedPixelBuffer};
+use i_slint_core::platform::PlatformError;
+use i_slint_core::software_renderer::PremultipliedRgbaColor;
+use i_slint_core::window::WindowInner;
+use std::path::Path;
+
+/// Renders the current contents of the window with the software renderer, and returns them as an image.
+///
+/// The window must have been created by the testing backend, initialized with [`init()`](crate::init)
+/// or `init_with_font_metrics()`. The latter should be preferred so that the layout of the text matches
+/// how it is rendered.
+pub fn take_snapshot(window: &Window) -> Result<SharedPixelBuffer<Rgba8Pixel>, PlatformError> {
+    let window_inner = WindowInner::from_pub(window);
+    let window_item = window_inner
+        .window_item()
+        .ok_or_else(|| PlatformError::Other("take_snapshot: the window has no component".into()))?;
+    let window_item = window_item.as_pin_ref();
+    let scale_factor = window_inner.scale_factor();
+    let width = (window_item.width().get() * scale_factor).round() as u32;
+    let height = (window_item.height().get() * scale_factor).round() as u32;
+    if width == 0 || height == 0 {
+        return Err(PlatformError::Other(format!(
+            "take_snapshot: the window has an empty size ({width}x{height})"
+        )));
+    }
+
+    let mut buffer = vec![PremultipliedRgbaColor::default(); width as usize * height as usize];
+    crate::access_testing_window(window, |testing_window| {
+        testing_window.renderer.set_window(window);
+        testing_window.renderer.render(&mut buffer, width as usize);
+    });
+
+    let mut image = SharedPixelBuffer::<Rgba8Pixel>::new(width, height);
+    for (pixel, color) in image.make_mut_slice().iter_mut().zip(buffer) {
+        let unpremultiply = |component: u8| {
+            if color.alpha == 0 {
+                0
+            } else {
+                (component as u16 * 255 / color.alpha as u16) as u8
+            }
+        };
+        *pixel = Rgba8Pixel {
+            r: unpremultiply(color.red),
+            g: unpremultiply(color.green),
+            b: unpremultiply(color.blue),
+            a: color.alpha,
+        };
+    }
+    Ok(image)
+}
+
+/// Error returned by [`compare_with_reference_png`]
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum ImageComparisonError {
+    /// The reference image could not be loaded
+    ReferenceNotLoaded(String),
+    /// The reference image and the snapshot have different sizes
+    SizeMismatch {
+        /// The size of the reference image
+        reference: PhysicalSize,
+        /// The size of the snapshot
+        snapshot: PhysicalSize,
+    },
+    /// Some pixels differ by more than the tolerance
+    PixelMismatch {
+        /// The number of pixels that differ by more than the tolerance
+        count: usize,
+        /// The largest difference of a color channel, between 0 and 255
+        max_difference: u8,
+        /// The position of the first pixel that differs by more than the tolerance
+        first: (u32, u32),
+    },
+}
+
+impl core::fmt::Display for ImageComparisonError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            ImageComparisonError::ReferenceNotLoaded(err) => {
+                write!(f, "error loading reference image: {err}")
+            }
+            ImageComparisonError::SizeMismatch { reference, snapshot } => write!(
+                f,
+                "image sizes don't match: the reference is {}x{} and the snapshot is {}x{}",
+                reference.width, reference.height, snapshot.width, snapshot.height
+            ),
+            ImageComparisonError::PixelMismatch { count, max_difference, first } => write!(
+                f,
+                "{count} pixels are different, starting at {first:?}. Maximum channel difference: {max_difference}"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ImageComparisonError {}
+
+/// Compares an image, typically obtained with [`take_snapshot`], with the PNG file at `reference`.
+///
+/// Each pixel matches if none of its red, green, blue and alpha channels differ by more than `tolerance`.
+pub fn compare_with_reference_png(
+    image: &SharedPixelBuffer<Rgba8Pixel>,
+    reference: impl AsRef<Path>,
+    tolerance: u8,
+) -> Result<(), ImageComparisonError> {
+    let reference = image::open(reference)
+        .map_err(|err| ImageComparisonError::ReferenceNotLoaded(err.to_string()))?
+        .into_rgba8();
+
+    if reference.dimensions() != (image.width(), image.height()) {
+        return Err(ImageComparisonError::SizeMismatch {
+            reference: PhysicalSize::new(reference.width(), reference.height()),
+            snapshot: PhysicalSize::new(image.width(), image.height()),
+        });
+    }
+
+    let mut count = 0;
+    let mut max_difference = 0;
+    let mut first = None;
+    for (index, (expected, actual)) in reference.pixels().zip(image.as_slice()).enumerate() {
+        let difference = expected
+            .0
+            .iter()
+            .zip([actual.r, actual.g, actual.b, actual.a])
+            .map(|(e, a)| e.abs_diff(a))
+            .max()
+            .unwrap_or_default();
+        if difference > tolerance {
+            count += 1;
+            max_difference = max_difference.max(difference);
+            first.get_or_insert((index as u32 % image.width(), index as u32 / image.width()));
+        }
+    }
+
+    match first {
+        None => Ok(()),
+        Some(first) => Err(ImageComparisonError::PixelMismatch { count, max_difference, first }),
+    }
+}
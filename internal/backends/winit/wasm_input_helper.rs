@@ -21,6 +21,7 @@
 use std::rc::{Rc, Weak};
 
 use i_slint_core::input::{KeyEventType, KeyInputEvent};
+use i_slint_core::items::InputType;
 use i_slint_core::platform::WindowEvent;
 use i_slint_core::window::{WindowAdapter, WindowInner};
 use i_slint_core::SharedString;
@@ -239,7 +240,14 @@ pub fn has_focus(&self) -> bool {
         self.input.matches(":focus").unwrap_or(false)
     }
 
-    pub fn show(&self) {
+    pub fn show(&self, input_type: InputType) {
+        // Let the browser show a virtual keyboard that matches the input type
+        let input_mode = match input_type {
+            InputType::Number => "numeric",
+            InputType::Decimal => "decimal",
+            _ => "text",
+        };
+        self.input.set_attribute("inputmode", input_mode).unwrap();
         self.input.style().set_property("visibility", "visible").unwrap();
         self.input.focus().unwrap();
     }
@@ -614,7 +614,7 @@ fn input_method_request(&self, request: corelib::window::InputMethodRequest) {
         self.with_window_handle(&mut |winit_window| {
             match request {
                 corelib::window::InputMethodRequest::Enable { input_type, .. } => winit_window
-                    .set_ime_allowed(matches!(input_type, corelib::items::InputType::Text)),
+                    .set_ime_allowed(!matches!(input_type, corelib::items::InputType::Password)),
                 corelib::window::InputMethodRequest::Disable { .. } => {
                     winit_window.set_ime_allowed(false)
                 }
@@ -627,13 +627,13 @@ fn input_method_request(&self, request: corelib::window::InputMethodRequest) {
 
         #[cfg(target_arch = "wasm32")]
         match request {
-            corelib::window::InputMethodRequest::Enable { .. } => {
+            corelib::window::InputMethodRequest::Enable { input_type, .. } => {
                 let mut vkh = self.virtual_keyboard_helper.borrow_mut();
                 let h = vkh.get_or_insert_with(|| {
                     let canvas = self.winit_window().canvas();
                     super::wasm_input_helper::WasmInputHelper::new(self.self_weak.clone(), canvas)
                 });
-                h.show();
+                h.show(input_type);
             }
             corelib::window::InputMethodRequest::Disable { .. } => {
                 if let Some(h) = &*self.virtual_keyboard_helper.borrow() {
@@ -237,15 +237,18 @@ enum ImageRendering {
                 Pixelated,
             }
 
-            /// This enum is used to define the type of the input field. Currently this only differentiates between
-            /// text and password inputs but in the future it could be expanded to also define what type of virtual keyboard
-            /// should be shown, for example.
+            /// This enum is used to define the type of the input field. It restricts the characters that can be entered
+            /// and is passed on to the platform's input method, so that for example a matching virtual keyboard is shown.
             #[non_exhaustive]
             enum InputType {
                 /// The default value. This will render all characters normally
                 Text,
                 /// This will render all characters with a character that defaults to "*"
                 Password,
+                /// Only digits, and a leading minus sign (`-`), can be entered
+                Number,
+                /// Only digits, a leading minus sign (`-`), and a single decimal point (`.`) can be entered
+                Decimal,
             }
 
             /// Enum representing the [alignment](../concepts/layouting.md#alignment) property of a
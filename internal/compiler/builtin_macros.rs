@@ -9,6 +9,7 @@
     BuiltinFunction, BuiltinMacroFunction, EasingCurve, Expression, Unit,
 };
 use crate::langtype::{EnumerationValue, Type};
+use crate::lookup::LookupCtx;
 use crate::parser::NodeOrToken;
 
 /// Used for uniquely name some variables
@@ -19,13 +20,15 @@ pub fn lower_macro(
     mac: BuiltinMacroFunction,
     n: Option<NodeOrToken>,
     mut sub_expr: impl Iterator<Item = (Expression, Option<NodeOrToken>)>,
-    diag: &mut BuildDiagnostics,
+    ctx: &mut LookupCtx,
 ) -> Expression {
+    let diag = &mut *ctx.diag;
     match mac {
         BuiltinMacroFunction::Min => min_max_macro(n, '<', sub_expr.collect(), diag),
         BuiltinMacroFunction::Max => min_max_macro(n, '>', sub_expr.collect(), diag),
         BuiltinMacroFunction::Mod => mod_macro(n, sub_expr.collect(), diag),
-        BuiltinMacroFunction::Debug => debug_macro(n, sub_expr.collect(), diag),
+        BuiltinMacroFunction::Clamp => clamp_macro(n, sub_expr.collect(), diag),
+        BuiltinMacroFunction::Debug => debug_macro(n, sub_expr.collect(), ctx),
         BuiltinMacroFunction::CubicBezier => {
             let mut has_error = None;
             let expected_argument_type_error =
@@ -62,7 +65,73 @@ pub fn lower_macro(
             expr
         }
         BuiltinMacroFunction::Rgb => rgb_macro(n, sub_expr.collect(), diag),
+        BuiltinMacroFunction::Hsv => hsx_macro(n, BuiltinFunction::Hsv, sub_expr.collect(), diag),
+        BuiltinMacroFunction::Hsl => hsx_macro(n, BuiltinFunction::Hsl, sub_expr.collect(), diag),
+        BuiltinMacroFunction::ArrayOperation(_) | BuiltinMacroFunction::ArrayContains => {
+            // The arguments of these need to be resolved with the item in scope
+            unreachable!("array functions are handled when resolving the function call")
+        }
+        BuiltinMacroFunction::OptionalValueOr => value_or_macro(n, sub_expr.collect(), ctx),
+    }
+}
+
+fn value_or_macro(
+    node: Option<NodeOrToken>,
+    mut args: Vec<(Expression, Option<NodeOrToken>)>,
+    ctx: &mut LookupCtx,
+) -> Expression {
+    // The first argument is the optional itself
+    if args.len() != 2 {
+        ctx.diag.push_error(
+            format!(
+                "The callback or function expects 1 arguments, but {} are provided",
+                args.len() - 1
+            ),
+            &node,
+        );
+        return Expression::Invalid;
     }
+    let (default, default_node) = args.pop().unwrap();
+    let (optional, _) = args.pop().unwrap();
+    let Type::Optional(inner) = optional.ty() else { unreachable!() };
+    if *inner == Type::Void {
+        // `none.value-or(x)`
+        return default;
+    }
+    let default = default.maybe_convert_to((*inner).clone(), &default_node, ctx.diag);
+    if default.ty() != *inner {
+        // The conversion failed, and an error was already reported
+        return Expression::Invalid;
+    }
+    optional_value_or(optional, |value, _| value, default, ctx)
+}
+
+/// Evaluate `optional` once, and return `map(value)` if it has a value, or `default` otherwise
+fn optional_value_or(
+    optional: Expression,
+    map: impl FnOnce(Expression, &mut LookupCtx) -> Expression,
+    default: Expression,
+    ctx: &mut LookupCtx,
+) -> Expression {
+    let ty = optional.ty();
+    let Type::Optional(inner) = &ty else { unreachable!() };
+    let local_object = format!("optional{}", ctx.unique_local_variable_suffix());
+    let read = || Expression::ReadLocalVariable { name: local_object.clone(), ty: ty.clone() };
+    Expression::CodeBlock(vec![
+        Expression::StoreLocalVariable { name: local_object.clone(), value: Box::new(optional) },
+        Expression::Condition {
+            condition: Box::new(Expression::BinaryExpression {
+                lhs: Box::new(read()),
+                rhs: Box::new(Expression::default_value_for_type(&ty)),
+                op: '!',
+            }),
+            true_expr: Box::new(map(
+                Expression::Cast { from: Box::new(read()), to: (**inner).clone() },
+                ctx,
+            )),
+            false_expr: Box::new(default),
+        },
+    ])
 }
 
 fn min_max_macro(
@@ -143,6 +212,73 @@ fn mod_macro(
     }
 }
 
+fn clamp_macro(
+    node: Option<NodeOrToken>,
+    args: Vec<(Expression, Option<NodeOrToken>)>,
+    diag: &mut BuildDiagnostics,
+) -> Expression {
+    if args.len() != 3 {
+        diag.push_error("Needs 3 arguments".into(), &node);
+        return Expression::Invalid;
+    }
+    let ty = match args[0].0.ty() {
+        Type::Float32 | Type::Int32 | Type::Percent => Type::Float32,
+        ty @ (Type::PhysicalLength | Type::LogicalLength | Type::Duration | Type::Angle) => ty,
+        _ => {
+            diag.push_error("Invalid argument type".into(), &args[0].1);
+            return Expression::Invalid;
+        }
+    };
+
+    let source_location = node.map(|n| n.to_source_location());
+    let function = Box::new(Expression::BuiltinFunctionReference(
+        BuiltinFunction::Clamp,
+        source_location.clone(),
+    ));
+    let arguments = args.into_iter().map(|(e, n)| e.maybe_convert_to(ty.clone(), &n, diag));
+    if matches!(ty, Type::Float32) {
+        Expression::FunctionCall { function, arguments: arguments.collect(), source_location }
+    } else {
+        Expression::Cast {
+            from: Expression::FunctionCall {
+                function,
+                arguments: arguments
+                    .map(|a| Expression::Cast { from: a.into(), to: Type::Float32 })
+                    .collect(),
+                source_location,
+            }
+            .into(),
+            to: ty,
+        }
+    }
+}
+
+fn hsx_macro(
+    node: Option<NodeOrToken>,
+    function: BuiltinFunction,
+    args: Vec<(Expression, Option<NodeOrToken>)>,
+    diag: &mut BuildDiagnostics,
+) -> Expression {
+    if args.len() < 3 || args.len() > 4 {
+        diag.push_error("Needs 3 or 4 argument".into(), &node);
+        return Expression::Invalid;
+    }
+    // The percentages are converted to a float between 0 and 1
+    let mut arguments: Vec<_> =
+        args.into_iter().map(|(expr, n)| expr.maybe_convert_to(Type::Float32, &n, diag)).collect();
+    if arguments.len() < 4 {
+        arguments.push(Expression::NumberLiteral(1., Unit::None))
+    }
+    Expression::FunctionCall {
+        function: Box::new(Expression::BuiltinFunctionReference(
+            function,
+            node.as_ref().map(|t| t.to_source_location()),
+        )),
+        arguments,
+        source_location: Some(node.to_source_location()),
+    }
+}
+
 fn rgb_macro(
     node: Option<NodeOrToken>,
     args: Vec<(Expression, Option<NodeOrToken>)>,
@@ -187,11 +323,11 @@ fn rgb_macro(
 fn debug_macro(
     node: Option<NodeOrToken>,
     args: Vec<(Expression, Option<NodeOrToken>)>,
-    diag: &mut BuildDiagnostics,
+    ctx: &mut LookupCtx,
 ) -> Expression {
     let mut string = None;
     for (expr, node) in args {
-        let val = to_debug_string(expr, node, diag);
+        let val = to_debug_string(expr, node, ctx);
         string = Some(match string {
             None => val,
             Some(string) => Expression::BinaryExpression {
@@ -216,11 +352,7 @@ fn debug_macro(
     }
 }
 
-fn to_debug_string(
-    expr: Expression,
-    node: Option<NodeOrToken>,
-    diag: &mut BuildDiagnostics,
-) -> Expression {
+fn to_debug_string(expr: Expression, node: Option<NodeOrToken>, ctx: &mut LookupCtx) -> Expression {
     let ty = expr.ty();
     match &ty {
         Type::Invalid => Expression::Invalid,
@@ -233,11 +365,18 @@ fn to_debug_string(
         | Type::LayoutCache
         | Type::Model
         | Type::PathData => {
-            diag.push_error("Cannot debug this expression".into(), &node);
+            ctx.diag.push_error("Cannot debug this expression".into(), &node);
             Expression::Invalid
         }
-        Type::Float32 | Type::Int32 => expr.maybe_convert_to(Type::String, &node, diag),
+        Type::Float32 | Type::Int32 => expr.maybe_convert_to(Type::String, &node, ctx.diag),
         Type::String => expr,
+        Type::Optional(inner) if **inner == Type::Void => Expression::StringLiteral("none".into()),
+        Type::Optional(_) => optional_value_or(
+            expr,
+            |value, ctx| to_debug_string(value, node.clone(), ctx),
+            Expression::StringLiteral("none".into()),
+            ctx,
+        ),
         // TODO
         Type::Color | Type::Brush | Type::Image | Type::Easing | Type::Array(_) => {
             Expression::StringLiteral("<debug-of-this-type-not-yet-implemented>".into())
@@ -253,7 +392,7 @@ fn to_debug_string(
                 Expression::Cast { from: Box::new(expr), to: Type::Float32 }.maybe_convert_to(
                     Type::String,
                     &node,
-                    diag,
+                    ctx.diag,
                 ),
             ),
             op: '+',
@@ -284,7 +423,7 @@ fn to_debug_string(
                         name: k.clone(),
                     },
                     node.clone(),
-                    diag,
+                    ctx,
                 );
                 let field = Expression::BinaryExpression {
                     lhs: Box::new(Expression::StringLiteral(field_name)),
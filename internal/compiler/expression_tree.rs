@@ -35,25 +35,62 @@ pub enum BuiltinFunction {
     ACos,
     ASin,
     ATan,
+    ATan2,
     Log,
+    Ln,
     Pow,
+    Exp,
+    Sign,
+    /// `clamp(value, min, max)`, the arguments are converted to float by the Clamp macro
+    Clamp,
     SetFocusItem,
     ShowPopupWindow,
     ClosePopupWindow,
+    /// Start, restart or stop the timers of the component according to their `running` and
+    /// `interval` properties. Called by the change callbacks set by the lower_timers pass.
+    UpdateTimers,
     /// A function that belongs to an item (such as TextInput's select-all function).
     ItemMemberFunction(String),
     /// the "42".to_float()
     StringToFloat,
     /// the "42".is_float()
     StringIsFloat,
+    /// the "abc".length
+    StringCharacterCount,
+    /// the "abc".to-uppercase()
+    StringToUppercase,
+    /// the "abc".to-lowercase()
+    StringToLowercase,
+    /// the " abc ".trim()
+    StringTrim,
+    /// the "abc".contains("b")
+    StringContains,
+    /// the "abc".starts-with("a")
+    StringStartsWith,
+    /// the "abc".ends-with("c")
+    StringEndsWith,
+    /// the "abc".substring(start, end)
+    StringSubstring,
+    /// the "abc".replace("b", "x")
+    StringReplace,
+    /// the "a,b".split(",")
+    StringSplit,
+    /// the (4.2).to-fixed(precision)
+    NumberToFixed,
     ColorBrighter,
     ColorDarker,
     ColorTransparentize,
     ColorMix,
     ColorWithAlpha,
+    /// The color.to-hsv() struct with the hue, saturation, value and alpha components
+    ColorHsvaStruct,
+    /// The color.to-hsl() struct with the hue, saturation, lightness and alpha components
+    ColorHslaStruct,
     ImageSize,
     ArrayLength,
     Rgb,
+    Hsv,
+    Hsl,
     DarkColorScheme,
     TextInputFocused,
     SetTextInputFocused,
@@ -78,12 +115,40 @@ pub enum BuiltinMacroFunction {
     Max,
     /// Add the right conversion operations so that the return type is the same as the argument type
     Mod,
+    /// Convert the arguments to a common type and call [`BuiltinFunction::Clamp`]
+    Clamp,
     CubicBezier,
     /// The argument can be r,g,b,a or r,g,b and they can be percentages or integer.
     /// transform the argument so it is always rgb(r, g, b, a) with r, g, b between 0 and 255.
     Rgb,
+    /// The argument can be h,s,v,a or h,s,v. The saturation, value and alpha can be percentages.
+    /// transform the argument so it is always hsv(h, s, v, a) with s, v, a between 0 and 1.
+    Hsv,
+    /// Same as [`BuiltinMacroFunction::Hsv`] for hsl(h, s, l, a)
+    Hsl,
     /// transform `debug(a, b, c)` into debug `a + " " + b + " " + c`
     Debug,
+    /// `array.filter(item => ...)` and the other functions that become an [`Expression::ArrayOperation`]
+    ArrayOperation(ArrayOperation),
+    /// Transform `array.contains(value)` into `array.find-index(item => item == value) != -1`
+    ArrayContains,
+    /// Transform `optional.value-or(default)` into a condition on whether the optional has a value
+    OptionalValueOr,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// The operations of [`Expression::ArrayOperation`]
+pub enum ArrayOperation {
+    /// `array.filter(item => condition)`: the items for which the condition is true
+    Filter,
+    /// `array.map(item => value)`: a new array with the value computed for each item
+    Map,
+    /// `array.sort-by(item => key)`: the items sorted by ascending key, which is a number or a string
+    SortBy,
+    /// `array.find-index(item => condition)`: the index of the first item for which the condition is true, or -1
+    FindIndex,
+    /// `array.reverse()`: the items in the reverse order
+    Reverse,
 }
 
 impl BuiltinFunction {
@@ -118,10 +183,21 @@ pub fn ty(&self) -> Type {
             BuiltinFunction::ACos | BuiltinFunction::ASin | BuiltinFunction::ATan => {
                 Type::Function { return_type: Box::new(Type::Angle), args: vec![Type::Float32] }
             }
+            BuiltinFunction::ATan2 => Type::Function {
+                return_type: Box::new(Type::Angle),
+                args: vec![Type::Float32, Type::Float32],
+            },
             BuiltinFunction::Log | BuiltinFunction::Pow => Type::Function {
                 return_type: Box::new(Type::Float32),
                 args: vec![Type::Float32, Type::Float32],
             },
+            BuiltinFunction::Ln | BuiltinFunction::Exp | BuiltinFunction::Sign => {
+                Type::Function { return_type: Box::new(Type::Float32), args: vec![Type::Float32] }
+            }
+            BuiltinFunction::Clamp => Type::Function {
+                return_type: Box::new(Type::Float32),
+                args: vec![Type::Float32, Type::Float32, Type::Float32],
+            },
             BuiltinFunction::SetFocusItem => Type::Function {
                 return_type: Box::new(Type::Void),
                 args: vec![Type::ElementReference],
@@ -132,6 +208,9 @@ pub fn ty(&self) -> Type {
                     args: vec![Type::ElementReference],
                 }
             }
+            BuiltinFunction::UpdateTimers => {
+                Type::Function { return_type: Box::new(Type::Void), args: vec![] }
+            }
             BuiltinFunction::ItemMemberFunction(..) => Type::Function {
                 return_type: Box::new(Type::Void),
                 args: vec![Type::ElementReference],
@@ -142,6 +221,36 @@ pub fn ty(&self) -> Type {
             BuiltinFunction::StringIsFloat => {
                 Type::Function { return_type: Box::new(Type::Bool), args: vec![Type::String] }
             }
+            BuiltinFunction::StringCharacterCount => {
+                Type::Function { return_type: Box::new(Type::Int32), args: vec![Type::String] }
+            }
+            BuiltinFunction::StringToUppercase
+            | BuiltinFunction::StringToLowercase
+            | BuiltinFunction::StringTrim => {
+                Type::Function { return_type: Box::new(Type::String), args: vec![Type::String] }
+            }
+            BuiltinFunction::StringContains
+            | BuiltinFunction::StringStartsWith
+            | BuiltinFunction::StringEndsWith => Type::Function {
+                return_type: Box::new(Type::Bool),
+                args: vec![Type::String, Type::String],
+            },
+            BuiltinFunction::StringSubstring => Type::Function {
+                return_type: Box::new(Type::String),
+                args: vec![Type::String, Type::Int32, Type::Int32],
+            },
+            BuiltinFunction::StringReplace => Type::Function {
+                return_type: Box::new(Type::String),
+                args: vec![Type::String, Type::String, Type::String],
+            },
+            BuiltinFunction::StringSplit => Type::Function {
+                return_type: Box::new(Type::Array(Box::new(Type::String))),
+                args: vec![Type::String, Type::String],
+            },
+            BuiltinFunction::NumberToFixed => Type::Function {
+                return_type: Box::new(Type::String),
+                args: vec![Type::Float32, Type::Int32],
+            },
             BuiltinFunction::ImplicitLayoutInfo(_) => Type::Function {
                 return_type: Box::new(crate::layout::layout_info_type()),
                 args: vec![Type::ElementReference],
@@ -166,6 +275,36 @@ pub fn ty(&self) -> Type {
                 return_type: Box::new(Type::Brush),
                 args: vec![Type::Brush, Type::Float32],
             },
+            BuiltinFunction::ColorHsvaStruct => Type::Function {
+                return_type: Box::new(Type::Struct {
+                    fields: IntoIterator::into_iter([
+                        ("hue".to_string(), Type::Float32),
+                        ("saturation".to_string(), Type::Float32),
+                        ("value".to_string(), Type::Float32),
+                        ("alpha".to_string(), Type::Float32),
+                    ])
+                    .collect(),
+                    name: None,
+                    node: None,
+                    rust_attributes: None,
+                }),
+                args: vec![Type::Color],
+            },
+            BuiltinFunction::ColorHslaStruct => Type::Function {
+                return_type: Box::new(Type::Struct {
+                    fields: IntoIterator::into_iter([
+                        ("hue".to_string(), Type::Float32),
+                        ("saturation".to_string(), Type::Float32),
+                        ("lightness".to_string(), Type::Float32),
+                        ("alpha".to_string(), Type::Float32),
+                    ])
+                    .collect(),
+                    name: None,
+                    node: None,
+                    rust_attributes: None,
+                }),
+                args: vec![Type::Color],
+            },
             BuiltinFunction::ImageSize => Type::Function {
                 return_type: Box::new(Type::Struct {
                     fields: IntoIterator::into_iter([
@@ -186,6 +325,10 @@ pub fn ty(&self) -> Type {
                 return_type: Box::new(Type::Color),
                 args: vec![Type::Int32, Type::Int32, Type::Int32, Type::Float32],
             },
+            BuiltinFunction::Hsv | BuiltinFunction::Hsl => Type::Function {
+                return_type: Box::new(Type::Color),
+                args: vec![Type::Float32, Type::Float32, Type::Float32, Type::Float32],
+            },
             BuiltinFunction::DarkColorScheme => {
                 Type::Function { return_type: Box::new(Type::Bool), args: vec![] }
             }
@@ -242,17 +385,36 @@ fn is_const(&self) -> bool {
             | BuiltinFunction::ACos
             | BuiltinFunction::ASin
             | BuiltinFunction::Log
+            | BuiltinFunction::Ln
             | BuiltinFunction::Pow
-            | BuiltinFunction::ATan => true,
+            | BuiltinFunction::Exp
+            | BuiltinFunction::Sign
+            | BuiltinFunction::Clamp
+            | BuiltinFunction::ATan
+            | BuiltinFunction::ATan2 => true,
             BuiltinFunction::SetFocusItem => false,
             BuiltinFunction::ShowPopupWindow | BuiltinFunction::ClosePopupWindow => false,
+            BuiltinFunction::UpdateTimers => false,
             BuiltinFunction::ItemMemberFunction(..) => false,
             BuiltinFunction::StringToFloat | BuiltinFunction::StringIsFloat => true,
+            BuiltinFunction::StringCharacterCount
+            | BuiltinFunction::StringToUppercase
+            | BuiltinFunction::StringToLowercase
+            | BuiltinFunction::StringTrim
+            | BuiltinFunction::StringContains
+            | BuiltinFunction::StringStartsWith
+            | BuiltinFunction::StringEndsWith
+            | BuiltinFunction::StringSubstring
+            | BuiltinFunction::StringReplace
+            | BuiltinFunction::StringSplit
+            | BuiltinFunction::NumberToFixed => true,
             BuiltinFunction::ColorBrighter
             | BuiltinFunction::ColorDarker
             | BuiltinFunction::ColorTransparentize
             | BuiltinFunction::ColorMix
-            | BuiltinFunction::ColorWithAlpha => true,
+            | BuiltinFunction::ColorWithAlpha
+            | BuiltinFunction::ColorHsvaStruct
+            | BuiltinFunction::ColorHslaStruct => true,
             // ImageSize is pure, except when loading images via the network. Then the initial size will be 0/0 and
             // we need to make sure that calls to this function stay within a binding, so that the property
             // notification when updating kicks in. Only Slintpad (wasm-interpreter) loads images via the network,
@@ -262,7 +424,7 @@ fn is_const(&self) -> bool {
             #[cfg(target_arch = "wasm32")]
             BuiltinFunction::ImageSize => false,
             BuiltinFunction::ArrayLength => true,
-            BuiltinFunction::Rgb => true,
+            BuiltinFunction::Rgb | BuiltinFunction::Hsv | BuiltinFunction::Hsl => true,
             BuiltinFunction::SetTextInputFocused => false,
             BuiltinFunction::TextInputFocused => false,
             BuiltinFunction::ImplicitLayoutInfo(_) => false,
@@ -295,20 +457,39 @@ pub fn is_pure(&self) -> bool {
             | BuiltinFunction::ACos
             | BuiltinFunction::ASin
             | BuiltinFunction::Log
+            | BuiltinFunction::Ln
             | BuiltinFunction::Pow
-            | BuiltinFunction::ATan => true,
+            | BuiltinFunction::Exp
+            | BuiltinFunction::Sign
+            | BuiltinFunction::Clamp
+            | BuiltinFunction::ATan
+            | BuiltinFunction::ATan2 => true,
             BuiltinFunction::SetFocusItem => false,
             BuiltinFunction::ShowPopupWindow | BuiltinFunction::ClosePopupWindow => false,
+            BuiltinFunction::UpdateTimers => false,
             BuiltinFunction::ItemMemberFunction(..) => false,
             BuiltinFunction::StringToFloat | BuiltinFunction::StringIsFloat => true,
+            BuiltinFunction::StringCharacterCount
+            | BuiltinFunction::StringToUppercase
+            | BuiltinFunction::StringToLowercase
+            | BuiltinFunction::StringTrim
+            | BuiltinFunction::StringContains
+            | BuiltinFunction::StringStartsWith
+            | BuiltinFunction::StringEndsWith
+            | BuiltinFunction::StringSubstring
+            | BuiltinFunction::StringReplace
+            | BuiltinFunction::StringSplit
+            | BuiltinFunction::NumberToFixed => true,
             BuiltinFunction::ColorBrighter
             | BuiltinFunction::ColorDarker
             | BuiltinFunction::ColorTransparentize
             | BuiltinFunction::ColorMix
-            | BuiltinFunction::ColorWithAlpha => true,
+            | BuiltinFunction::ColorWithAlpha
+            | BuiltinFunction::ColorHsvaStruct
+            | BuiltinFunction::ColorHslaStruct => true,
             BuiltinFunction::ImageSize => true,
             BuiltinFunction::ArrayLength => true,
-            BuiltinFunction::Rgb => true,
+            BuiltinFunction::Rgb | BuiltinFunction::Hsv | BuiltinFunction::Hsl => true,
             BuiltinFunction::ImplicitLayoutInfo(_) => true,
             BuiltinFunction::ItemAbsolutePosition => true,
             BuiltinFunction::SetTextInputFocused => false,
@@ -444,6 +625,9 @@ pub enum Expression {
     NumberLiteral(f64, Unit),
     ///
     BoolLiteral(bool),
+    /// The `none` literal: an optional without value.
+    /// Its type is `Type::Optional(Type::Void)`, it is converted to other optional types with a Cast
+    NoneLiteral,
 
     /// Reference to the callback `<name>` in the `<element>`
     ///
@@ -573,6 +757,16 @@ pub enum Expression {
         false_expr: Box<Expression>,
     },
 
+    /// Evaluate the arm that matches the value of an enumeration
+    Match {
+        /// This expression should have [`Type::Enumeration`] type
+        value: Box<Expression>,
+        /// The indices of the enumeration values matched by each arm, and the arm's expression
+        arms: Vec<(Vec<usize>, Expression)>,
+        /// The `_` arm, evaluated for the values not covered by `arms`
+        default: Option<Box<Expression>>,
+    },
+
     Array {
         element_ty: Type,
         values: Vec<Expression>,
@@ -601,6 +795,26 @@ pub enum Expression {
 
     ReturnStatement(Option<Box<Expression>>),
 
+    /// An operation over all the rows of an array, such as `array.filter(item => condition)`.
+    ///
+    /// The lambda is the name of the local variable holding the current row, and the expression
+    /// evaluated for each row. It is `None` for the operations without a lambda (`reverse()`).
+    ArrayOperation {
+        array: Box<Expression>,
+        operation: ArrayOperation,
+        lambda: Option<(String, Box<Expression>)>,
+    },
+
+    /// Evaluate the body as long as the condition is true
+    ///
+    /// A `for` loop is lowered to a `WhileLoop` over the index of the model, with local variables.
+    /// A `while` loop also counts its iterations in a local variable, to stop after a maximum.
+    WhileLoop {
+        /// This expression should have [`Type::Bool`] type
+        condition: Box<Expression>,
+        body: Box<Expression>,
+    },
+
     LayoutCacheAccess {
         layout_cache_prop: NamedReference,
         index: usize,
@@ -623,6 +837,7 @@ pub fn ty(&self) -> Type {
             Expression::StringLiteral(_) => Type::String,
             Expression::NumberLiteral(_, unit) => unit.ty(),
             Expression::BoolLiteral(_) => Type::Bool,
+            Expression::NoneLiteral => Type::Optional(Box::new(Type::Void)),
             Expression::CallbackReference(nr, _) => nr.ty(),
             Expression::FunctionReference(nr, _) => nr.ty(),
             Expression::PropertyReference(nr) => nr.ty(),
@@ -682,6 +897,16 @@ pub fn ty(&self) -> Type {
                     Type::Void
                 }
             }
+            Expression::Match { arms, default, .. } => {
+                let mut types =
+                    arms.iter().map(|(_, e)| e).chain(default.as_deref()).map(|e| e.ty());
+                let first = types.find(|t| *t != Type::Invalid).unwrap_or(Type::Invalid);
+                if types.all(|t| t == first || t == Type::Invalid) {
+                    first
+                } else {
+                    Type::Void
+                }
+            }
             Expression::BinaryExpression { op, lhs, rhs } => {
                 if operator_class(*op) != OperatorClass::ArithmeticOp {
                     Type::Bool
@@ -746,6 +971,16 @@ pub fn ty(&self) -> Type {
             Expression::EnumerationValue(value) => Type::Enumeration(value.enumeration.clone()),
             // invalid because the expression is unreachable
             Expression::ReturnStatement(_) => Type::Invalid,
+            Expression::ArrayOperation { array, operation, lambda } => match operation {
+                ArrayOperation::Map => Type::Array(Box::new(
+                    lambda.as_ref().map_or(Type::Invalid, |(_, body)| body.ty()),
+                )),
+                ArrayOperation::FindIndex => Type::Int32,
+                ArrayOperation::Filter | ArrayOperation::SortBy | ArrayOperation::Reverse => {
+                    array.ty()
+                }
+            },
+            Expression::WhileLoop { .. } => Type::Void,
             Expression::LayoutCacheAccess { .. } => Type::LogicalLength,
             Expression::ComputeLayoutInfo(..) => crate::layout::layout_info_type(),
             Expression::SolveLayout(..) => Type::LayoutCache,
@@ -760,6 +995,7 @@ pub fn visit(&self, mut visitor: impl FnMut(&Self)) {
             Expression::StringLiteral(_) => {}
             Expression::NumberLiteral(_, _) => {}
             Expression::BoolLiteral(_) => {}
+            Expression::NoneLiteral => {}
             Expression::CallbackReference { .. } => {}
             Expression::PropertyReference { .. } => {}
             Expression::FunctionReference { .. } => {}
@@ -796,6 +1032,11 @@ pub fn visit(&self, mut visitor: impl FnMut(&Self)) {
                 visitor(&**true_expr);
                 visitor(&**false_expr);
             }
+            Expression::Match { value, arms, default } => {
+                visitor(&**value);
+                arms.iter().for_each(|(_, e)| visitor(e));
+                default.as_deref().map(visitor);
+            }
             Expression::BinaryExpression { lhs, rhs, .. } => {
                 visitor(&**lhs);
                 visitor(&**rhs);
@@ -842,6 +1083,16 @@ pub fn visit(&self, mut visitor: impl FnMut(&Self)) {
             Expression::ReturnStatement(expr) => {
                 expr.as_deref().map(visitor);
             }
+            Expression::ArrayOperation { array, lambda, .. } => {
+                visitor(array);
+                if let Some((_, body)) = lambda {
+                    visitor(body);
+                }
+            }
+            Expression::WhileLoop { condition, body } => {
+                visitor(condition);
+                visitor(body);
+            }
             Expression::LayoutCacheAccess { repeater_index, .. } => {
                 repeater_index.as_deref().map(visitor);
             }
@@ -857,6 +1108,7 @@ pub fn visit_mut(&mut self, mut visitor: impl FnMut(&mut Self)) {
             Expression::StringLiteral(_) => {}
             Expression::NumberLiteral(_, _) => {}
             Expression::BoolLiteral(_) => {}
+            Expression::NoneLiteral => {}
             Expression::CallbackReference { .. } => {}
             Expression::PropertyReference { .. } => {}
             Expression::FunctionReference { .. } => {}
@@ -893,6 +1145,11 @@ pub fn visit_mut(&mut self, mut visitor: impl FnMut(&mut Self)) {
                 visitor(&mut **true_expr);
                 visitor(&mut **false_expr);
             }
+            Expression::Match { value, arms, default } => {
+                visitor(&mut **value);
+                arms.iter_mut().for_each(|(_, e)| visitor(e));
+                default.as_deref_mut().map(visitor);
+            }
             Expression::BinaryExpression { lhs, rhs, .. } => {
                 visitor(&mut **lhs);
                 visitor(&mut **rhs);
@@ -942,6 +1199,16 @@ pub fn visit_mut(&mut self, mut visitor: impl FnMut(&mut Self)) {
             Expression::ReturnStatement(expr) => {
                 expr.as_deref_mut().map(visitor);
             }
+            Expression::ArrayOperation { array, lambda, .. } => {
+                visitor(array);
+                if let Some((_, body)) = lambda {
+                    visitor(body);
+                }
+            }
+            Expression::WhileLoop { condition, body } => {
+                visitor(condition);
+                visitor(body);
+            }
             Expression::LayoutCacheAccess { repeater_index, .. } => {
                 repeater_index.as_deref_mut().map(visitor);
             }
@@ -969,6 +1236,7 @@ pub fn is_constant(&self) -> bool {
             Expression::StringLiteral(_) => true,
             Expression::NumberLiteral(_, _) => true,
             Expression::BoolLiteral(_) => true,
+            Expression::NoneLiteral => true,
             Expression::CallbackReference { .. } => false,
             Expression::FunctionReference(nr, _) => nr.is_constant(),
             Expression::PropertyReference(nr) => nr.is_constant(),
@@ -992,6 +1260,11 @@ pub fn is_constant(&self) -> bool {
             Expression::Condition { condition, false_expr, true_expr } => {
                 condition.is_constant() && false_expr.is_constant() && true_expr.is_constant()
             }
+            Expression::Match { value, arms, default } => {
+                value.is_constant()
+                    && arms.iter().all(|(_, e)| e.is_constant())
+                    && default.as_ref().map_or(true, |e| e.is_constant())
+            }
             Expression::BinaryExpression { lhs, rhs, .. } => lhs.is_constant() && rhs.is_constant(),
             Expression::UnaryOp { sub, .. } => sub.is_constant(),
             Expression::Array { values, .. } => values.iter().all(Expression::is_constant),
@@ -1017,6 +1290,8 @@ pub fn is_constant(&self) -> bool {
             Expression::ReturnStatement(expr) => {
                 expr.as_ref().map_or(true, |expr| expr.is_constant())
             }
+            Expression::ArrayOperation { .. } => false,
+            Expression::WhileLoop { .. } => false,
             // TODO:  detect constant property within layouts
             Expression::LayoutCacheAccess { .. } => false,
             Expression::ComputeLayoutInfo(..) => false,
@@ -1041,6 +1316,34 @@ pub fn maybe_convert_to(
             self
         } else if ty.can_convert(&target_type) {
             let from = match (ty, &target_type) {
+                // `none` to an optional type
+                (Type::Optional(from), Type::Optional(_)) if *from == Type::Void => self,
+                (Type::Optional(from), Type::Optional(_)) => {
+                    let var_name = "tmpoptional";
+                    let read = || Expression::ReadLocalVariable {
+                        name: var_name.into(),
+                        ty: Type::Optional(from.clone()),
+                    };
+                    return Expression::CodeBlock(vec![
+                        Expression::StoreLocalVariable {
+                            name: var_name.into(),
+                            value: Box::new(self),
+                        },
+                        Expression::Condition {
+                            condition: Box::new(Expression::BinaryExpression {
+                                lhs: Box::new(read()),
+                                rhs: Box::new(Expression::default_value_for_type(&read().ty())),
+                                op: '!',
+                            }),
+                            true_expr: Box::new(
+                                Expression::Cast { from: Box::new(read()), to: (*from).clone() }
+                                    .maybe_convert_to(target_type.clone(), node, diag),
+                            ),
+                            false_expr: Box::new(Expression::default_value_for_type(&target_type)),
+                        },
+                    ]);
+                }
+                (_, Type::Optional(inner)) => self.maybe_convert_to((**inner).clone(), node, diag),
                 (Type::Percent, Type::Float32) => Expression::BinaryExpression {
                     lhs: Box::new(self),
                     rhs: Box::new(Expression::NumberLiteral(0.01, Unit::None)),
@@ -1240,6 +1543,9 @@ pub fn default_value_for_type(ty: &Type) -> Expression {
             Type::Array(element_ty) => {
                 Expression::Array { element_ty: (**element_ty).clone(), values: vec![] }
             }
+            Type::Optional(_) => {
+                Expression::Cast { from: Box::new(Expression::NoneLiteral), to: ty.clone() }
+            }
             Type::Struct { fields, .. } => Expression::Struct {
                 ty: ty.clone(),
                 values: fields
@@ -1309,6 +1615,7 @@ pub fn try_set_rw(
             }
             Expression::StructFieldAccess { base, .. } => base.try_set_rw(ctx, what, node),
             Expression::RepeaterModelReference { .. } => true,
+            Expression::ReadLocalVariable { .. } => true,
             Expression::ArrayIndex { array, .. } => array.try_set_rw(ctx, what, node),
             _ => {
                 ctx.diag.push_error(format!("{what} needs to be done on a property"), node);
@@ -1478,6 +1785,7 @@ pub fn pretty_print(f: &mut dyn std::fmt::Write, expression: &Expression) -> std
         Expression::StringLiteral(s) => write!(f, "{:?}", s),
         Expression::NumberLiteral(vl, unit) => write!(f, "{}{}", vl, unit),
         Expression::BoolLiteral(b) => write!(f, "{:?}", b),
+        Expression::NoneLiteral => write!(f, "none"),
         Expression::CallbackReference(a, _) => write!(f, "{:?}", a),
         Expression::PropertyReference(a) => write!(f, "{:?}", a),
         Expression::FunctionReference(a, _) => write!(f, "{:?}", a),
@@ -1563,6 +1871,30 @@ pub fn pretty_print(f: &mut dyn std::fmt::Write, expression: &Expression) -> std
             pretty_print(f, false_expr)?;
             write!(f, " }}")
         }
+        Expression::Match { value, arms, default } => {
+            write!(f, "match (")?;
+            pretty_print(f, value)?;
+            write!(f, ") {{ ")?;
+            let ty = value.ty();
+            for (values, e) in arms {
+                let patterns = values
+                    .iter()
+                    .map(|v| match &ty {
+                        Type::Enumeration(en) => en.values[*v].clone(),
+                        _ => v.to_string(),
+                    })
+                    .collect::<Vec<_>>();
+                write!(f, "{} => ", patterns.join(" | "))?;
+                pretty_print(f, e)?;
+                write!(f, ", ")?;
+            }
+            if let Some(e) = default {
+                write!(f, "_ => ")?;
+                pretty_print(f, e)?;
+                write!(f, ", ")?;
+            }
+            write!(f, "}}")
+        }
         Expression::Array { element_ty: _, values } => {
             write!(f, "[")?;
             for e in values {
@@ -1611,6 +1943,21 @@ pub fn pretty_print(f: &mut dyn std::fmt::Write, expression: &Expression) -> std
             write!(f, "return ")?;
             e.as_ref().map(|e| pretty_print(f, e)).unwrap_or(Ok(()))
         }
+        Expression::ArrayOperation { array, operation, lambda } => {
+            pretty_print(f, array)?;
+            write!(f, ".{operation:?}(")?;
+            if let Some((item, body)) = lambda {
+                write!(f, "{item} => ")?;
+                pretty_print(f, body)?;
+            }
+            write!(f, ")")
+        }
+        Expression::WhileLoop { condition, body } => {
+            write!(f, "while (")?;
+            pretty_print(f, condition)?;
+            write!(f, ") ")?;
+            pretty_print(f, body)
+        }
         Expression::LayoutCacheAccess { layout_cache_prop, index, repeater_index } => {
             write!(
                 f,
@@ -354,6 +354,7 @@ fn cpp_type(&self) -> Option<String> {
             }
 
             Type::Array(i) => Some(format!("std::shared_ptr<slint::Model<{}>>", i.cpp_type()?)),
+            Type::Optional(i) => Some(format!("std::optional<{}>", i.cpp_type()?)),
             Type::Image => Some("slint::Image".to_owned()),
             Type::Enumeration(enumeration) => {
                 if enumeration.node.is_some() {
@@ -501,6 +502,7 @@ pub fn generate(doc: &Document) -> impl std::fmt::Display {
 
     file.includes.push("<array>".into());
     file.includes.push("<limits>".into());
+    file.includes.push("<optional>".into());
     file.includes.push("<cstdlib>".into()); // TODO: ideally only include this if needed (by to_float)
     file.includes.push("<cmath>".into()); // TODO: ideally only include this if needed (by floor/ceil/round)
     file.includes.push("<slint.h>".into());
@@ -1186,6 +1188,49 @@ fn generate_item_tree(
         }),
     ));
 
+    // The element infos are not generated for C++ yet
+    target_struct.members.push((
+        Access::Private,
+        Declaration::Function(Function {
+            name: "item_element_infos".into(),
+            signature:
+                "([[maybe_unused]] slint::private_api::ComponentRef component, [[maybe_unused]] uintptr_t index, [[maybe_unused]] slint::SharedString *result) -> bool"
+                    .into(),
+            is_static: true,
+            statements: Some(vec!["return false;".into()]),
+            ..Default::default()
+        }),
+    ));
+
+    let shortcut_index = sub_tree.shortcut_index();
+    target_struct.members.push((
+        Access::Private,
+        Declaration::Function(Function {
+            name: "get_shortcut_index".into(),
+            signature:
+                "([[maybe_unused]] slint::private_api::ComponentRef component) -> slint::cbindgen_private::Slice<uint32_t>"
+                    .into(),
+            is_static: true,
+            statements: Some(if shortcut_index.is_empty() {
+                // The pointer of a slice must not be null, even if it is empty
+                vec![
+                    "static const uint32_t empty = 0;".to_owned(),
+                    "return { const_cast<uint32_t*>(&empty), 0 };".to_owned(),
+                ]
+            } else {
+                vec![
+                    format!(
+                        "static const uint32_t shortcut_index[] {{ {} }};",
+                        shortcut_index.iter().map(|i| i.to_string()).join(", ")
+                    ),
+                    "return { const_cast<uint32_t*>(shortcut_index), std::size(shortcut_index) };"
+                        .to_owned(),
+                ]
+            }),
+            ..Default::default()
+        }),
+    ));
+
     target_struct.members.push((
         Access::Public,
         Declaration::Var(Var {
@@ -1201,8 +1246,8 @@ fn generate_item_tree(
         init: Some(format!(
             "{{ visit_children, get_item_ref, get_subtree_range, get_subtree_component, \
                 get_item_tree, parent_node, subtree_index, layout_info, \
-                accessible_role, accessible_string_property, \
-                slint::private_api::drop_in_place<{}>, slint::private_api::dealloc }}",
+                accessible_role, accessible_string_property, item_element_infos, \
+                get_shortcut_index, slint::private_api::drop_in_place<{}>, slint::private_api::dealloc }}",
             item_tree_class_name
         )),
         ..Default::default()
@@ -1599,6 +1644,58 @@ fn generate_sub_component(
         expr_str
     }));
 
+    for (idx, (prop, handler)) in component.change_callbacks.iter().enumerate() {
+        let change_tracker_id = format!("change_tracker{}", idx);
+        user_init.push(format!(
+            "self->{change_tracker_id}.init([self]() {{ return {value}; }}, [self]() {{ {handler}; }});",
+            value = compile_expression(&llr::Expression::PropertyReference(prop.clone()), &ctx),
+            handler = compile_expression(&handler.borrow(), &ctx),
+        ));
+        target_struct.members.push((
+            field_access,
+            Declaration::Var(Var {
+                ty: "slint::private_api::ChangeTracker".into(),
+                name: change_tracker_id,
+                ..Default::default()
+            }),
+        ));
+    }
+
+    if !component.timers.is_empty() {
+        let mut update_timers = vec!["[[maybe_unused]] auto self = this;".into()];
+        user_init.push("self->update_timers();".into());
+        for (i, tmr) in component.timers.iter().enumerate() {
+            let name = format!("timer{}", i);
+            let running = compile_expression(&tmr.running.borrow(), &ctx);
+            let interval = compile_expression(&tmr.interval.borrow(), &ctx);
+            let callback = compile_expression(&tmr.triggered.borrow(), &ctx);
+            update_timers.push(format!(
+                "if (auto interval = std::chrono::milliseconds(static_cast<int64_t>({interval})); {running} && interval > std::chrono::milliseconds::zero()) {{"
+            ));
+            update_timers.push(format!(
+                "    if (!self->{name}.running() || self->{name}.interval() != interval) {{"
+            ));
+            update_timers.push(format!(
+                "        self->{name}.start(slint::TimerMode::Repeated, interval, [self] {{ {callback}; }});"
+            ));
+            update_timers.push("    }".into());
+            update_timers.push(format!("}} else {{ self->{name}.stop(); }}"));
+            target_struct.members.push((
+                field_access,
+                Declaration::Var(Var { ty: "slint::Timer".into(), name, ..Default::default() }),
+            ));
+        }
+        target_struct.members.push((
+            field_access,
+            Declaration::Function(Function {
+                name: "update_timers".to_owned(),
+                signature: "() -> void".into(),
+                statements: Some(update_timers),
+                ..Default::default()
+            }),
+        ));
+    }
+
     target_struct
         .members
         .extend(generate_functions(&component.functions, &ctx).map(|x| (Access::Public, x)));
@@ -2323,6 +2420,7 @@ fn compile_expression(expr: &llr::Expression, ctx: &EvaluationContext) -> String
             }
         }
         Expression::BoolLiteral(b) => b.to_string(),
+        Expression::NoneLiteral => "std::nullopt".into(),
         Expression::PropertyReference(nr) => {
             let access = access_member(nr, ctx);
             format!(r#"{}.get()"#, access)
@@ -2347,7 +2445,13 @@ fn compile_expression(expr: &llr::Expression, ctx: &EvaluationContext) -> String
         }
         Expression::FunctionParameterReference { index, .. } => format!("arg_{}", index),
         Expression::StoreLocalVariable { name, value } => {
-            format!("auto {} = {};", ident(name), compile_expression(value, ctx))
+            let ty = value.ty(ctx);
+            // Numbers get an explicit type so that assigning another number later keeps that type
+            let cpp_ty = ty.as_unit_product().and_then(|_| ty.cpp_type()).unwrap_or_else(|| "auto".into());
+            format!("{} {} = {};", cpp_ty, ident(name), compile_expression(value, ctx))
+        }
+        Expression::LocalVariableAssignment { name, value } => {
+            format!("{} = {}", ident(name), compile_expression(value, ctx))
         }
         Expression::ReadLocalVariable { name, .. } => ident(name),
         Expression::StructFieldAccess { base, name } => match base.ty(ctx) {
@@ -2388,6 +2492,21 @@ fn compile_expression(expr: &llr::Expression, ctx: &EvaluationContext) -> String
                 (Type::Brush, Type::Color) => {
                     format!("{}.color()", f)
                 }
+                (Type::Optional(from_inner), Type::Optional(_)) if *from_inner == Type::Void => {
+                    // from `none`
+                    if matches!(from.as_ref(), Expression::NoneLiteral) {
+                        format!("{}()", to.cpp_type().unwrap())
+                    } else {
+                        format!("({}, {}())", f, to.cpp_type().unwrap())
+                    }
+                }
+                (Type::Optional(_), Type::Optional(_)) => f,
+                (_, Type::Optional(_)) => {
+                    format!("{}({})", to.cpp_type().unwrap(), f)
+                }
+                (Type::Optional(_), _) => {
+                    format!("({}).value_or({}{{}})", f, to.cpp_type().unwrap())
+                }
                 (Type::Struct { .. }, Type::Struct{ fields, name: Some(_), ..}) => {
                     format!(
                         "[&](const auto &o){{ {struct_name} s; {fields} return s; }}({obj})",
@@ -2561,6 +2680,25 @@ fn compile_expression(expr: &llr::Expression, ctx: &EvaluationContext) -> String
                 false_code
             )
         }
+        Expression::Match { value, arms, default } => {
+            let ty = expr.ty(ctx);
+            let value_code = compile_expression(value, ctx);
+            let value_code = remove_parentheses(&value_code);
+            let mut cases = String::new();
+            for (values, e) in arms {
+                for v in values {
+                    write!(cases, "case {}: ", compile_expression(&Expression::EnumerationValue(v.clone()), ctx)).unwrap();
+                }
+                write!(cases, "{{ {}; }} break; ", return_compile_expression(e, ctx, Some(&ty))).unwrap();
+            }
+            format!(
+                r#"[&]() -> {} {{ switch ({}) {{ {}default: {{ {}; }} }}}}()"#,
+                ty.cpp_type().unwrap_or_else(|| "void".to_string()),
+                value_code,
+                cases,
+                return_compile_expression(default, ctx, Some(&ty)),
+            )
+        }
         Expression::Array { element_ty, values, as_model } => {
             let ty = element_ty.cpp_type().unwrap();
             let mut val = values.iter().map(|e| format!("{ty} ( {expr} )", expr = compile_expression(e, ctx), ty = ty));
@@ -2643,6 +2781,47 @@ fn compile_expression(expr: &llr::Expression, ctx: &EvaluationContext) -> String
             compile_expression(expr, ctx)
         ),
         Expression::ReturnStatement(None) => "throw slint::private_api::ReturnWrapper<void>()".to_owned(),
+        Expression::ArrayOperation { array, operation, lambda } => {
+            let array_code = compile_expression(array, ctx);
+            let Some((item, body)) = lambda else {
+                return format!("slint::private_api::array_reverse({})", array_code);
+            };
+            let item_ty = match array.ty(ctx) {
+                Type::Array(ty) => ty.cpp_type().unwrap_or_else(|| "auto".into()),
+                _ => "auto".into(),
+            };
+            let body_ty = body.ty(ctx);
+            let mut body_code = compile_expression(body, ctx);
+            let function = match operation {
+                crate::expression_tree::ArrayOperation::Filter => "array_filter".into(),
+                crate::expression_tree::ArrayOperation::Map => {
+                    format!("array_map<{}>", body_ty.cpp_type().unwrap_or_default())
+                }
+                crate::expression_tree::ArrayOperation::SortBy => {
+                    if body_ty != Type::String {
+                        body_code = format!("double({})", body_code);
+                    }
+                    "array_sort_by".into()
+                }
+                crate::expression_tree::ArrayOperation::FindIndex => "array_find_index".into(),
+                crate::expression_tree::ArrayOperation::Reverse => unreachable!(),
+            };
+            // The lambda of the adapter models outlives the evaluation of the expression
+            let capture = match operation {
+                crate::expression_tree::ArrayOperation::FindIndex => "&",
+                _ if ctx.current_global.is_some() => "=, this",
+                _ => "=",
+            };
+            format!(
+                "slint::private_api::{}({}, [{}](const {} &{}) {{ return {}; }})",
+                function, array_code, capture, item_ty, ident(item), body_code
+            )
+        }
+        Expression::WhileLoop { condition, body } => {
+            let cond_code = compile_expression(condition, ctx);
+            let cond_code = remove_parentheses(&cond_code);
+            format!("[&]{{ while ({}) {{ {}; }} }}()", cond_code, compile_expression(body, ctx))
+        }
         Expression::LayoutCacheAccess { layout_cache_prop, index, repeater_index } =>  {
             let cache = access_member(layout_cache_prop, ctx);
             if let Some(ri) = repeater_index {
@@ -2718,12 +2897,31 @@ fn compile_builtin_function_call(
         BuiltinFunction::Pow => {
             format!("std::pow(({}), ({}))", a.next().unwrap(), a.next().unwrap())
         }
+        BuiltinFunction::Ln => format!("std::log({})", a.next().unwrap()),
+        BuiltinFunction::Exp => format!("std::exp({})", a.next().unwrap()),
+        BuiltinFunction::Sign => {
+            format!(
+                "[](double x){{ return x == 0. ? 0. : std::copysign(1., x); }}({})",
+                a.next().unwrap()
+            )
+        }
+        BuiltinFunction::Clamp => {
+            format!(
+                "std::min<double>(std::max<double>(({}), ({})), ({}))",
+                a.next().unwrap(),
+                a.next().unwrap(),
+                a.next().unwrap()
+            )
+        }
         BuiltinFunction::Sin => format!("std::sin(({}) * {})", a.next().unwrap(), pi_180),
         BuiltinFunction::Cos => format!("std::cos(({}) * {})", a.next().unwrap(), pi_180),
         BuiltinFunction::Tan => format!("std::tan(({}) * {})", a.next().unwrap(), pi_180),
         BuiltinFunction::ASin => format!("std::asin({}) / {}", a.next().unwrap(), pi_180),
         BuiltinFunction::ACos => format!("std::acos({}) / {}", a.next().unwrap(), pi_180),
         BuiltinFunction::ATan => format!("std::atan({}) / {}", a.next().unwrap(), pi_180),
+        BuiltinFunction::ATan2 => {
+            format!("std::atan2({}, {}) / {}", a.next().unwrap(), a.next().unwrap(), pi_180)
+        }
         BuiltinFunction::SetFocusItem => {
             if let [llr::Expression::PropertyReference(pr)] = arguments {
                 let window = access_window_field(ctx);
@@ -2748,6 +2946,43 @@ fn compile_builtin_function_call(
         BuiltinFunction::StringToFloat => {
             format!("[](const auto &a){{ auto e1 = std::end(a); auto e2 = const_cast<char*>(e1); auto r = std::strtod(std::begin(a), &e2); return e1 == e2 ? r : 0; }}({})", a.next().unwrap())
         }
+        BuiltinFunction::StringCharacterCount => {
+            format!("slint::private_api::string_character_count({})", a.next().unwrap())
+        }
+        BuiltinFunction::StringToUppercase => {
+            format!("slint::private_api::string_to_uppercase({})", a.next().unwrap())
+        }
+        BuiltinFunction::StringToLowercase => {
+            format!("slint::private_api::string_to_lowercase({})", a.next().unwrap())
+        }
+        BuiltinFunction::StringTrim => {
+            format!("slint::private_api::string_trim({})", a.next().unwrap())
+        }
+        BuiltinFunction::StringContains => {
+            format!(
+                "slint::private_api::string_contains({}, {})",
+                a.next().unwrap(),
+                a.next().unwrap()
+            )
+        }
+        BuiltinFunction::StringStartsWith => {
+            format!("std::string_view({}).starts_with({})", a.next().unwrap(), a.next().unwrap())
+        }
+        BuiltinFunction::StringEndsWith => {
+            format!("std::string_view({}).ends_with({})", a.next().unwrap(), a.next().unwrap())
+        }
+        BuiltinFunction::StringSubstring => {
+            format!("slint::private_api::string_substring({})", a.join(", "))
+        }
+        BuiltinFunction::StringReplace => {
+            format!("slint::private_api::string_replace({})", a.join(", "))
+        }
+        BuiltinFunction::StringSplit => {
+            format!("slint::private_api::string_split({})", a.join(", "))
+        }
+        BuiltinFunction::NumberToFixed => {
+            format!("slint::private_api::number_to_fixed({})", a.join(", "))
+        }
         BuiltinFunction::ColorBrighter => {
             format!("{}.brighter({})", a.next().unwrap(), a.next().unwrap())
         }
@@ -2763,6 +2998,12 @@ fn compile_builtin_function_call(
         BuiltinFunction::ColorWithAlpha => {
             format!("{}.with_alpha({})", a.next().unwrap(), a.next().unwrap())
         }
+        BuiltinFunction::ColorHsvaStruct => {
+            format!("[](const auto &c){{ return std::make_tuple(c.alpha, c.hue, c.saturation, c.value); }}({}.to_hsva())", a.next().unwrap())
+        }
+        BuiltinFunction::ColorHslaStruct => {
+            format!("[](const auto &c){{ return std::make_tuple(c.alpha, c.hue, c.lightness, c.saturation); }}({}.to_hsla())", a.next().unwrap())
+        }
         BuiltinFunction::ImageSize => {
             format!("{}.size()", a.next().unwrap())
         }
@@ -2778,6 +3019,24 @@ fn compile_builtin_function_call(
                 a = a.next().unwrap(),
             )
         }
+        BuiltinFunction::Hsv => {
+            format!(
+                "slint::Color::from_hsva({}, {}, {}, {})",
+                a.next().unwrap(),
+                a.next().unwrap(),
+                a.next().unwrap(),
+                a.next().unwrap()
+            )
+        }
+        BuiltinFunction::Hsl => {
+            format!(
+                "slint::Color::from_hsla({}, {}, {}, {})",
+                a.next().unwrap(),
+                a.next().unwrap(),
+                a.next().unwrap(),
+                a.next().unwrap()
+            )
+        }
         BuiltinFunction::DarkColorScheme => {
             format!("{}.dark_color_scheme()", access_window_field(ctx))
         }
@@ -2788,7 +3047,7 @@ fn compile_builtin_function_call(
             format!("{}.text_input_focused()", access_window_field(ctx))
         }
         BuiltinFunction::ShowPopupWindow => {
-            if let [llr::Expression::NumberLiteral(popup_index), x, y, close_on_click, llr::Expression::PropertyReference(parent_ref)] =
+            if let [llr::Expression::NumberLiteral(popup_index), x, y, close_on_click, llr::Expression::BoolLiteral(is_tooltip), llr::Expression::PropertyReference(parent_ref)] =
                 arguments
             {
                 let mut parent_ctx = ctx;
@@ -2808,18 +3067,30 @@ fn compile_builtin_function_call(
                 let parent_component = access_item_rc(parent_ref, ctx);
                 let x = compile_expression(x, ctx);
                 let y = compile_expression(y, ctx);
-                let close_on_click = compile_expression(close_on_click, ctx);
-                format!(
-                    "{window}.show_popup<{popup_window_id}>({component_access}, {{ static_cast<float>({x}), static_cast<float>({y}) }}, {close_on_click}, {{ {parent_component} }})"
-                )
+                if *is_tooltip {
+                    format!(
+                        "{window}.show_tooltip<{popup_window_id}>({component_access}, {{ static_cast<float>({x}), static_cast<float>({y}) }}, {{ {parent_component} }})"
+                    )
+                } else {
+                    let close_on_click = compile_expression(close_on_click, ctx);
+                    format!(
+                        "{window}.show_popup<{popup_window_id}>({component_access}, {{ static_cast<float>({x}), static_cast<float>({y}) }}, {close_on_click}, {{ {parent_component} }})"
+                    )
+                }
             } else {
                 panic!("internal error: invalid args to ShowPopupWindow {:?}", arguments)
             }
         }
         BuiltinFunction::ClosePopupWindow => {
-            let window = access_window_field(ctx);
-            format!("{window}.close_popup()")
+            if let [llr::Expression::PropertyReference(parent_ref)] = arguments {
+                let window = access_window_field(ctx);
+                let parent_component = access_item_rc(parent_ref, ctx);
+                format!("{window}.close_popup({{ {parent_component} }})")
+            } else {
+                panic!("internal error: invalid args to ClosePopupWindow {:?}", arguments)
+            }
         }
+        BuiltinFunction::UpdateTimers => "self->update_timers()".into(),
         BuiltinFunction::ItemMemberFunction(name) => {
             if let [llr::Expression::PropertyReference(pr)] = arguments {
                 let item = access_member(pr, ctx);
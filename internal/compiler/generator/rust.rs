@@ -23,7 +23,7 @@
 use itertools::Either;
 use proc_macro2::{Ident, TokenStream};
 use quote::{format_ident, quote};
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet};
 use std::num::NonZeroUsize;
 use std::str::FromStr;
 
@@ -92,6 +92,10 @@ fn rust_primitive_type(ty: &Type) -> Option<proc_macro2::TokenStream> {
             let inner = rust_primitive_type(o)?;
             Some(quote!(slint::private_unstable_api::re_exports::ModelRc<#inner>))
         }
+        Type::Optional(o) => {
+            let inner = rust_primitive_type(o)?;
+            Some(quote!(Option<#inner>))
+        }
         Type::Enumeration(e) => {
             let i = ident(&e.name);
             if e.node.is_some() {
@@ -306,6 +310,7 @@ pub fn generate(doc: &Document) -> TokenStream {
         #[allow(clippy::style)]
         #[allow(clippy::complexity)]
         #[allow(unused_braces)]
+        #[allow(unused_mut)] // All local variables are mutable
         #[allow(clippy::erasing_op)]
         #[allow(clippy::approx_constant)] // We may get those from .slint inputs!
         #[allow(clippy::eq_op)] // The generated code will compare/subtract/etc. equal values
@@ -877,6 +882,9 @@ fn generate_sub_component(
         }
     }
 
+    let mut element_infos = component.element_infos.clone();
+    let mut element_infos_branch = vec![];
+
     let mut user_init_code: Vec<TokenStream> = Vec::new();
 
     let mut sub_component_names: Vec<Ident> = vec![];
@@ -953,10 +961,35 @@ fn generate_sub_component(
             ));
         }
 
+        // The root item of the sub-component has both the infos of the element that instantiates it, and of its root element
+        if let Some(infos) = element_infos.remove(&local_tree_index) {
+            element_infos_branch.push(quote!(
+                #local_tree_index => {
+                    let mut infos = slint::private_unstable_api::re_exports::SharedString::from(#infos);
+                    if let Some(sub_infos) = #sub_compo_field.apply_pin(_self).item_element_infos(0) {
+                        infos += "\n";
+                        infos += &sub_infos;
+                    }
+                    Some(infos)
+                }
+            ));
+        }
+        if !sub.ty.element_infos.is_empty() && sub_items_count > 1 {
+            let range_begin = local_index_of_first_child as usize;
+            let range_end = range_begin + sub_items_count - 2;
+            element_infos_branch.push(quote!(
+                #range_begin..=#range_end => #sub_compo_field.apply_pin(_self).item_element_infos(index - #range_begin + 1),
+            ));
+        }
+
         sub_component_names.push(field_name);
         sub_component_types.push(sub_component_id);
     }
 
+    for (index, infos) in element_infos {
+        element_infos_branch.push(quote!(#index => Some(#infos.into()),));
+    }
+
     for (prop1, prop2) in &component.two_way_bindings {
         let p1 = access_member(prop1, &ctx);
         let p2 = access_member(prop2, &ctx);
@@ -996,6 +1029,77 @@ fn generate_sub_component(
         quote!(#code;)
     }));
 
+    let mut change_tracker_names = vec![];
+    for (idx, (prop, handler)) in component.change_callbacks.iter().enumerate() {
+        let change_tracker_id = format_ident!("change_tracker{}", idx);
+        let value = compile_expression(&llr::Expression::PropertyReference(prop.clone()), &ctx);
+        let handler = compile_expression(&handler.borrow(), &ctx);
+        user_init_code.push(quote! {
+            _self.#change_tracker_id.init(
+                {
+                    let self_weak = _self.self_weak.get().unwrap().clone();
+                    move || {
+                        let self_rc = self_weak.upgrade().unwrap();
+                        let _self = self_rc.as_pin_ref();
+                        #value
+                    }
+                },
+                {
+                    let self_weak = _self.self_weak.get().unwrap().clone();
+                    move || {
+                        let self_rc = self_weak.upgrade().unwrap();
+                        let _self = self_rc.as_pin_ref();
+                        #handler;
+                    }
+                },
+            );
+        });
+        change_tracker_names.push(change_tracker_id);
+    }
+
+    let mut timer_names = vec![];
+    let mut update_timers = vec![];
+    for (idx, timer) in component.timers.iter().enumerate() {
+        let timer_id = format_ident!("timer{}", idx);
+        let interval = compile_expression(&timer.interval.borrow(), &ctx);
+        let running = compile_expression(&timer.running.borrow(), &ctx);
+        let triggered = compile_expression(&timer.triggered.borrow(), &ctx);
+        update_timers.push(quote! {
+            {
+                let interval = (#interval) as i64;
+                if #running && interval > 0 {
+                    let interval = core::time::Duration::from_millis(interval as u64);
+                    if !_self.#timer_id.running() || _self.#timer_id.interval() != Some(interval) {
+                        let self_weak = _self.self_weak.get().unwrap().clone();
+                        _self.#timer_id.start(
+                            slint::private_unstable_api::re_exports::TimerMode::Repeated,
+                            interval,
+                            move || {
+                                if let Some(self_rc) = self_weak.upgrade() {
+                                    let _self = self_rc.as_pin_ref();
+                                    #triggered;
+                                }
+                            },
+                        );
+                    }
+                } else {
+                    _self.#timer_id.stop();
+                }
+            }
+        });
+        timer_names.push(timer_id);
+    }
+    let update_timers_fn = (!update_timers.is_empty()).then(|| {
+        user_init_code.push(quote!(_self.update_timers();));
+        quote! {
+            fn update_timers(self: ::core::pin::Pin<&Self>) {
+                #![allow(unused)]
+                let _self = self;
+                #(#update_timers)*
+            }
+        }
+    });
+
     let layout_info_h = compile_expression(&component.layout_info_h.borrow(), &ctx);
     let layout_info_v = compile_expression(&component.layout_info_v.borrow(), &ctx);
 
@@ -1027,6 +1131,8 @@ struct #inner_component_id {
             #(#declared_property_vars : slint::private_unstable_api::re_exports::Property<#declared_property_types>,)*
             #(#declared_callbacks : slint::private_unstable_api::re_exports::Callback<(#(#declared_callbacks_types,)*), #declared_callbacks_ret>,)*
             #(#repeated_element_names : slint::private_unstable_api::re_exports::Repeater<#repeated_element_components>,)*
+            #(#change_tracker_names : slint::private_unstable_api::re_exports::ChangeTracker,)*
+            #(#timer_names : slint::private_unstable_api::re_exports::Timer,)*
             self_weak : slint::private_unstable_api::re_exports::OnceCell<slint::private_unstable_api::re_exports::VWeakMapped<slint::private_unstable_api::re_exports::ComponentVTable, #inner_component_id>>,
             #(parent : #parent_component_type,)*
             root : slint::private_unstable_api::re_exports::OnceCell<slint::private_unstable_api::re_exports::VWeak<slint::private_unstable_api::re_exports::ComponentVTable, #root_component_id>>,
@@ -1124,6 +1230,17 @@ fn accessible_string_property(
                 }
             }
 
+            fn item_element_infos(self: ::core::pin::Pin<&Self>, index: usize) -> Option<slint::private_unstable_api::re_exports::SharedString> {
+                #![allow(unused)]
+                let _self = self;
+                match index {
+                    #(#element_infos_branch)*
+                    _ => None,
+                }
+            }
+
+            #update_timers_fn
+
             #(#declared_functions)*
         }
 
@@ -1409,6 +1526,8 @@ fn maybe_window_adapter(
             })
         })
     });
+    let shortcut_index = sub_tree.shortcut_index();
+    let shortcut_index_len = shortcut_index.len();
     let mut item_tree_array = vec![];
     let mut item_array = vec![];
     sub_tree.tree.visit_in_array(&mut |node, children_offset, parent_index| {
@@ -1567,6 +1686,27 @@ fn accessible_string_property(
             ) {
                 *result = self.accessible_string_property(index, what);
             }
+
+            fn item_element_infos(
+                self: ::core::pin::Pin<&Self>,
+                index: usize,
+                result: &mut slint::private_unstable_api::re_exports::SharedString,
+            ) -> bool {
+                match self.item_element_infos(index) {
+                    Some(infos) => {
+                        *result = infos;
+                        true
+                    }
+                    None => false,
+                }
+            }
+
+            fn get_shortcut_index(
+                self: ::core::pin::Pin<&Self>) -> slint::private_unstable_api::re_exports::Slice<u32>
+            {
+                const SHORTCUT_INDEX : [u32; #shortcut_index_len] = [#(#shortcut_index),*];
+                (&SHORTCUT_INDEX[..]).into()
+            }
         }
 
 
@@ -1913,6 +2053,48 @@ fn access_item_rc(pr: &llr::PropertyReference, ctx: &EvaluationContext) -> Token
     }
 }
 
+/// The lambda of `filter`, `map` and `sort-by` is kept by the adapter model and called after the
+/// evaluation of the expression, so the closure must not borrow anything.
+/// Returns the code that goes before the `move` closure to capture `_self` as a weak reference
+/// and clone the local variables and arguments used in `body`, and the code that restores `_self`
+/// at the beginning of the closure.
+fn array_lambda_captures(
+    item: &str,
+    body: &Expression,
+    ctx: &EvaluationContext,
+) -> (TokenStream, TokenStream) {
+    let mut locals = BTreeSet::new();
+    let mut declared = BTreeSet::from([item.to_owned()]);
+    let mut uses_args = false;
+    body.visit_recursive(&mut |e| match e {
+        Expression::ReadLocalVariable { name, .. } => {
+            locals.insert(name.clone());
+        }
+        Expression::StoreLocalVariable { name, .. } => {
+            declared.insert(name.clone());
+        }
+        Expression::ArrayOperation { lambda: Some((item, _)), .. } => {
+            declared.insert(item.clone());
+        }
+        Expression::FunctionParameterReference { .. } => uses_args = true,
+        _ => {}
+    });
+    let locals = locals.difference(&declared).map(|l| ident(l));
+    let args = uses_args.then(|| quote!(let args = args.clone();));
+    if let Some(global) = ctx.current_global {
+        let global_id = format_ident!("global_{}", ident(&global.name));
+        (
+            quote!(#(let #locals = #locals.clone();)* #args let root_weak = _self.root.get().unwrap().clone();),
+            quote!(let root_rc = root_weak.upgrade().unwrap(); let _self = root_rc.globals.#global_id.as_ref();),
+        )
+    } else {
+        (
+            quote!(#(let #locals = #locals.clone();)* #args let self_weak = _self.self_weak.get().unwrap().clone();),
+            quote!(let self_rc = self_weak.upgrade().unwrap(); let _self = self_rc.as_pin_ref();),
+        )
+    }
+}
+
 fn compile_expression(expr: &Expression, ctx: &EvaluationContext) -> TokenStream {
     match expr {
         Expression::StringLiteral(s) => {
@@ -1920,6 +2102,7 @@ fn compile_expression(expr: &Expression, ctx: &EvaluationContext) -> TokenStream
         }
         Expression::NumberLiteral(n) => quote!(#n),
         Expression::BoolLiteral(b) => quote!(#b),
+        Expression::NoneLiteral => quote!(Option::<()>::None),
         Expression::Cast { from, to } => {
             let f = compile_expression(&*from, ctx);
             match (from.ty(ctx), to) {
@@ -1940,6 +2123,37 @@ fn compile_expression(expr: &Expression, ctx: &EvaluationContext) -> TokenStream
                 (Type::Brush, Type::Color) => {
                     quote!(#f.color())
                 }
+                (Type::Optional(from_inner), Type::Optional(_)) if *from_inner == Type::Void => {
+                    // from `none`
+                    let t = rust_primitive_type(to).unwrap();
+                    if matches!(from.as_ref(), Expression::NoneLiteral) {
+                        quote!(<#t>::None)
+                    } else {
+                        quote!({ #f; <#t>::None })
+                    }
+                }
+                (Type::Optional(_), Type::Optional(_)) => f,
+                (_, Type::Optional(inner)) => {
+                    let t = rust_primitive_type(inner).unwrap();
+                    if matches!(
+                        **inner,
+                        Type::Int32
+                            | Type::Float32
+                            | Type::Duration
+                            | Type::PhysicalLength
+                            | Type::LogicalLength
+                            | Type::Angle
+                            | Type::Percent
+                            | Type::Rem
+                    ) {
+                        quote!(Some((#f) as #t))
+                    } else {
+                        quote!(Some(#f))
+                    }
+                }
+                (Type::Optional(_), _) => {
+                    quote!((#f).unwrap_or_default())
+                }
                 (Type::Struct { ref fields, .. }, Type::Struct { name: Some(n), .. }) => {
                     let fields = fields.iter().enumerate().map(|(index, (name, _))| {
                         let index = proc_macro2::Literal::usize_unsuffixed(index);
@@ -2171,6 +2385,43 @@ fn compile_expression(expr: &Expression, ctx: &EvaluationContext) -> TokenStream
                 )
             }
         },
+        Expression::ArrayOperation { array, operation, lambda } => {
+            let array_code = compile_expression(array, ctx);
+            let Some((item, body)) = lambda else {
+                return quote!(slint::private_unstable_api::re_exports::reverse_rows(&#array_code));
+            };
+            let body_ty = body.ty(ctx);
+            let body_code = compile_expression(body, ctx);
+            let (captures, restore_self) = array_lambda_captures(item, body, ctx);
+            let item = ident(item);
+            match operation {
+                crate::expression_tree::ArrayOperation::Filter => quote!(
+                    slint::private_unstable_api::re_exports::filter_rows(&#array_code, { #captures move |#item| { #restore_self #body_code } })
+                ),
+                crate::expression_tree::ArrayOperation::Map => {
+                    let rust_element_ty = rust_primitive_type(&body_ty).unwrap();
+                    quote!(slint::private_unstable_api::re_exports::map_rows::<_, #rust_element_ty>(
+                        &#array_code,
+                        { #captures move |#item| { #restore_self (#body_code) as _ } }
+                    ))
+                }
+                crate::expression_tree::ArrayOperation::SortBy => {
+                    let key = if body_ty == Type::String { quote!() } else { quote!(as f64) };
+                    quote!(
+                        slint::private_unstable_api::re_exports::sort_rows_by_key(&#array_code, { #captures move |#item| { #restore_self (#body_code) #key } })
+                    )
+                }
+                crate::expression_tree::ArrayOperation::FindIndex => quote!(
+                    slint::private_unstable_api::re_exports::find_row(&#array_code, |#item| #body_code)
+                ),
+                crate::expression_tree::ArrayOperation::Reverse => unreachable!(),
+            }
+        }
+        Expression::WhileLoop { condition, body } => {
+            let condition_code = compile_expression(condition, ctx);
+            let body_code = compile_expression(body, ctx);
+            quote!(while #condition_code { #body_code; })
+        }
         Expression::Condition { condition, true_expr, false_expr } => {
             let condition_code = compile_expression(&*condition, ctx);
             let true_code = compile_expression(&*true_expr, ctx);
@@ -2183,6 +2434,24 @@ fn compile_expression(expr: &Expression, ctx: &EvaluationContext) -> TokenStream
                 }
             )
         }
+        Expression::Match { value, arms, default } => {
+            let value_code = compile_expression(value, ctx);
+            let arms_code = arms.iter().enumerate().map(|(i, (values, e))| {
+                let patterns = values
+                    .iter()
+                    .map(|v| compile_expression(&Expression::EnumerationValue(v.clone()), ctx));
+                let code = compile_expression(e, ctx);
+                let code = if i == 0 { code } else { quote!((#code) as _) };
+                quote!(#(#patterns)|* => { #code })
+            });
+            let default_code = compile_expression(default, ctx);
+            let default_code =
+                if arms.is_empty() { default_code } else { quote!((#default_code) as _) };
+            quote!(match #value_code {
+                #(#arms_code)*
+                _ => { #default_code }
+            })
+        }
         Expression::Array { values, element_ty, as_model } => {
             let val = values.iter().map(|e| compile_expression(e, ctx));
             if *as_model {
@@ -2227,9 +2496,24 @@ fn compile_expression(expr: &Expression, ctx: &EvaluationContext) -> TokenStream
         }
 
         Expression::StoreLocalVariable { name, value } => {
+            let ty = value.ty(ctx);
+            let value = compile_expression(value, ctx);
+            let name = ident(name);
+            // Numbers get an explicit type so that assigning another number later keeps that type
+            match rust_primitive_type(&ty).filter(|_| ty.as_unit_product().is_some()) {
+                Some(rust_ty) => quote!(let mut #name: #rust_ty = (#value) as _;),
+                None => quote!(let mut #name = #value;),
+            }
+        }
+        Expression::LocalVariableAssignment { name, value } => {
+            let is_number = value.ty(ctx).as_unit_product().is_some();
             let value = compile_expression(value, ctx);
             let name = ident(name);
-            quote!(let #name = #value;)
+            if is_number {
+                quote!(#name = (#value) as _;)
+            } else {
+                quote!(#name = #value;)
+            }
         }
         Expression::ReadLocalVariable { name, .. } => {
             let name = ident(name);
@@ -2338,7 +2622,7 @@ fn compile_builtin_function_call(
             }
         }
         BuiltinFunction::ShowPopupWindow => {
-            if let [Expression::NumberLiteral(popup_index), x, y, close_on_click, Expression::PropertyReference(parent_ref)] =
+            if let [Expression::NumberLiteral(popup_index), x, y, close_on_click, Expression::BoolLiteral(is_tooltip), Expression::PropertyReference(parent_ref)] =
                 arguments
             {
                 let mut parent_ctx = ctx;
@@ -2357,29 +2641,49 @@ fn compile_builtin_function_call(
                 let parent_component = access_item_rc(parent_ref, ctx);
                 let x = compile_expression(x, ctx);
                 let y = compile_expression(y, ctx);
-                let close_on_click = compile_expression(close_on_click, ctx);
                 let window_adapter_tokens = access_window_adapter_field(ctx);
-                quote!(
-                    slint::private_unstable_api::re_exports::WindowInner::from_pub(#window_adapter_tokens.window()).show_popup(
-                        &VRc::into_dyn({
-                            let instance = #popup_window_id::new(#component_access_tokens.self_weak.get().unwrap().clone());
-                            #popup_window_id::user_init(slint::private_unstable_api::re_exports::VRc::map(instance.clone(), |x| x));
-                            instance.into()
-                        }),
-                        Point::new(#x as slint::private_unstable_api::re_exports::Coord, #y as slint::private_unstable_api::re_exports::Coord),
-                        #close_on_click,
-                        #parent_component
+                let popup_instance = quote!(&VRc::into_dyn({
+                    let instance = #popup_window_id::new(#component_access_tokens.self_weak.get().unwrap().clone());
+                    #popup_window_id::user_init(slint::private_unstable_api::re_exports::VRc::map(instance.clone(), |x| x));
+                    instance.into()
+                }));
+                let position = quote!(Point::new(#x as slint::private_unstable_api::re_exports::Coord, #y as slint::private_unstable_api::re_exports::Coord));
+                if *is_tooltip {
+                    quote!(
+                        slint::private_unstable_api::re_exports::WindowInner::from_pub(#window_adapter_tokens.window()).show_tooltip(
+                            #popup_instance,
+                            #position,
+                            #parent_component
+                        )
                     )
-                )
+                } else {
+                    let close_on_click = compile_expression(close_on_click, ctx);
+                    quote!(
+                        slint::private_unstable_api::re_exports::WindowInner::from_pub(#window_adapter_tokens.window()).show_popup(
+                            #popup_instance,
+                            #position,
+                            #close_on_click,
+                            #parent_component
+                        )
+                    )
+                }
             } else {
                 panic!("internal error: invalid args to ShowPopupWindow {:?}", arguments)
             }
         }
         BuiltinFunction::ClosePopupWindow => {
-            let window_adapter_tokens = access_window_adapter_field(ctx);
-            quote!(
-                slint::private_unstable_api::re_exports::WindowInner::from_pub(#window_adapter_tokens.window()).close_popup()
-            )
+            if let [Expression::PropertyReference(parent_ref)] = arguments {
+                let parent_component = access_item_rc(parent_ref, ctx);
+                let window_adapter_tokens = access_window_adapter_field(ctx);
+                quote!(
+                    slint::private_unstable_api::re_exports::WindowInner::from_pub(#window_adapter_tokens.window()).close_popup_from(#parent_component)
+                )
+            } else {
+                panic!("internal error: invalid args to ClosePopupWindow {:?}", arguments)
+            }
+        }
+        BuiltinFunction::UpdateTimers => {
+            quote!(_self.update_timers())
         }
         BuiltinFunction::ItemMemberFunction(name) => {
             if let [Expression::PropertyReference(pr)] = arguments {
@@ -2468,10 +2772,67 @@ fn compile_builtin_function_call(
             let (a1, a2) = (a.next().unwrap(), a.next().unwrap());
             quote!((#a1 as f64).powf(#a2 as f64))
         }
+        BuiltinFunction::ATan2 => {
+            let (a1, a2) = (a.next().unwrap(), a.next().unwrap());
+            quote!((#a1 as f64).atan2(#a2 as f64).to_degrees())
+        }
+        BuiltinFunction::Ln => quote!((#(#a)* as f64).ln()),
+        BuiltinFunction::Exp => quote!((#(#a)* as f64).exp()),
+        BuiltinFunction::Sign => {
+            quote!(match #(#a)* as f64 { x if x == 0. => 0., x => x.signum() })
+        }
+        BuiltinFunction::Clamp => {
+            let (x, min, max) = (a.next().unwrap(), a.next().unwrap(), a.next().unwrap());
+            quote!((#x as f64).max(#min as f64).min(#max as f64))
+        }
         BuiltinFunction::StringToFloat => {
             quote!(#(#a)*.as_str().parse::<f64>().unwrap_or_default())
         }
         BuiltinFunction::StringIsFloat => quote!(#(#a)*.as_str().parse::<f64>().is_ok()),
+        BuiltinFunction::StringCharacterCount => {
+            quote!(slint::private_unstable_api::re_exports::string::character_count(&#(#a)*) as i32)
+        }
+        BuiltinFunction::StringToUppercase => {
+            quote!(slint::private_unstable_api::re_exports::SharedString::from(#(#a)*.to_uppercase()))
+        }
+        BuiltinFunction::StringToLowercase => {
+            quote!(slint::private_unstable_api::re_exports::SharedString::from(#(#a)*.to_lowercase()))
+        }
+        BuiltinFunction::StringTrim => {
+            quote!(slint::private_unstable_api::re_exports::SharedString::from(#(#a)*.trim()))
+        }
+        BuiltinFunction::StringContains => {
+            let (s, pattern) = (a.next().unwrap(), a.next().unwrap());
+            quote!(#s.contains(#pattern.as_str()))
+        }
+        BuiltinFunction::StringStartsWith => {
+            let (s, pattern) = (a.next().unwrap(), a.next().unwrap());
+            quote!(#s.starts_with(#pattern.as_str()))
+        }
+        BuiltinFunction::StringEndsWith => {
+            let (s, pattern) = (a.next().unwrap(), a.next().unwrap());
+            quote!(#s.ends_with(#pattern.as_str()))
+        }
+        BuiltinFunction::StringSubstring => {
+            let (s, start, end) = (a.next().unwrap(), a.next().unwrap(), a.next().unwrap());
+            quote!(slint::private_unstable_api::re_exports::string::substring(&#s, #start as i32, #end as i32))
+        }
+        BuiltinFunction::StringReplace => {
+            let (s, from, to) = (a.next().unwrap(), a.next().unwrap(), a.next().unwrap());
+            quote!(slint::private_unstable_api::re_exports::SharedString::from(#s.replace(#from.as_str(), &#to)))
+        }
+        BuiltinFunction::StringSplit => {
+            let (s, separator) = (a.next().unwrap(), a.next().unwrap());
+            quote!(slint::private_unstable_api::re_exports::ModelRc::new(
+                slint::private_unstable_api::re_exports::VecModel::from(
+                    slint::private_unstable_api::re_exports::string::split(&#s, &#separator)
+                )
+            ))
+        }
+        BuiltinFunction::NumberToFixed => {
+            let (n, precision) = (a.next().unwrap(), a.next().unwrap());
+            quote!(slint::private_unstable_api::re_exports::string::format_number_fixed(#n as f64, #precision as i32))
+        }
         BuiltinFunction::ColorBrighter => {
             let x = a.next().unwrap();
             let factor = a.next().unwrap();
@@ -2498,6 +2859,12 @@ fn compile_builtin_function_call(
             let alpha = a.next().unwrap();
             quote!(#x.with_alpha(#alpha as f32))
         }
+        BuiltinFunction::ColorHsvaStruct => {
+            quote!(match #(#a)*.to_hsva() { c => (c.alpha, c.hue, c.saturation, c.value) })
+        }
+        BuiltinFunction::ColorHslaStruct => {
+            quote!(match #(#a)*.to_hsla() { c => (c.alpha, c.hue, c.lightness, c.saturation) })
+        }
         BuiltinFunction::ImageSize => quote!( #(#a)*.size()),
         BuiltinFunction::ArrayLength => {
             quote!(match &#(#a)* { x => {
@@ -2517,6 +2884,16 @@ fn compile_builtin_function_call(
                 slint::private_unstable_api::re_exports::Color::from_argb_u8(a, r, g, b)
             })
         }
+        BuiltinFunction::Hsv => {
+            let (h, s, v, a) =
+                (a.next().unwrap(), a.next().unwrap(), a.next().unwrap(), a.next().unwrap());
+            quote!(slint::private_unstable_api::re_exports::Color::from_hsva(#h as f32, #s as f32, #v as f32, #a as f32))
+        }
+        BuiltinFunction::Hsl => {
+            let (h, s, l, a) =
+                (a.next().unwrap(), a.next().unwrap(), a.next().unwrap(), a.next().unwrap());
+            quote!(slint::private_unstable_api::re_exports::Color::from_hsla(#h as f32, #s as f32, #l as f32, #a as f32))
+        }
         BuiltinFunction::DarkColorScheme => {
             let window_adapter_tokens = access_window_adapter_field(ctx);
             quote!(slint::private_unstable_api::re_exports::WindowInner::from_pub(#window_adapter_tokens.window()).dark_color_scheme())
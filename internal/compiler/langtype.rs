@@ -53,6 +53,9 @@ pub enum Type {
     Brush,
     /// This is usually a model
     Array(Box<Type>),
+    /// `T?` in .slint: either a value of the inner type, or no value.
+    /// The type of the `none` literal is `Optional(Void)`
+    Optional(Box<Type>),
     Struct {
         fields: BTreeMap<String, Type>,
         /// When declared in .slint as  `struct Foo := { }`, then the name is "Foo"
@@ -106,6 +109,7 @@ fn eq(&self, other: &Self) -> bool {
             Type::Easing => matches!(other, Type::Easing),
             Type::Brush => matches!(other, Type::Brush),
             Type::Array(a) => matches!(other, Type::Array(b) if a == b),
+            Type::Optional(a) => matches!(other, Type::Optional(b) if a == b),
             Type::Struct { fields, name, node: _, rust_attributes: _ } => {
                 matches!(other, Type::Struct{fields:f,name:n,node:_, rust_attributes: _ } if fields == f && name == n)
             }
@@ -165,6 +169,8 @@ fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
             Type::Bool => write!(f, "bool"),
             Type::Model => write!(f, "model"),
             Type::Array(t) => write!(f, "[{}]", t),
+            Type::Optional(t) if **t == Type::Void => write!(f, "none"),
+            Type::Optional(t) => write!(f, "{}?", t),
             Type::Struct { name: Some(name), .. } => write!(f, "{}", name),
             Type::Struct { fields, name: None, .. } => {
                 write!(f, "{{ ")?;
@@ -223,6 +229,7 @@ pub fn is_property_type(&self) -> bool {
                 | Self::ElementReference
                 | Self::Struct { .. }
                 | Self::Array(_)
+                | Self::Optional(_)
                 | Self::Brush
                 | Self::InferredProperty
         )
@@ -283,6 +290,9 @@ pub fn can_convert(&self, other: &Self) -> bool {
             (Type::Struct { fields: a, .. }, Type::Struct { fields: b, .. }) => {
                 can_convert_struct(a, b)
             }
+            (Type::Optional(a), Type::Optional(b)) => **a == Type::Void || a.can_convert(b),
+            (Type::Optional(_), _) => false,
+            (a, Type::Optional(b)) => **b != Type::Void && a.can_convert(b),
             (Type::UnitProduct(u), o) => match o.as_unit_product() {
                 Some(o) => unit_product_length_conversion(u.as_slice(), o.as_slice()).is_some(),
                 None => false,
@@ -322,6 +332,7 @@ pub fn default_unit(&self) -> Option<Unit> {
             Type::Easing => None,
             Type::Brush => None,
             Type::Array(_) => None,
+            Type::Optional(_) => None,
             Type::Struct { .. } => None,
             Type::Enumeration(_) => None,
             Type::UnitProduct(_) => None,
@@ -80,6 +80,10 @@ pub struct CompilerConfiguration {
 
     /// The domain used as one of the parameter to the translate function
     pub translation_domain: Option<String>,
+
+    /// Keep the type names and ids of the elements, so that they can be found
+    /// at runtime, for example by the testing API
+    pub debug_info: bool,
 }
 
 impl CompilerConfiguration {
@@ -117,6 +121,8 @@ pub fn new(output_format: crate::generator::OutputFormat) -> Self {
             Err(_) => output_format == crate::generator::OutputFormat::Interpreter,
         };
 
+        let debug_info = std::env::var_os("SLINT_EMIT_DEBUG_INFO").is_some();
+
         let scale_factor = std::env::var("SLINT_SCALE_FACTOR")
             .ok()
             .and_then(|x| x.parse::<f64>().ok())
@@ -132,6 +138,7 @@ pub fn new(output_format: crate::generator::OutputFormat) -> Self {
             scale_factor,
             accessibility: true,
             translation_domain: None,
+            debug_info,
         }
     }
 }
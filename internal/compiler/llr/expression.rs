@@ -2,7 +2,7 @@
 // SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-1.0 OR LicenseRef-Slint-commercial
 
 use super::PropertyReference;
-use crate::expression_tree::{BuiltinFunction, OperatorClass};
+use crate::expression_tree::{ArrayOperation, BuiltinFunction, OperatorClass};
 use crate::langtype::Type;
 use crate::layout::Orientation;
 use itertools::Either;
@@ -16,6 +16,8 @@ pub enum Expression {
     NumberLiteral(f64),
     /// Bool
     BoolLiteral(bool),
+    /// The `none` literal, of type `Type::Optional(Type::Void)`
+    NoneLiteral,
 
     /// Reference to a property (which can also be a callback) or an element (property name is empty then).
     PropertyReference(PropertyReference),
@@ -101,6 +103,11 @@ pub enum Expression {
         index: Box<Expression>,
         value: Box<Expression>,
     },
+    /// An assignment of a new value to a local variable previously stored with [`Self::StoreLocalVariable`]
+    LocalVariableAssignment {
+        name: String,
+        value: Box<Expression>,
+    },
 
     BinaryExpression {
         lhs: Box<Expression>,
@@ -125,6 +132,13 @@ pub enum Expression {
         false_expr: Box<Expression>,
     },
 
+    /// Evaluate the arm that matches the value of an enumeration, or the default otherwise
+    Match {
+        value: Box<Expression>,
+        arms: Vec<(Vec<crate::langtype::EnumerationValue>, Expression)>,
+        default: Box<Expression>,
+    },
+
     Array {
         element_ty: Type,
         values: Vec<Expression>,
@@ -153,6 +167,20 @@ pub enum Expression {
 
     ReturnStatement(Option<Box<Expression>>),
 
+    /// An operation over all the rows of an array, evaluating the lambda for each row
+    /// with the row stored in a local variable
+    ArrayOperation {
+        array: Box<Expression>,
+        operation: ArrayOperation,
+        lambda: Option<(String, Box<Expression>)>,
+    },
+
+    /// Evaluate the body as long as the condition is true
+    WhileLoop {
+        condition: Box<Expression>,
+        body: Box<Expression>,
+    },
+
     LayoutCacheAccess {
         layout_cache_prop: PropertyReference,
         index: usize,
@@ -217,6 +245,9 @@ pub fn default_value_for_type(ty: &Type) -> Option<Self> {
                 values: vec![],
                 as_model: true,
             },
+            Type::Optional(_) => {
+                Expression::Cast { from: Box::new(Expression::NoneLiteral), to: ty.clone() }
+            }
             Type::Struct { fields, .. } => Expression::Struct {
                 ty: ty.clone(),
                 values: fields
@@ -240,6 +271,7 @@ pub fn ty(&self, ctx: &dyn TypeResolutionContext) -> Type {
             Self::StringLiteral(_) => Type::String,
             Self::NumberLiteral(_) => Type::Float32,
             Self::BoolLiteral(_) => Type::Bool,
+            Self::NoneLiteral => Type::Optional(Box::new(Type::Void)),
             Self::PropertyReference(prop) => ctx.property_ty(prop).clone(),
             Self::FunctionParameterReference { index } => ctx.arg_type(*index).clone(),
             Self::StoreLocalVariable { .. } => Type::Void,
@@ -270,6 +302,7 @@ pub fn ty(&self, ctx: &dyn TypeResolutionContext) -> Type {
             Self::PropertyAssignment { .. } => Type::Void,
             Self::ModelDataAssignment { .. } => Type::Void,
             Self::ArrayIndexAssignment { .. } => Type::Void,
+            Self::LocalVariableAssignment { .. } => Type::Void,
             Self::BinaryExpression { lhs, rhs: _, op } => {
                 if crate::expression_tree::operator_class(*op) != OperatorClass::ArithmeticOp {
                     Type::Bool
@@ -280,6 +313,7 @@ pub fn ty(&self, ctx: &dyn TypeResolutionContext) -> Type {
             Self::UnaryOp { sub, .. } => sub.ty(ctx),
             Self::ImageReference { .. } => Type::Image,
             Self::Condition { true_expr, .. } => true_expr.ty(ctx),
+            Self::Match { default, .. } => default.ty(ctx),
             Self::Array { element_ty, .. } => Type::Array(element_ty.clone().into()),
             Self::Struct { ty, .. } => ty.clone(),
             Self::EasingCurve(_) => Type::Easing,
@@ -287,6 +321,16 @@ pub fn ty(&self, ctx: &dyn TypeResolutionContext) -> Type {
             Self::RadialGradient { .. } => Type::Brush,
             Self::EnumerationValue(e) => Type::Enumeration(e.enumeration.clone()),
             Self::ReturnStatement(_) => Type::Invalid,
+            Self::ArrayOperation { array, operation, lambda } => match operation {
+                ArrayOperation::Map => Type::Array(Box::new(
+                    lambda.as_ref().map_or(Type::Invalid, |(_, body)| body.ty(ctx)),
+                )),
+                ArrayOperation::FindIndex => Type::Int32,
+                ArrayOperation::Filter | ArrayOperation::SortBy | ArrayOperation::Reverse => {
+                    array.ty(ctx)
+                }
+            },
+            Self::WhileLoop { .. } => Type::Void,
             Self::LayoutCacheAccess { .. } => Type::LogicalLength,
             Self::BoxLayoutFunction { sub_expression, .. } => sub_expression.ty(ctx),
             Self::ComputeDialogLayoutCells { .. } => {
@@ -302,6 +346,7 @@ macro_rules! visit_impl {
             Expression::StringLiteral(_) => {}
             Expression::NumberLiteral(_) => {}
             Expression::BoolLiteral(_) => {}
+            Expression::NoneLiteral => {}
             Expression::PropertyReference(_) => {}
             Expression::FunctionParameterReference { .. } => {}
             Expression::StoreLocalVariable { value, .. } => $visitor(value),
@@ -326,6 +371,7 @@ macro_rules! visit_impl {
                 $visitor(index);
                 $visitor(value);
             }
+            Expression::LocalVariableAssignment { value, .. } => $visitor(value),
             Expression::BinaryExpression { lhs, rhs, .. } => {
                 $visitor(lhs);
                 $visitor(rhs);
@@ -339,6 +385,11 @@ macro_rules! visit_impl {
                 $visitor(true_expr);
                 $visitor(false_expr);
             }
+            Expression::Match { value, arms, default } => {
+                $visitor(value);
+                arms.$iter().for_each(|(_, e)| $visitor(e));
+                $visitor(default);
+            }
             Expression::Array { values, .. } => values.$iter().for_each($visitor),
             Expression::Struct { values, .. } => values.$values().for_each($visitor),
             Expression::EasingCurve(_) => {}
@@ -361,6 +412,16 @@ macro_rules! visit_impl {
                     $visitor(r);
                 }
             }
+            Expression::ArrayOperation { array, lambda, .. } => {
+                $visitor(array);
+                if let Some((_, body)) = lambda {
+                    $visitor(body);
+                }
+            }
+            Expression::WhileLoop { condition, body } => {
+                $visitor(condition);
+                $visitor(body);
+            }
             Expression::LayoutCacheAccess { repeater_index, .. } => {
                 if let Some(repeater_index) = repeater_index {
                     $visitor(repeater_index);
@@ -217,6 +217,14 @@ fn visit_in_array_recursive(
     }
 }
 
+/// A `Timer` element, lowered by the lower_timers pass
+#[derive(Debug)]
+pub struct Timer {
+    pub interval: MutExpression,
+    pub running: MutExpression,
+    pub triggered: MutExpression,
+}
+
 #[derive(Debug)]
 pub struct SubComponent {
     pub name: String,
@@ -225,6 +233,7 @@ pub struct SubComponent {
     pub items: Vec<Item>,
     pub repeated: Vec<RepeatedElement>,
     pub popup_windows: Vec<ItemTree>,
+    pub timers: Vec<Timer>,
     pub sub_components: Vec<SubComponentInstance>,
     /// The initial value or binding for properties.
     /// This is ordered in the order they must be set.
@@ -235,6 +244,8 @@ pub struct SubComponent {
     pub const_properties: Vec<PropertyReference>,
     /// Code that is run in the sub component constructor, after property initializations
     pub init_code: Vec<MutExpression>,
+    /// The `changed` callbacks: the handler is run when the value of the property changes
+    pub change_callbacks: Vec<(PropertyReference, MutExpression)>,
 
     pub layout_info_h: MutExpression,
     pub layout_info_v: MutExpression,
@@ -242,6 +253,10 @@ pub struct SubComponent {
     /// Maps (item_index, property) to an expression
     pub accessible_prop: BTreeMap<(usize, String), MutExpression>,
 
+    /// Maps item index to the type names and ids of the elements it comes from, one `TypeName::id` per line.
+    /// Only filled when compiled with debug info.
+    pub element_infos: BTreeMap<usize, String>,
+
     pub prop_analysis: HashMap<PropertyReference, PropAnalysis>,
 }
 
@@ -303,6 +318,31 @@ pub struct ItemTree {
     pub parent_context: Option<String>,
 }
 
+impl ItemTree {
+    /// The indices in the item tree of the `Shortcut` items, and of the dynamic nodes of the
+    /// repeaters whose components contain some
+    pub fn shortcut_index(&self) -> Vec<u32> {
+        let mut result = vec![];
+        let mut index = 0;
+        self.tree.visit_in_array(&mut |node, _, _| {
+            let mut sub_component = &self.root;
+            for i in &node.sub_component_path {
+                sub_component = &sub_component.sub_components[*i].ty;
+            }
+            let has_shortcut = if node.repeated {
+                !sub_component.repeated[node.item_index].sub_tree.shortcut_index().is_empty()
+            } else {
+                sub_component.items[node.item_index].ty.class_name == "Shortcut"
+            };
+            if has_shortcut {
+                result.push(index);
+            }
+            index += 1;
+        });
+        result
+    }
+}
+
 #[derive(Debug)]
 pub struct PublicComponent {
     pub public_properties: PublicProperties,
@@ -351,6 +391,14 @@ pub fn for_each_expression<'a>(
             for e in &sc.init_code {
                 visitor(e, ctx);
             }
+            for (_, e) in &sc.change_callbacks {
+                visitor(e, ctx);
+            }
+            for t in &sc.timers {
+                visitor(&t.interval, ctx);
+                visitor(&t.running, ctx);
+                visitor(&t.triggered, ctx);
+            }
             for (_, e) in &sc.property_init {
                 visitor(&e.expression, ctx);
             }
@@ -68,6 +68,7 @@ pub fn lower_expression(
             llr_Expression::NumberLiteral(unit.normalize(*n))
         }
         tree_Expression::BoolLiteral(b) => llr_Expression::BoolLiteral(*b),
+        tree_Expression::NoneLiteral => llr_Expression::NoneLiteral,
         tree_Expression::CallbackReference(nr, _)
         | tree_Expression::PropertyReference(nr)
         | tree_Expression::FunctionReference(nr, _) => {
@@ -116,6 +117,9 @@ pub fn lower_expression(
             tree_Expression::BuiltinFunctionReference(BuiltinFunction::ShowPopupWindow, _) => {
                 lower_show_popup(arguments, ctx)
             }
+            tree_Expression::BuiltinFunctionReference(BuiltinFunction::ClosePopupWindow, _) => {
+                lower_close_popup(arguments, ctx)
+            }
             tree_Expression::BuiltinFunctionReference(f, _) => {
                 let mut arguments =
                     arguments.iter().map(|e| lower_expression(e, ctx)).collect::<Vec<_>>();
@@ -157,6 +161,33 @@ pub fn lower_expression(
                 false_expr: lower_expression(false_expr, ctx).into(),
             }
         }
+        tree_Expression::Match { value, arms, default } => {
+            let enumeration = match value.ty() {
+                Type::Enumeration(e) => e,
+                _ => panic!("match over a value that is not an enumeration"),
+            };
+            let mut arms = arms
+                .iter()
+                .map(|(values, e)| {
+                    let values = values
+                        .iter()
+                        .map(|v| EnumerationValue { value: *v, enumeration: enumeration.clone() })
+                        .collect();
+                    (values, lower_expression(e, ctx))
+                })
+                .collect::<Vec<_>>();
+            // The generated code always has a default arm, so that it also works with
+            // enumerations that are `#[non_exhaustive]`
+            let default = match default {
+                Some(default) => lower_expression(default, ctx),
+                None => arms.pop().expect("match expression without arms").1,
+            };
+            llr_Expression::Match {
+                value: Box::new(lower_expression(value, ctx)),
+                arms,
+                default: Box::new(default),
+            }
+        }
         tree_Expression::Array { element_ty, values } => llr_Expression::Array {
             element_ty: element_ty.clone(),
             values: values.iter().map(|e| lower_expression(e, ctx)).collect::<_>(),
@@ -188,6 +219,19 @@ pub fn lower_expression(
         tree_Expression::ReturnStatement(x) => {
             llr_Expression::ReturnStatement(x.as_ref().map(|e| lower_expression(e, ctx).into()))
         }
+        tree_Expression::ArrayOperation { array, operation, lambda } => {
+            llr_Expression::ArrayOperation {
+                array: Box::new(lower_expression(array, ctx)),
+                operation: *operation,
+                lambda: lambda
+                    .as_ref()
+                    .map(|(item, body)| (item.clone(), Box::new(lower_expression(body, ctx)))),
+            }
+        }
+        tree_Expression::WhileLoop { condition, body } => llr_Expression::WhileLoop {
+            condition: Box::new(lower_expression(condition, ctx)),
+            body: Box::new(lower_expression(body, ctx)),
+        },
         tree_Expression::LayoutCacheAccess { layout_cache_prop, index, repeater_index } => {
             llr_Expression::LayoutCacheAccess {
                 layout_cache_prop: ctx.map_property_reference(layout_cache_prop),
@@ -286,6 +330,20 @@ fn lower_assignment(
 
             llr_Expression::ModelDataAssignment { level, value }
         }
+        tree_Expression::ReadLocalVariable { name, ty } => {
+            let rhs = lower_expression(rhs, ctx);
+            let value = Box::new(if op == '=' {
+                rhs
+            } else {
+                llr_Expression::BinaryExpression {
+                    lhs: llr_Expression::ReadLocalVariable { name: name.clone(), ty: ty.clone() }
+                        .into(),
+                    rhs: rhs.into(),
+                    op,
+                }
+            });
+            llr_Expression::LocalVariableAssignment { name: name.clone(), value }
+        }
         tree_Expression::ArrayIndex { array, index } => {
             let rhs = lower_expression(rhs, ctx);
             let array = Box::new(lower_expression(array, ctx));
@@ -335,45 +393,63 @@ fn repeater_special_property(
     llr_Expression::PropertyReference(r)
 }
 
+/// Calls `f` with the index of the popup in the popup list of its parent component, and the popup itself
+fn with_popup_window<R>(
+    popup_window: &ElementRc,
+    f: impl FnOnce(usize, &crate::object_tree::PopupWindow) -> R,
+) -> R {
+    let pop_comp = popup_window.borrow().enclosing_component.upgrade().unwrap();
+    let parent_component =
+        pop_comp.parent_element.upgrade().unwrap().borrow().enclosing_component.upgrade().unwrap();
+    let popup_list = parent_component.popup_windows.borrow();
+    let (popup_index, popup) =
+        popup_list.iter().enumerate().find(|(_, p)| Rc::ptr_eq(&p.component, &pop_comp)).unwrap();
+    f(popup_index, popup)
+}
+
 fn lower_show_popup(args: &[tree_Expression], ctx: &ExpressionContext) -> llr_Expression {
     if let [tree_Expression::ElementReference(e)] = args {
-        let popup_window = e.upgrade().unwrap();
-        let pop_comp = popup_window.borrow().enclosing_component.upgrade().unwrap();
-        let parent_component = pop_comp
-            .parent_element
-            .upgrade()
-            .unwrap()
-            .borrow()
-            .enclosing_component
-            .upgrade()
-            .unwrap();
-        let popup_list = parent_component.popup_windows.borrow();
-        let (popup_index, popup) = popup_list
-            .iter()
-            .enumerate()
-            .find(|(_, p)| Rc::ptr_eq(&p.component, &pop_comp))
-            .unwrap();
-        let x = llr_Expression::PropertyReference(ctx.map_property_reference(&popup.x));
-        let y = llr_Expression::PropertyReference(ctx.map_property_reference(&popup.y));
-        let item_ref = lower_expression(
-            &tree_Expression::ElementReference(Rc::downgrade(&popup.parent_element)),
-            ctx,
-        );
-        llr_Expression::BuiltinFunctionCall {
-            function: BuiltinFunction::ShowPopupWindow,
-            arguments: vec![
-                llr_Expression::NumberLiteral(popup_index as _),
-                x,
-                y,
-                llr_Expression::BoolLiteral(popup.close_on_click),
-                item_ref,
-            ],
-        }
+        with_popup_window(&e.upgrade().unwrap(), |popup_index, popup| {
+            let x = llr_Expression::PropertyReference(ctx.map_property_reference(&popup.x));
+            let y = llr_Expression::PropertyReference(ctx.map_property_reference(&popup.y));
+            let item_ref = lower_expression(
+                &tree_Expression::ElementReference(Rc::downgrade(&popup.parent_element)),
+                ctx,
+            );
+            llr_Expression::BuiltinFunctionCall {
+                function: BuiltinFunction::ShowPopupWindow,
+                arguments: vec![
+                    llr_Expression::NumberLiteral(popup_index as _),
+                    x,
+                    y,
+                    llr_Expression::BoolLiteral(popup.close_on_click),
+                    llr_Expression::BoolLiteral(popup.is_tooltip),
+                    item_ref,
+                ],
+            }
+        })
     } else {
         panic!("invalid arguments to ShowPopupWindow");
     }
 }
 
+fn lower_close_popup(args: &[tree_Expression], ctx: &ExpressionContext) -> llr_Expression {
+    if let [tree_Expression::ElementReference(e)] = args {
+        with_popup_window(&e.upgrade().unwrap(), |_, popup| {
+            let item_ref = lower_expression(
+                &tree_Expression::ElementReference(Rc::downgrade(&popup.parent_element)),
+                ctx,
+            );
+            llr_Expression::BuiltinFunctionCall {
+                function: BuiltinFunction::ClosePopupWindow,
+                arguments: vec![item_ref],
+            }
+        })
+    } else {
+        panic!("invalid arguments to ClosePopupWindow");
+    }
+}
+
 pub fn lower_animation(a: &PropertyAnimation, ctx: &ExpressionContext<'_>) -> Animation {
     fn lower_animation_element(a: &ElementRc, ctx: &ExpressionContext<'_>) -> llr_Expression {
         llr_Expression::Struct {
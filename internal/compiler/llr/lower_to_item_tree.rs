@@ -185,21 +185,25 @@ fn lower_sub_component(
         items: Default::default(),
         repeated: Default::default(),
         popup_windows: Default::default(),
+        timers: Default::default(),
         sub_components: Default::default(),
         property_init: Default::default(),
         animations: Default::default(),
         two_way_bindings: Default::default(),
         const_properties: Default::default(),
         init_code: Default::default(),
+        change_callbacks: Default::default(),
         // just initialize to dummy expression right now and it will be set later
         layout_info_h: super::Expression::BoolLiteral(false).into(),
         layout_info_v: super::Expression::BoolLiteral(false).into(),
         accessible_prop: Default::default(),
+        element_infos: Default::default(),
         prop_analysis: Default::default(),
     };
     let mut mapping = LoweredSubComponentMapping::default();
     let mut repeated = vec![];
     let mut accessible_prop = Vec::new();
+    let mut change_callbacks = Vec::new();
 
     if let Some(parent) = component.parent_element.upgrade() {
         // Add properties for the model data and index
@@ -306,6 +310,11 @@ fn lower_sub_component(
                 crate::generator::to_pascal_case(key.strip_prefix("accessible-").unwrap());
             accessible_prop.push((*elem.item_index.get().unwrap(), enum_value, nr.clone()));
         }
+        change_callbacks.extend(elem.change_callbacks.iter().cloned());
+        if !elem.debug.is_empty() {
+            let infos = elem.debug.iter().map(|info| info.encode()).collect::<Vec<_>>();
+            sub_component.element_infos.insert(*elem.item_index.get().unwrap(), infos.join("\n"));
+        }
         Some(element.clone())
     });
     let ctx = ExpressionContext { mapping: &mapping, state, parent: parent_context, component };
@@ -393,6 +402,8 @@ fn lower_sub_component(
         .map(|popup| lower_popup_component(&popup.component, &ctx))
         .collect();
 
+    sub_component.timers = component.timers.borrow().iter().map(|t| lower_timer(t, &ctx)).collect();
+
     crate::generator::for_each_const_properties(component, |elem, n| {
         let x = ctx.map_property_reference(&NamedReference::new(elem, n));
         sub_component.const_properties.push(x);
@@ -405,6 +416,16 @@ fn lower_sub_component(
         .map(|e| super::lower_expression::lower_expression(e, &ctx).into())
         .collect();
 
+    sub_component.change_callbacks = change_callbacks
+        .into_iter()
+        .map(|(nr, handler)| {
+            (
+                ctx.map_property_reference(&nr),
+                super::lower_expression::lower_expression(&handler, &ctx).into(),
+            )
+        })
+        .collect();
+
     sub_component.layout_info_h = super::lower_expression::get_layout_info(
         &component.root_element,
         &ctx,
@@ -529,6 +550,20 @@ fn lower_popup_component(component: &Rc<Component>, ctx: &ExpressionContext) ->
     }
 }
 
+fn lower_timer(timer: &crate::object_tree::Timer, ctx: &ExpressionContext) -> Timer {
+    Timer {
+        interval: super::Expression::PropertyReference(ctx.map_property_reference(&timer.interval))
+            .into(),
+        running: super::Expression::PropertyReference(ctx.map_property_reference(&timer.running))
+            .into(),
+        triggered: super::Expression::CallBackCall {
+            callback: ctx.map_property_reference(&timer.triggered),
+            arguments: vec![],
+        }
+        .into(),
+    }
+}
+
 fn lower_global(
     global: &Rc<Component>,
     global_index: usize,
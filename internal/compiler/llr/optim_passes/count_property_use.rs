@@ -58,6 +58,15 @@ pub fn count_property_use(root: &PublicComponent) {
         for expr in &sc.init_code {
             expr.borrow().visit_recursive(&mut |e| visit_expression(e, ctx));
         }
+        for (p, expr) in &sc.change_callbacks {
+            visit_property(p, ctx);
+            expr.borrow().visit_recursive(&mut |e| visit_expression(e, ctx));
+        }
+        for t in &sc.timers {
+            t.interval.borrow().visit_recursive(&mut |e| visit_expression(e, ctx));
+            t.running.borrow().visit_recursive(&mut |e| visit_expression(e, ctx));
+            t.triggered.borrow().visit_recursive(&mut |e| visit_expression(e, ctx));
+        }
         // 4. the models
         for (idx, r) in sc.repeated.iter().enumerate() {
             r.model.borrow().visit_recursive(&mut |e| visit_expression(e, ctx));
@@ -25,6 +25,7 @@ fn expression_cost(exp: &Expression, ctx: &EvaluationContext) -> isize {
         Expression::StringLiteral(_) => ALLOC_COST,
         Expression::NumberLiteral(_) => 0,
         Expression::BoolLiteral(_) => 0,
+        Expression::NoneLiteral => 0,
         Expression::PropertyReference(_) => PROPERTY_ACCESS_COST,
         Expression::FunctionParameterReference { .. } => return isize::MAX,
         Expression::StoreLocalVariable { .. } => 0,
@@ -40,10 +41,12 @@ fn expression_cost(exp: &Expression, ctx: &EvaluationContext) -> isize {
         Expression::PropertyAssignment { .. } => return isize::MAX,
         Expression::ModelDataAssignment { .. } => return isize::MAX,
         Expression::ArrayIndexAssignment { .. } => return isize::MAX,
+        Expression::LocalVariableAssignment { .. } => 0,
         Expression::BinaryExpression { .. } => 1,
         Expression::UnaryOp { .. } => 1,
         Expression::ImageReference { .. } => 1,
         Expression::Condition { .. } => 10,
+        Expression::Match { .. } => 10,
         Expression::Array { .. } => ALLOC_COST,
         Expression::Struct { .. } => 1,
         Expression::EasingCurve(_) => 1,
@@ -51,6 +54,8 @@ fn expression_cost(exp: &Expression, ctx: &EvaluationContext) -> isize {
         Expression::RadialGradient { .. } => ALLOC_COST,
         Expression::EnumerationValue(_) => 0,
         Expression::ReturnStatement(_) => 1,
+        Expression::ArrayOperation { .. } => return isize::MAX,
+        Expression::WhileLoop { .. } => return isize::MAX,
         Expression::LayoutCacheAccess { .. } => PROPERTY_ACCESS_COST,
         Expression::BoxLayoutFunction { .. } => return isize::MAX,
         Expression::ComputeDialogLayoutCells { .. } => return isize::MAX,
@@ -84,21 +89,42 @@ fn builtin_function_cost(function: &BuiltinFunction) -> isize {
         BuiltinFunction::ACos => 10,
         BuiltinFunction::ASin => 10,
         BuiltinFunction::ATan => 10,
+        BuiltinFunction::ATan2 => 10,
         BuiltinFunction::Log => 10,
+        BuiltinFunction::Ln => 10,
         BuiltinFunction::Pow => 10,
+        BuiltinFunction::Exp => 10,
+        BuiltinFunction::Sign => 10,
+        BuiltinFunction::Clamp => 10,
         BuiltinFunction::SetFocusItem => isize::MAX,
         BuiltinFunction::ShowPopupWindow | BuiltinFunction::ClosePopupWindow => isize::MAX,
+        BuiltinFunction::UpdateTimers => isize::MAX,
         BuiltinFunction::ItemMemberFunction(..) => isize::MAX,
         BuiltinFunction::StringToFloat => 50,
         BuiltinFunction::StringIsFloat => 50,
+        BuiltinFunction::StringCharacterCount => 50,
+        BuiltinFunction::StringToUppercase => 50,
+        BuiltinFunction::StringToLowercase => 50,
+        BuiltinFunction::StringTrim => 50,
+        BuiltinFunction::StringContains => 50,
+        BuiltinFunction::StringStartsWith => 50,
+        BuiltinFunction::StringEndsWith => 50,
+        BuiltinFunction::StringSubstring => 50,
+        BuiltinFunction::StringReplace => 50,
+        BuiltinFunction::StringSplit => 50,
+        BuiltinFunction::NumberToFixed => 50,
         BuiltinFunction::ColorBrighter => 50,
         BuiltinFunction::ColorDarker => 50,
         BuiltinFunction::ColorTransparentize => 50,
         BuiltinFunction::ColorMix => 50,
         BuiltinFunction::ColorWithAlpha => 50,
+        BuiltinFunction::ColorHsvaStruct => 50,
+        BuiltinFunction::ColorHslaStruct => 50,
         BuiltinFunction::ImageSize => 50,
         BuiltinFunction::ArrayLength => 50,
         BuiltinFunction::Rgb => 50,
+        BuiltinFunction::Hsv => 50,
+        BuiltinFunction::Hsl => 50,
         BuiltinFunction::ImplicitLayoutInfo(_) => isize::MAX,
         BuiltinFunction::ItemAbsolutePosition => isize::MAX,
         BuiltinFunction::RegisterCustomFontByPath => isize::MAX,
@@ -162,6 +162,7 @@ fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result {
             Expression::StringLiteral(x) => write!(f, "{:?}", x),
             Expression::NumberLiteral(x) => write!(f, "{:?}", x),
             Expression::BoolLiteral(x) => write!(f, "{:?}", x),
+            Expression::NoneLiteral => write!(f, "none"),
             Expression::PropertyReference(x) => write!(f, "{}", DisplayPropertyRef(x, ctx)),
             Expression::FunctionParameterReference { index } => write!(f, "arg_{}", index),
             Expression::StoreLocalVariable { name, value } => {
@@ -205,6 +206,9 @@ fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result {
             Expression::ArrayIndexAssignment { array, index, value } => {
                 write!(f, "{}[{}] = {}", e(array), e(index), e(value))
             }
+            Expression::LocalVariableAssignment { name, value } => {
+                write!(f, "{} = {}", name, e(value))
+            }
             Expression::BinaryExpression { lhs, rhs, op } => {
                 write!(f, "({} {} {})", e(lhs), op, e(rhs))
             }
@@ -213,6 +217,15 @@ fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result {
             Expression::Condition { condition, true_expr, false_expr } => {
                 write!(f, "({} ? {} : {})", e(condition), e(true_expr), e(false_expr))
             }
+            Expression::Match { value, arms, default } => write!(
+                f,
+                "match ({}) {{ {}_ => {} }}",
+                e(value),
+                arms.iter()
+                    .map(|(values, x)| format!("{} => {}, ", values.iter().join(" | "), e(x)))
+                    .join(""),
+                e(default)
+            ),
             Expression::Array { values, .. } => {
                 write!(f, "[{}]", values.iter().map(e).join(", "))
             }
@@ -236,6 +249,15 @@ fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result {
             Expression::EnumerationValue(x) => write!(f, "{}", x),
             Expression::ReturnStatement(Some(x)) => write!(f, "return {}", e(x)),
             Expression::ReturnStatement(None) => f.write_str("return"),
+            Expression::ArrayOperation { array, operation, lambda: Some((item, body)) } => {
+                write!(f, "{}.{:?}({} => {})", e(array), operation, item, e(body))
+            }
+            Expression::ArrayOperation { array, operation, lambda: None } => {
+                write!(f, "{}.{:?}()", e(array), operation)
+            }
+            Expression::WhileLoop { condition, body } => {
+                write!(f, "while ({}) {}", e(condition), e(body))
+            }
             Expression::LayoutCacheAccess { layout_cache_prop, index, repeater_index: None } => {
                 write!(f, "{}[{}]", DisplayPropertyRef(layout_cache_prop, ctx), index)
             }
@@ -7,7 +7,7 @@
 
 use crate::diagnostics::{BuildDiagnostics, Spanned};
 use crate::expression_tree::{
-    BuiltinFunction, BuiltinMacroFunction, EasingCurve, Expression, Unit,
+    ArrayOperation, BuiltinFunction, BuiltinMacroFunction, EasingCurve, Expression, Unit,
 };
 use crate::langtype::{ElementType, Enumeration, EnumerationValue, Type};
 use crate::namedreference::NamedReference;
@@ -34,6 +34,14 @@ pub struct LookupCtx<'a> {
     /// The name of the arguments of the callback or function
     pub arguments: Vec<String>,
 
+    /// The local variables declared with `let` (or by a `for` loop) that are in scope,
+    /// one entry per nested code block
+    pub local_variables: Vec<Vec<(String, Type)>>,
+
+    /// The number of local variables introduced by the compiler (for example by a `for` loop)
+    /// so far, to give each of them a unique name
+    pub generated_local_variables: usize,
+
     /// The type register in which to look for Globals
     pub type_register: &'a TypeRegister,
 
@@ -54,6 +62,8 @@ pub fn empty_context(type_register: &'a TypeRegister, diag: &'a mut BuildDiagnos
             component_scope: Default::default(),
             diag,
             arguments: Default::default(),
+            local_variables: Default::default(),
+            generated_local_variables: 0,
             type_register,
             type_loader: None,
             current_token: None,
@@ -70,6 +80,13 @@ pub fn return_type(&self) -> &Type {
         }
     }
 
+    /// Returns a number that makes the name of a local variable introduced by the compiler
+    /// unique within the expression being resolved
+    pub fn unique_local_variable_suffix(&mut self) -> usize {
+        self.generated_local_variables += 1;
+        self.generated_local_variables - 1
+    }
+
     pub fn is_legacy_component(&self) -> bool {
         self.component_scope.first().map_or(false, |e| e.borrow().is_legacy_syntax)
     }
@@ -185,6 +202,29 @@ fn lookup(&self, ctx: &LookupCtx, name: &str) -> Option<LookupResult> {
     }
 }
 
+/// The name of the local variable that holds the value of the `let` variable with the given name
+pub fn local_variable_name(name: &str) -> String {
+    format!("local_{name}")
+}
+
+struct LocalVariableLookup;
+impl LookupObject for LocalVariableLookup {
+    fn for_each_entry<R>(
+        &self,
+        ctx: &LookupCtx,
+        f: &mut impl FnMut(&str, LookupResult) -> Option<R>,
+    ) -> Option<R> {
+        for (name, ty) in ctx.local_variables.iter().rev().flatten() {
+            let e =
+                Expression::ReadLocalVariable { name: local_variable_name(name), ty: ty.clone() };
+            if let Some(r) = f(name, e.into()) {
+                return Some(r);
+            }
+        }
+        None
+    }
+}
+
 struct ArgumentsLookup;
 impl LookupObject for ArgumentsLookup {
     fn for_each_entry<R>(
@@ -505,7 +545,7 @@ fn for_each_entry<R>(
         ctx: &LookupCtx,
         f: &mut impl FnMut(&str, LookupResult) -> Option<R>,
     ) -> Option<R> {
-        match ctx.return_type() {
+        match optional_inner_type(ctx.return_type()) {
             Type::Color => ColorSpecific.for_each_entry(ctx, f),
             Type::Brush => ColorSpecific.for_each_entry(ctx, f),
             Type::Easing => EasingSpecific.for_each_entry(ctx, f),
@@ -515,7 +555,7 @@ fn for_each_entry<R>(
     }
 
     fn lookup(&self, ctx: &LookupCtx, name: &str) -> Option<LookupResult> {
-        match ctx.return_type() {
+        match optional_inner_type(ctx.return_type()) {
             Type::Color => ColorSpecific.lookup(ctx, name),
             Type::Brush => ColorSpecific.lookup(ctx, name),
             Type::Easing => EasingSpecific.lookup(ctx, name),
@@ -525,6 +565,27 @@ fn lookup(&self, ctx: &LookupCtx, name: &str) -> Option<LookupResult> {
     }
 }
 
+/// The values that can be assigned to an optional are the values of its inner type
+fn optional_inner_type(ty: &Type) -> &Type {
+    match ty {
+        Type::Optional(inner) => inner,
+        ty => ty,
+    }
+}
+
+/// The `none` literal. It comes after the [`ReturnTypeSpecificLookup`] so that it doesn't shadow
+/// enumeration values called `none`
+struct NoneLookup;
+impl LookupObject for NoneLookup {
+    fn for_each_entry<R>(
+        &self,
+        _ctx: &LookupCtx,
+        f: &mut impl FnMut(&str, LookupResult) -> Option<R>,
+    ) -> Option<R> {
+        f("none", Expression::NoneLiteral.into())
+    }
+}
+
 struct ColorSpecific;
 impl LookupObject for ColorSpecific {
     fn for_each_entry<R>(
@@ -656,6 +717,11 @@ fn for_each_entry<R>(
             .or_else(|| f("atan", BuiltinFunctionReference(BuiltinFunction::ATan, sl())))
             .or_else(|| f("log", BuiltinFunctionReference(BuiltinFunction::Log, sl())))
             .or_else(|| f("pow", BuiltinFunctionReference(BuiltinFunction::Pow, sl())))
+            .or_else(|| f("clamp", BuiltinMacroReference(BuiltinMacroFunction::Clamp, t.clone())))
+            .or_else(|| f("atan2", BuiltinFunctionReference(BuiltinFunction::ATan2, sl())))
+            .or_else(|| f("exp", BuiltinFunctionReference(BuiltinFunction::Exp, sl())))
+            .or_else(|| f("ln", BuiltinFunctionReference(BuiltinFunction::Ln, sl())))
+            .or_else(|| f("sign", BuiltinFunctionReference(BuiltinFunction::Sign, sl())))
     }
 }
 
@@ -694,6 +760,10 @@ fn for_each_entry<R>(
         let mut f = |n, e: Expression| f(n, e.into());
         None.or_else(|| f("rgb", BuiltinMacroReference(BuiltinMacroFunction::Rgb, t.clone())))
             .or_else(|| f("rgba", BuiltinMacroReference(BuiltinMacroFunction::Rgb, t.clone())))
+            .or_else(|| f("hsv", BuiltinMacroReference(BuiltinMacroFunction::Hsv, t.clone())))
+            .or_else(|| f("hsva", BuiltinMacroReference(BuiltinMacroFunction::Hsv, t.clone())))
+            .or_else(|| f("hsl", BuiltinMacroReference(BuiltinMacroFunction::Hsl, t.clone())))
+            .or_else(|| f("hsla", BuiltinMacroReference(BuiltinMacroFunction::Hsl, t.clone())))
     }
 }
 
@@ -747,16 +817,22 @@ fn for_each_entry<R>(
 
 pub fn global_lookup() -> impl LookupObject {
     (
-        ArgumentsLookup,
+        LocalVariableLookup,
         (
-            SpecialIdLookup,
+            ArgumentsLookup,
             (
-                IdLookup,
+                SpecialIdLookup,
                 (
-                    InScopeLookup,
+                    IdLookup,
                     (
-                        LookupType,
-                        (BuiltinNamespaceLookup, (ReturnTypeSpecificLookup, BuiltinFunctionLookup)),
+                        InScopeLookup,
+                        (
+                            LookupType,
+                            (
+                                BuiltinNamespaceLookup,
+                                (ReturnTypeSpecificLookup, (NoneLookup, BuiltinFunctionLookup)),
+                            ),
+                        ),
                     ),
                 ),
             ),
@@ -789,9 +865,11 @@ fn for_each_entry<R>(
                     None
                 }
                 Type::String => StringExpression(self).for_each_entry(ctx, f),
+                Type::Float32 | Type::Int32 => NumberExpression(self).for_each_entry(ctx, f),
                 Type::Brush | Type::Color => ColorExpression(self).for_each_entry(ctx, f),
                 Type::Image => ImageExpression(self).for_each_entry(ctx, f),
                 Type::Array(_) => ArrayExpression(self).for_each_entry(ctx, f),
+                Type::Optional(_) => OptionalExpression(self).for_each_entry(ctx, f),
                 _ => None,
             },
         }
@@ -808,9 +886,11 @@ fn lookup(&self, ctx: &LookupCtx, name: &str) -> Option<LookupResult> {
                     })
                 }),
                 Type::String => StringExpression(self).lookup(ctx, name),
+                Type::Float32 | Type::Int32 => NumberExpression(self).lookup(ctx, name),
                 Type::Brush | Type::Color => ColorExpression(self).lookup(ctx, name),
                 Type::Image => ImageExpression(self).lookup(ctx, name),
                 Type::Array(_) => ArrayExpression(self).lookup(ctx, name),
+                Type::Optional(_) => OptionalExpression(self).lookup(ctx, name),
                 _ => None,
             },
         }
@@ -834,8 +914,48 @@ fn for_each_entry<R>(
                 )),
             })
         };
+        let function_call = |f: BuiltinFunction| {
+            LookupResult::from(Expression::FunctionCall {
+                function: Box::new(Expression::BuiltinFunctionReference(
+                    f,
+                    ctx.current_token.as_ref().map(|t| t.to_source_location()),
+                )),
+                source_location: ctx.current_token.as_ref().map(|t| t.to_source_location()),
+                arguments: vec![self.0.clone()],
+            })
+        };
         None.or_else(|| f("is-float", member_function(BuiltinFunction::StringIsFloat)))
             .or_else(|| f("to-float", member_function(BuiltinFunction::StringToFloat)))
+            .or_else(|| f("length", function_call(BuiltinFunction::StringCharacterCount)))
+            .or_else(|| f("to-uppercase", member_function(BuiltinFunction::StringToUppercase)))
+            .or_else(|| f("to-lowercase", member_function(BuiltinFunction::StringToLowercase)))
+            .or_else(|| f("trim", member_function(BuiltinFunction::StringTrim)))
+            .or_else(|| f("contains", member_function(BuiltinFunction::StringContains)))
+            .or_else(|| f("starts-with", member_function(BuiltinFunction::StringStartsWith)))
+            .or_else(|| f("ends-with", member_function(BuiltinFunction::StringEndsWith)))
+            .or_else(|| f("substring", member_function(BuiltinFunction::StringSubstring)))
+            .or_else(|| f("replace", member_function(BuiltinFunction::StringReplace)))
+            .or_else(|| f("split", member_function(BuiltinFunction::StringSplit)))
+    }
+}
+struct NumberExpression<'a>(&'a Expression);
+impl<'a> LookupObject for NumberExpression<'a> {
+    fn for_each_entry<R>(
+        &self,
+        ctx: &LookupCtx,
+        f: &mut impl FnMut(&str, LookupResult) -> Option<R>,
+    ) -> Option<R> {
+        let member_function = |f: BuiltinFunction| {
+            LookupResult::from(Expression::MemberFunction {
+                base: Box::new(self.0.clone()),
+                base_node: ctx.current_token.clone(), // Note that this is not the base_node, but the function's node
+                member: Box::new(Expression::BuiltinFunctionReference(
+                    f,
+                    ctx.current_token.as_ref().map(|t| t.to_source_location()),
+                )),
+            })
+        };
+        None.or_else(|| f("to-fixed", member_function(BuiltinFunction::NumberToFixed)))
     }
 }
 struct ColorExpression<'a>(&'a Expression);
@@ -855,11 +975,29 @@ fn for_each_entry<R>(
                 )),
             })
         };
+        let hsva_field_access = |f: &str| {
+            LookupResult::from(Expression::StructFieldAccess {
+                base: Box::new(Expression::FunctionCall {
+                    function: Box::new(Expression::BuiltinFunctionReference(
+                        BuiltinFunction::ColorHsvaStruct,
+                        ctx.current_token.as_ref().map(|t| t.to_source_location()),
+                    )),
+                    source_location: ctx.current_token.as_ref().map(|t| t.to_source_location()),
+                    arguments: vec![self.0.clone()],
+                }),
+                name: f.into(),
+            })
+        };
         None.or_else(|| f("brighter", member_function(BuiltinFunction::ColorBrighter)))
             .or_else(|| f("darker", member_function(BuiltinFunction::ColorDarker)))
             .or_else(|| f("transparentize", member_function(BuiltinFunction::ColorTransparentize)))
             .or_else(|| f("with-alpha", member_function(BuiltinFunction::ColorWithAlpha)))
             .or_else(|| f("mix", member_function(BuiltinFunction::ColorMix)))
+            .or_else(|| f("to-hsv", member_function(BuiltinFunction::ColorHsvaStruct)))
+            .or_else(|| f("to-hsl", member_function(BuiltinFunction::ColorHslaStruct)))
+            .or_else(|| f("hue", hsva_field_access("hue")))
+            .or_else(|| f("saturation", hsva_field_access("saturation")))
+            .or_else(|| f("value", hsva_field_access("value")))
     }
 }
 
@@ -905,6 +1043,48 @@ fn for_each_entry<R>(
                 arguments: vec![self.0.clone()],
             })
         };
+        let array_function = |mac: BuiltinMacroFunction| {
+            LookupResult::from(Expression::MemberFunction {
+                base: Box::new(self.0.clone()),
+                base_node: ctx.current_token.clone(), // Note that this is not the base_node, but the function's node
+                member: Box::new(Expression::BuiltinMacroReference(mac, ctx.current_token.clone())),
+            })
+        };
+        let array_operation = |op| array_function(BuiltinMacroFunction::ArrayOperation(op));
         None.or_else(|| f("length", member_function(BuiltinFunction::ArrayLength)))
+            .or_else(|| f("filter", array_operation(ArrayOperation::Filter)))
+            .or_else(|| f("map", array_operation(ArrayOperation::Map)))
+            .or_else(|| f("sort-by", array_operation(ArrayOperation::SortBy)))
+            .or_else(|| f("find-index", array_operation(ArrayOperation::FindIndex)))
+            .or_else(|| f("reverse", array_operation(ArrayOperation::Reverse)))
+            .or_else(|| f("contains", array_function(BuiltinMacroFunction::ArrayContains)))
+    }
+}
+
+struct OptionalExpression<'a>(&'a Expression);
+impl<'a> LookupObject for OptionalExpression<'a> {
+    fn for_each_entry<R>(
+        &self,
+        ctx: &LookupCtx,
+        f: &mut impl FnMut(&str, LookupResult) -> Option<R>,
+    ) -> Option<R> {
+        let has_value = || {
+            LookupResult::from(Expression::BinaryExpression {
+                lhs: Box::new(self.0.clone()),
+                rhs: Box::new(Expression::default_value_for_type(&self.0.ty())),
+                op: '!',
+            })
+        };
+        let value_or = || {
+            LookupResult::from(Expression::MemberFunction {
+                base: Box::new(self.0.clone()),
+                base_node: ctx.current_token.clone(), // Note that this is not the base_node, but the function's node
+                member: Box::new(Expression::BuiltinMacroReference(
+                    BuiltinMacroFunction::OptionalValueOr,
+                    ctx.current_token.clone(),
+                )),
+            })
+        };
+        None.or_else(|| f("has-value", has_value())).or_else(|| f("value-or", value_or()))
     }
 }
@@ -7,7 +7,7 @@
 
 // cSpell: ignore qualname
 
-use crate::diagnostics::{BuildDiagnostics, SourceLocation, Spanned};
+use crate::diagnostics::{BuildDiagnostics, DiagnosticLevel, SourceLocation, Spanned};
 use crate::expression_tree::{self, BindingExpression, Expression, Unit};
 use crate::langtype::{BuiltinElement, Enumeration, NativeClass, Type};
 use crate::langtype::{ElementType, PropertyLookupResult};
@@ -43,7 +43,7 @@ pub struct Document {
     pub inner_components: Vec<Rc<Component>>,
     pub inner_types: Vec<Type>,
     pub root_component: Rc<Component>,
-    pub local_registry: TypeRegister,
+    pub local_registry: Rc<RefCell<TypeRegister>>,
     /// A list of paths to .ttf/.ttc files that are supposed to be registered on
     /// startup for custom font use.
     pub custom_fonts: Vec<(String, crate::parser::SyntaxToken)>,
@@ -60,16 +60,16 @@ pub fn from_node(
     ) -> Self {
         debug_assert_eq!(node.kind(), SyntaxKind::Document);
 
-        let mut local_registry = TypeRegister::new(parent_registry);
+        let local_registry = Rc::new(RefCell::new(TypeRegister::new(parent_registry)));
         let mut inner_components = vec![];
         let mut inner_types = vec![];
 
         let mut process_component =
             |n: syntax_nodes::Component,
              diag: &mut BuildDiagnostics,
-             local_registry: &mut TypeRegister| {
+             local_registry: &Rc<RefCell<TypeRegister>>| {
                 let compo = Component::from_node(n, diag, local_registry);
-                local_registry.add(compo.clone());
+                local_registry.borrow_mut().add(compo.clone());
                 inner_components.push(compo);
             };
         let process_struct = |n: syntax_nodes::StructDeclaration,
@@ -123,28 +123,34 @@ pub fn from_node(
 
         for n in node.children() {
             match n.kind() {
-                SyntaxKind::Component => process_component(n.into(), diag, &mut local_registry),
-                SyntaxKind::StructDeclaration => {
-                    process_struct(n.into(), diag, &mut local_registry, &mut inner_types)
-                }
+                SyntaxKind::Component => process_component(n.into(), diag, &local_registry),
+                SyntaxKind::StructDeclaration => process_struct(
+                    n.into(),
+                    diag,
+                    &mut local_registry.borrow_mut(),
+                    &mut inner_types,
+                ),
                 SyntaxKind::EnumDeclaration => {
-                    process_enum(n.into(), diag, &mut local_registry, &mut inner_types)
+                    process_enum(n.into(), diag, &mut local_registry.borrow_mut(), &mut inner_types)
                 }
                 SyntaxKind::ExportsList => {
                     for n in n.children() {
                         match n.kind() {
                             SyntaxKind::Component => {
-                                process_component(n.into(), diag, &mut local_registry)
+                                process_component(n.into(), diag, &local_registry)
                             }
                             SyntaxKind::StructDeclaration => process_struct(
                                 n.into(),
                                 diag,
-                                &mut local_registry,
+                                &mut local_registry.borrow_mut(),
+                                &mut inner_types,
+                            ),
+                            SyntaxKind::EnumDeclaration => process_enum(
+                                n.into(),
+                                diag,
+                                &mut local_registry.borrow_mut(),
                                 &mut inner_types,
                             ),
-                            SyntaxKind::EnumDeclaration => {
-                                process_enum(n.into(), diag, &mut local_registry, &mut inner_types)
-                            }
                             _ => {}
                         }
                     }
@@ -152,7 +158,8 @@ pub fn from_node(
                 _ => {}
             };
         }
-        let mut exports = Exports::from_node(&node, &inner_components, &local_registry, diag);
+        let mut exports =
+            Exports::from_node(&node, &inner_components, &local_registry.borrow(), diag);
         exports.add_reexports(reexports, diag);
 
         let root_component = exports
@@ -164,14 +171,27 @@ pub fn from_node(
                     .and_then(|import| {
                         crate::typeloader::ImportedName::extract_imported_names(&import).last()
                     })
-                    .and_then(|import| local_registry.lookup_element(&import.internal_name).ok())
+                    .and_then(|import| {
+                        local_registry.borrow().lookup_element(&import.internal_name).ok()
+                    })
                     .and_then(|c| match c {
-                        ElementType::Component(c) => Some(c),
+                        ElementType::Component(c) if c.generic.is_none() => Some(c),
                         _ => None,
                     })
             })
             .unwrap_or_default();
 
+        // The instances of generic components must be processed before the components using them
+        let mut inner_components = inner_components
+            .into_iter()
+            .flat_map(|c| {
+                let mut instances = vec![];
+                collect_generic_instances(&c, &mut instances);
+                instances.push(c);
+                instances
+            })
+            .collect::<Vec<_>>();
+
         let custom_fonts = foreign_imports
             .into_iter()
             .filter_map(|import| {
@@ -204,6 +224,7 @@ pub fn from_node(
             })
             .collect();
 
+        let mut unused_generic_instances = vec![];
         for local_compo in &inner_components {
             if exports
                 .components_or_types
@@ -215,7 +236,17 @@ pub fn from_node(
             }
             // Don't warn about these for now - detecting their use can only be done after the resolve_expressions
             // pass.
-            if local_compo.is_global() {
+            if local_compo.is_global() || local_compo.generic_instance.is_some() {
+                continue;
+            }
+            if let Some(generic) = &local_compo.generic {
+                if generic.instances.borrow().is_empty() {
+                    diag.push_warning(
+                        "Component is neither used nor exported".into(),
+                        &local_compo.node,
+                    );
+                    unused_generic_instances.push(local_compo.instantiate_unused(diag));
+                }
                 continue;
             }
             // First ref count is in the type registry, the second one in inner_components. Any use of the element
@@ -228,6 +259,8 @@ pub fn from_node(
             }
         }
 
+        inner_components.extend(unused_generic_instances);
+
         Document {
             node: Some(node),
             root_component,
@@ -240,15 +273,38 @@ pub fn from_node(
     }
 }
 
+/// Push to `result` the instances of generic components used by `component` that are not yet part
+/// of a document, such that every instance comes after the instances it uses.
+fn collect_generic_instances(component: &Rc<Component>, result: &mut Vec<Rc<Component>>) {
+    recurse_elem(&component.root_element, &(), &mut |elem, _| {
+        if let ElementType::Component(c) = &elem.borrow().base_type {
+            if c.generic_instance.as_ref().map_or(false, |i| !i.collected.replace(true)) {
+                collect_generic_instances(c, result);
+                result.push(c.clone());
+            }
+        }
+    });
+}
+
 #[derive(Debug, Clone)]
 pub struct PopupWindow {
     pub component: Rc<Component>,
     pub x: NamedReference,
     pub y: NamedReference,
     pub close_on_click: bool,
+    /// The popup is a `ToolTipPopup`
+    pub is_tooltip: bool,
     pub parent_element: ElementRc,
 }
 
+/// A `Timer` element, after it was removed from the tree by the lower_timers pass
+#[derive(Debug, Clone)]
+pub struct Timer {
+    pub interval: NamedReference,
+    pub running: NamedReference,
+    pub triggered: NamedReference,
+}
+
 type ChildrenInsertionPoint = (ElementRc, syntax_nodes::ChildrenPlaceholder);
 
 /// Used sub types for a root component
@@ -327,6 +383,7 @@ pub struct Component {
     /// (This only make sense on the root component)
     pub used_types: RefCell<UsedSubTypes>,
     pub popup_windows: RefCell<Vec<PopupWindow>>,
+    pub timers: RefCell<Vec<Timer>>,
 
     /// The names under which this component should be accessible
     /// if it is a global singleton and exported.
@@ -339,19 +396,88 @@ pub struct Component {
     /// This is the main entry point for the code generators. Such a component
     /// should have the full API, etc.
     pub is_root_component: Cell<bool>,
+
+    /// Set when this is a generic component (`component Foo<T> { ... }`). A generic component is
+    /// not compiled by itself, it is instantiated for each list of generic arguments it is used with.
+    pub generic: Option<GenericComponent>,
+    /// Set when this component is an instance of a generic component
+    pub generic_instance: Option<GenericInstance>,
+}
+
+#[derive(Debug)]
+pub struct GenericComponent {
+    /// The names of the generic parameters
+    pub parameters: Vec<String>,
+    /// The type register of the document declaring the generic component, from which the
+    /// instances are created. (Weak because the register holds the generic component.)
+    type_register: Weak<RefCell<TypeRegister>>,
+    /// The instances with their generic arguments. (Weak because each instance holds a
+    /// register derived from `type_register`.)
+    instances: RefCell<Vec<(Vec<Either<Type, ElementType>>, Weak<Component>)>>,
+}
+
+#[derive(Debug)]
+pub struct GenericInstance {
+    /// The type register of the declaration of the generic component, with the generic
+    /// parameters set to the generic arguments. The expressions of the instance are resolved with it.
+    pub type_register: Rc<RefCell<TypeRegister>>,
+    /// The generic arguments where the instance was first used, such as `<int, Text>`. None for
+    /// the instance that checks an unused generic component.
+    arguments_node: Option<syntax_nodes::GenericArguments>,
+    /// The name of the instance for the diagnostics, such as `Foo<int, Text>`
+    name: String,
+    /// Set once the instance was added to the `inner_components` of a document
+    collected: Cell<bool>,
 }
 
 impl Component {
     pub fn from_node(
         node: syntax_nodes::Component,
         diag: &mut BuildDiagnostics,
+        tr: &Rc<RefCell<TypeRegister>>,
+    ) -> Rc<Self> {
+        let id = parser::identifier_text(&node.DeclaredIdentifier()).unwrap_or_default();
+        if let Some(parameters_node) = node.GenericParameters() {
+            let mut parameters = Vec::<String>::new();
+            for n in parameters_node.DeclaredIdentifier() {
+                let name = unwrap_or_continue!(parser::identifier_text(&n); diag);
+                if parameters.contains(&name) {
+                    diag.push_error(format!("Duplicated generic parameter '{name}'"), &n);
+                }
+                parameters.push(name);
+            }
+            // The element tree is only created when instantiating the component
+            let root_element =
+                Element { id: "root".into(), node: Some(node.Element()), ..Default::default() };
+            let c = Rc::new(Component {
+                node: Some(node.into()),
+                id,
+                root_element: Rc::new(RefCell::new(root_element)),
+                generic: Some(GenericComponent {
+                    parameters,
+                    type_register: Rc::downgrade(tr),
+                    instances: Default::default(),
+                }),
+                ..Default::default()
+            });
+            c.root_element.borrow_mut().enclosing_component = Rc::downgrade(&c);
+            return c;
+        }
+        Self::from_node_with_id(node, id, None, diag, &tr.borrow())
+    }
+
+    fn from_node_with_id(
+        node: syntax_nodes::Component,
+        id: String,
+        generic_instance: Option<GenericInstance>,
+        diag: &mut BuildDiagnostics,
         tr: &TypeRegister,
     ) -> Rc<Self> {
         let mut child_insertion_point = None;
         let is_legacy_syntax = node.child_token(SyntaxKind::ColonEqual).is_some();
         let c = Component {
             node: Some(node.clone().into()),
-            id: parser::identifier_text(&node.DeclaredIdentifier()).unwrap_or_default(),
+            id,
             root_element: Element::from_node(
                 node.Element(),
                 "root".into(),
@@ -366,6 +492,7 @@ pub fn from_node(
                 tr,
             ),
             child_insertion_point: RefCell::new(child_insertion_point),
+            generic_instance,
             ..Default::default()
         };
         let c = Rc::new(c);
@@ -376,6 +503,135 @@ pub fn from_node(
         c
     }
 
+    /// Return the instance of this generic component for the generic arguments, creating it
+    /// if this is the first use with these arguments.
+    fn instantiate(
+        self: &Rc<Self>,
+        arguments_node: syntax_nodes::GenericArguments,
+        diag: &mut BuildDiagnostics,
+        tr: &TypeRegister,
+    ) -> ElementType {
+        let generic = self.generic.as_ref().expect("instantiating a non-generic component");
+        let arguments = arguments_node
+            .Type()
+            .map(|n| generic_argument_from_node(n, diag, tr))
+            .collect::<Vec<_>>();
+        if arguments.len() != generic.parameters.len() {
+            diag.push_error(
+                format!(
+                    "'{}' expects {} generic arguments, but {} are provided",
+                    self.id,
+                    generic.parameters.len(),
+                    arguments.len()
+                ),
+                &arguments_node,
+            );
+            return ElementType::Error;
+        }
+        let Some(arguments) = arguments.into_iter().collect::<Option<Vec<_>>>() else {
+            return ElementType::Error;
+        };
+
+        let existing = generic.instances.borrow().iter().find_map(|(a, instance)| {
+            let same = a.iter().zip(&arguments).all(|(a, b)| same_generic_argument(a, b));
+            same.then(|| instance.upgrade()).flatten()
+        });
+        if let Some(instance) = existing {
+            return ElementType::Component(instance);
+        }
+
+        let document_register =
+            generic.type_register.upgrade().expect("the document of a generic component is alive");
+        let mut type_register = TypeRegister::new(&document_register);
+        for (name, argument) in generic.parameters.iter().zip(arguments.iter().cloned()) {
+            match argument {
+                Either::Left(ty) => type_register.insert_type_with_name(ty, name.clone()),
+                Either::Right(ty) => type_register.insert_element_with_name(name.clone(), ty),
+            }
+        }
+        let type_register = Rc::new(RefCell::new(type_register));
+        // The id must be usable as an identifier in the generated code, and distinct from the
+        // other instances, whose arguments may have the same name.
+        let key = arguments.iter().map(|a| a.to_string()).collect::<Vec<_>>().join(", ");
+        let mut id = std::iter::once(self.id.as_str())
+            .chain(key.split(|c: char| !c.is_alphanumeric() && c != '_' && c != '-'))
+            .filter(|s| !s.is_empty())
+            .collect::<Vec<_>>()
+            .join("-");
+        let instance_count = generic.instances.borrow().len();
+        if instance_count > 0 {
+            id = format!("{id}-{instance_count}");
+        }
+        let diagnostic_count = diag.iter().count();
+        let instance = Self::from_node_with_id(
+            self.node.clone().expect("generic component without node").into(),
+            id,
+            Some(GenericInstance {
+                type_register: type_register.clone(),
+                arguments_node: Some(arguments_node),
+                name: format!("{}<{key}>", self.id),
+                collected: Cell::new(false),
+            }),
+            diag,
+            &type_register.borrow(),
+        );
+        instance.report_errors_at_generic_use(diag, diagnostic_count);
+        generic.instances.borrow_mut().push((arguments, Rc::downgrade(&instance)));
+        ElementType::Component(instance)
+    }
+
+    /// Return an instance of an unused generic component, in which each generic parameter is an
+    /// unknown type and element. This checks the parts of the component that don't depend on
+    /// the generic arguments, the others don't report errors.
+    fn instantiate_unused(self: &Rc<Self>, diag: &mut BuildDiagnostics) -> Rc<Self> {
+        let generic = self.generic.as_ref().expect("instantiating a non-generic component");
+        let document_register =
+            generic.type_register.upgrade().expect("the document of a generic component is alive");
+        let mut type_register = TypeRegister::new(&document_register);
+        for name in &generic.parameters {
+            type_register.insert_type_with_name(Type::Invalid, name.clone());
+            type_register.insert_element_with_name(name.clone(), ElementType::Error);
+        }
+        let type_register = Rc::new(RefCell::new(type_register));
+        let instance = Self::from_node_with_id(
+            self.node.clone().expect("generic component without node").into(),
+            self.id.clone(),
+            Some(GenericInstance {
+                type_register: type_register.clone(),
+                arguments_node: None,
+                name: self.id.clone(),
+                collected: Cell::new(true),
+            }),
+            diag,
+            &type_register.borrow(),
+        );
+        instance
+    }
+
+    /// The errors in an instance of a generic component are located in the generic component,
+    /// which doesn't tell which generic arguments caused them. So the errors pushed to `diag`
+    /// after its first `diagnostic_count` diagnostics are also reported where the instance is used.
+    pub fn report_errors_at_generic_use(
+        &self,
+        diag: &mut BuildDiagnostics,
+        diagnostic_count: usize,
+    ) {
+        let Some((instance, node)) =
+            self.generic_instance.as_ref().and_then(|i| Some((i, i.arguments_node.as_ref()?)))
+        else {
+            return;
+        };
+        let errors = diag
+            .iter()
+            .skip(diagnostic_count)
+            .filter(|d| d.level() == DiagnosticLevel::Error)
+            .map(|d| d.message().to_owned())
+            .collect::<Vec<_>>();
+        for error in errors {
+            diag.push_error(format!("In '{}': {error}", instance.name), node);
+        }
+    }
+
     /// This component is a global component introduced with the "global" keyword
     pub fn is_global(&self) -> bool {
         match &self.root_element.borrow().base_type {
@@ -553,6 +809,22 @@ fn deep_clone(e: &ElementRc) -> ElementRc {
 
 pub type BindingsMap = BTreeMap<String, RefCell<BindingExpression>>;
 
+/// The type name and id of an element, as written in the .slint file
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ElementDebugInfo {
+    /// The name of the type of the element, e.g. `Button`
+    pub type_name: String,
+    /// The id of the element, or an empty string if it has none
+    pub id: String,
+}
+
+impl ElementDebugInfo {
+    /// Encode the information as `TypeName::id`, the format used by the runtime
+    pub fn encode(&self) -> String {
+        format!("{}::{}", self.type_name, self.id)
+    }
+}
+
 /// An Element is an instantiation of a Component
 #[derive(Default)]
 pub struct Element {
@@ -583,6 +855,9 @@ pub struct Element {
     pub states: Vec<State>,
     pub transitions: Vec<Transition>,
 
+    /// The `changed <property> => { ... }` handlers, with the property they track
+    pub change_callbacks: Vec<(NamedReference, Expression)>,
+
     /// true when this item's geometry is handled by a layout
     pub child_of_layout: bool,
     /// The property pointing to the layout info. `(horizontal, vertical)`
@@ -611,6 +886,11 @@ pub struct Element {
     /// How many times the element was inlined
     pub inline_depth: i32,
 
+    /// The elements of the .slint sources this element comes from. There can be several of them when
+    /// the root element of a component was merged with the element instantiating it.
+    /// Only collected when [`CompilerConfiguration::debug_info`](crate::CompilerConfiguration::debug_info) is set.
+    pub debug: Vec<ElementDebugInfo>,
+
     /// The AST node, if available
     pub node: Option<syntax_nodes::Element>,
 }
@@ -691,6 +971,12 @@ macro_rules! indent {
         indent!();
         writeln!(f, "transitions {:?} ", e.transitions)?;
     }
+    for (nr, expr) in &e.change_callbacks {
+        indent!();
+        write!(f, "changed {} => ", nr.name())?;
+        expression_tree::pretty_print(f, expr)?;
+        writeln!(f)?;
+    }
     for c in &e.children {
         indent!();
         pretty_print(f, &c.borrow(), indentation)?
@@ -795,7 +1081,27 @@ pub fn from_node(
                     );
                     ElementType::Error
                 }
-                Ok(ty) => ty,
+                Ok(ElementType::Component(c)) if c.generic.is_some() => {
+                    match node.GenericArguments() {
+                        Some(arguments) => c.instantiate(arguments, diag, tr),
+                        None => {
+                            diag.push_error(
+                                format!(
+                                    "'{0}' is a generic component and needs generic arguments, such as '{0}<...>'",
+                                    c.id
+                                ),
+                                &base_node,
+                            );
+                            ElementType::Error
+                        }
+                    }
+                }
+                Ok(ty) => {
+                    if let Some(arguments) = node.GenericArguments() {
+                        diag.push_error(format!("'{}' is not a generic component", ty), &arguments);
+                    }
+                    ty
+                }
                 Err(err) => {
                     diag.push_error(err, &base_node);
                     ElementType::Error
@@ -814,6 +1120,7 @@ pub fn from_node(
             node.PropertyAnimation().for_each(|n| error_on(&n, "animations"));
             node.States().for_each(|n| error_on(&n, "states"));
             node.Transitions().for_each(|n| error_on(&n, "transitions"));
+            node.ChangedCallback().for_each(|n| error_on(&n, "change callbacks"));
             node.CallbackDeclaration().for_each(|cb| {
                 if parser::identifier_text(&cb.DeclaredIdentifier()).map_or(false, |s| s == "init")
                 {
@@ -1321,6 +1628,40 @@ pub fn from_node(
             }
         }
 
+        let is_global = r.borrow().base_type == ElementType::Global;
+        for changed in node.ChangedCallback().filter(|_| !is_global) {
+            let Some(unresolved_name) = parser::identifier_text(&changed.DeclaredIdentifier())
+            else {
+                continue;
+            };
+            let lookup_result = r.borrow().lookup_property(&unresolved_name);
+            if !lookup_result.property_type.is_property_type() {
+                diag.push_error(
+                    format!("'{}' is not a property in {}", unresolved_name, r.borrow().base_type),
+                    &changed.DeclaredIdentifier(),
+                );
+                continue;
+            }
+            if unresolved_name != lookup_result.resolved_name.as_ref() {
+                diag.push_property_deprecation_warning(
+                    &unresolved_name,
+                    &lookup_result.resolved_name,
+                    &changed.DeclaredIdentifier(),
+                );
+            }
+            let nr = NamedReference::new(&r, &lookup_result.resolved_name);
+            if r.borrow().change_callbacks.iter().any(|(n, _)| *n == nr) {
+                diag.push_error(
+                    format!("Duplicated change callback on '{}'", unresolved_name),
+                    &changed.DeclaredIdentifier(),
+                );
+                continue;
+            }
+            r.borrow_mut()
+                .change_callbacks
+                .push((nr, Expression::Uncompiled(changed.clone().into())));
+        }
+
         if r.borrow().base_type.to_string() == "ListView" {
             let mut seen_for = false;
             for se in node.children() {
@@ -1662,8 +2003,11 @@ pub fn type_from_node(
 
         let prop_type = tr.lookup_qualified(&qualified_type.members);
 
-        if prop_type == Type::Invalid && tr.lookup_element(&qualified_type.to_string()).is_err() {
+        let element = tr.lookup_element(&qualified_type.to_string());
+        if prop_type == Type::Invalid && element.is_err() {
             diag.push_error(format!("Unknown type '{}'", qualified_type), &qualified_type_node);
+        } else if prop_type == Type::Invalid && element == Ok(ElementType::Error) {
+            // A generic parameter of an unused generic component, which can be any type
         } else if !prop_type.is_property_type() {
             diag.push_error(
                 format!("'{}' is not a valid type", qualified_type),
@@ -1675,12 +2019,79 @@ pub fn type_from_node(
         type_struct_from_node(object_node, diag, tr, None)
     } else if let Some(array_node) = node.ArrayType() {
         Type::Array(Box::new(type_from_node(array_node.Type(), diag, tr)))
+    } else if let Some(optional_node) = node.OptionalType() {
+        match type_from_node(optional_node.Type(), diag, tr) {
+            Type::Invalid => Type::Invalid,
+            Type::Optional(_) => {
+                diag.push_error("Optional types cannot be nested".into(), &optional_node);
+                Type::Invalid
+            }
+            ty => Type::Optional(Box::new(ty)),
+        }
     } else {
         assert!(diag.has_error());
         Type::Invalid
     }
 }
 
+/// Whether two generic arguments are the same. The structs and enums declared in .slint are
+/// compared by identity, since two of them with the same name are different types.
+fn same_generic_argument(a: &Either<Type, ElementType>, b: &Either<Type, ElementType>) -> bool {
+    fn same_type(a: &Type, b: &Type) -> bool {
+        match (a, b) {
+            (Type::Struct { node: Some(a), .. }, Type::Struct { node: Some(b), .. }) => {
+                Rc::ptr_eq(&a.source_file, &b.source_file) && a.node == b.node
+            }
+            (
+                Type::Struct { fields: a, name: a_name, node: None, .. },
+                Type::Struct { fields: b, name: b_name, node: None, .. },
+            ) => {
+                a_name == b_name
+                    && a.len() == b.len()
+                    && a.iter().zip(b).all(|((a_k, a), (b_k, b))| a_k == b_k && same_type(a, b))
+            }
+            (Type::Enumeration(a), Type::Enumeration(b)) => Rc::ptr_eq(a, b),
+            (Type::Array(a), Type::Array(b)) => same_type(a, b),
+            (Type::Struct { .. } | Type::Enumeration(_), _) => false,
+            _ => a == b,
+        }
+    }
+    match (a, b) {
+        (Either::Left(a), Either::Left(b)) => same_type(a, b),
+        (Either::Right(a), Either::Right(b)) => a == b,
+        _ => false,
+    }
+}
+
+/// A generic argument is either a type, or an element type
+fn generic_argument_from_node(
+    node: syntax_nodes::Type,
+    diag: &mut BuildDiagnostics,
+    tr: &TypeRegister,
+) -> Option<Either<Type, ElementType>> {
+    if let Some(qualified_name_node) = node.QualifiedName() {
+        let name = QualifiedTypeName::from_node(qualified_name_node.clone());
+        if tr.lookup_qualified(&name.members) == Type::Invalid {
+            return match ElementType::Error.lookup_type_for_child_element(&name.to_string(), tr) {
+                Ok(ElementType::Component(c)) if c.is_global() || c.generic.is_some() => {
+                    diag.push_error(
+                        format!("'{name}' cannot be used as a generic argument"),
+                        &qualified_name_node,
+                    );
+                    None
+                }
+                Ok(ty) => Some(Either::Right(ty)),
+                Err(err) => {
+                    diag.push_error(err, &qualified_name_node);
+                    None
+                }
+            };
+        }
+    }
+    let ty = type_from_node(node, diag, tr);
+    (ty != Type::Invalid).then_some(Either::Left(ty))
+}
+
 /// Create a [`Type::Struct`] from a [`syntax_nodes::ObjectType`]
 pub fn type_struct_from_node(
     object_node: syntax_nodes::ObjectType,
@@ -2003,6 +2414,12 @@ fn visit_element_expressions_simple(
     }
     elem.borrow_mut().transitions = transitions;
 
+    let mut change_callbacks = std::mem::take(&mut elem.borrow_mut().change_callbacks);
+    for (_, e) in &mut change_callbacks {
+        vis(e, None, &|| Type::Void);
+    }
+    elem.borrow_mut().change_callbacks = change_callbacks;
+
     let component = elem.borrow().enclosing_component.upgrade().unwrap();
     if Rc::ptr_eq(&component.root_element, elem) {
         for e in component.init_code.borrow_mut().iter_mut() {
@@ -2060,6 +2477,11 @@ pub fn visit_all_named_references_in_element(
         }
     }
     elem.borrow_mut().transitions = transitions;
+    let mut change_callbacks = std::mem::take(&mut elem.borrow_mut().change_callbacks);
+    for (r, _) in &mut change_callbacks {
+        vis(r);
+    }
+    elem.borrow_mut().change_callbacks = change_callbacks;
     let mut repeated = std::mem::take(&mut elem.borrow_mut().repeated);
     if let Some(r) = &mut repeated {
         if let Some(lv) = &mut r.is_listview {
@@ -2111,6 +2533,11 @@ pub fn visit_all_named_references(
                     vis(&mut p.x);
                     vis(&mut p.y);
                 });
+                compo.timers.borrow_mut().iter_mut().for_each(|t| {
+                    vis(&mut t.interval);
+                    vis(&mut t.running);
+                    vis(&mut t.triggered);
+                });
             }
             compo
         },
@@ -2237,7 +2664,7 @@ pub fn from_node(
             |it: &mut dyn Iterator<Item = (ExportedName, Either<Rc<Component>, Type>)>| {
                 for (name, compo_or_type) in it {
                     match compo_or_type.as_ref().left() {
-                        Some(compo) if !compo.is_global() => {
+                        Some(compo) if !compo.is_global() && compo.generic.is_none() => {
                             last_exported_component = Some(compo.clone())
                         }
                         _ => {}
@@ -2361,7 +2788,8 @@ pub fn from_node(
         }
 
         if last_exported_component.is_none() {
-            last_exported_component = inner_components.last().cloned();
+            last_exported_component =
+                inner_components.last().filter(|c| c.generic.is_none()).cloned();
         }
 
         Self { components_or_types: sorted_deduped_exports, last_exported_component }
@@ -326,12 +326,16 @@ fn source_file(&self) -> Option<&SourceFile> {
     {
         Document -> [ *Component, *ExportsList, *ImportSpecifier, *StructDeclaration, *EnumDeclaration ],
         /// `DeclaredIdentifier := Element { ... }`
-        Component -> [ DeclaredIdentifier, Element ],
+        Component -> [ DeclaredIdentifier, ?GenericParameters, Element ],
+        /// `<T, Delegate>` after the name of a generic component
+        GenericParameters -> [ *DeclaredIdentifier ],
+        /// `<Person, PersonRow>` after the base type of an element using a generic component
+        GenericArguments -> [ *Type ],
         /// `id := Element { ... }`
         SubElement -> [ Element ],
-        Element -> [ ?QualifiedName, *PropertyDeclaration, *Binding, *CallbackConnection,
-                     *CallbackDeclaration, *Function, *SubElement, *RepeatedElement, *PropertyAnimation,
-                     *TwoWayBinding, *States, *Transitions, ?ChildrenPlaceholder ],
+        Element -> [ ?QualifiedName, ?GenericArguments, *PropertyDeclaration, *Binding, *CallbackConnection,
+                     *CallbackDeclaration, *ChangedCallback, *Function, *SubElement, *RepeatedElement,
+                     *PropertyAnimation, *TwoWayBinding, *States, *Transitions, ?ChildrenPlaceholder ],
         RepeatedElement -> [ ?DeclaredIdentifier, ?RepeatedIndex, Expression , SubElement],
         RepeatedIndex -> [],
         ConditionalElement -> [ Expression , SubElement],
@@ -341,6 +345,8 @@ fn source_file(&self) -> Option<&SourceFile> {
         /// `-> type`  (but without the ->)
         ReturnType -> [Type],
         CallbackConnection -> [ *DeclaredIdentifier,  CodeBlock ],
+        /// `changed foo => { ... }`, the DeclaredIdentifier is the name of the property
+        ChangedCallback -> [ DeclaredIdentifier, CodeBlock ],
         /// Declaration of a property.
         PropertyDeclaration-> [ ?Type , DeclaredIdentifier, ?BindingExpression, ?TwoWayBinding ],
         /// QualifiedName are the properties name
@@ -356,13 +362,19 @@ fn source_file(&self) -> Option<&SourceFile> {
         /// the right-hand-side of a binding
         // Fixme: the test should be a or
         BindingExpression-> [ ?CodeBlock, ?Expression ],
-        CodeBlock-> [ *Expression, *ReturnStatement ],
+        CodeBlock-> [ *Expression, *LetStatement, *ReturnStatement, *ForStatement, *WhileStatement ],
+        /// `let foo = expr;` or `let foo: type = expr;`
+        LetStatement -> [ DeclaredIdentifier, ?Type, Expression ],
         ReturnStatement -> [ ?Expression ],
+        /// `for foo[idx] in expr { ... }`
+        ForStatement -> [ DeclaredIdentifier, ?RepeatedIndex, Expression, CodeBlock ],
+        /// `while (condition) { ... }`
+        WhileStatement -> [ Expression, CodeBlock ],
         // FIXME: the test should test that as alternative rather than several of them (but it can also be a literal)
         Expression-> [ ?Expression, ?FunctionCallExpression, ?IndexExpression, ?SelfAssignment,
                        ?ConditionalExpression, ?QualifiedName, ?BinaryExpression, ?Array, ?ObjectLiteral,
                        ?UnaryOpExpression, ?CodeBlock, ?StringTemplate, ?AtImageUrl, ?AtGradient, ?AtTr,
-                       ?MemberAccess ],
+                       ?MemberAccess, ?Lambda, ?MatchExpression ],
         /// Concatenate the Expressions to make a string (usually expended from a template string)
         StringTemplate -> [*Expression],
         /// `@image-url("foo.png")`
@@ -383,12 +395,18 @@ fn source_file(&self) -> Option<&SourceFile> {
         SelfAssignment -> [2 Expression],
         /// `condition ? first : second`
         ConditionalExpression -> [3 Expression],
+        /// `match (expr) { a | b => first, _ => second }`
+        MatchExpression -> [ Expression, *MatchArm ],
+        /// `a | b => expr` in a `MatchExpression`. The `_` pattern is a QualifiedName as well
+        MatchArm -> [ *QualifiedName, Expression ],
         /// `expr + expr`
         BinaryExpression -> [2 Expression],
         /// `- expr`
         UnaryOpExpression -> [Expression],
         /// `(foo).bar`, where `foo` is the base expression, and `bar` is a Identifier.
         MemberAccess -> [Expression],
+        /// `item => expression`, as argument of the array functions such as `filter`
+        Lambda -> [ DeclaredIdentifier, ?Expression, ?CodeBlock ],
         /// `[ ... ]`
         Array -> [ *Expression ],
         /// `{ foo: bar }`
@@ -422,13 +440,15 @@ fn source_file(&self) -> Option<&SourceFile> {
         ExternalName -> [],
         InternalName -> [],
         /// The representation of a type
-        Type -> [ ?QualifiedName, ?ObjectType, ?ArrayType ],
+        Type -> [ ?QualifiedName, ?ObjectType, ?ArrayType, ?OptionalType ],
         /// `{foo: string, bar: string} `
         ObjectType ->[ *ObjectTypeMember ],
         /// `foo: type` inside an ObjectType
         ObjectTypeMember -> [ Type ],
         /// `[ type ]`
         ArrayType -> [ Type ],
+        /// `type?`
+        OptionalType -> [ Type ],
         /// `struct Foo { ... }`
         StructDeclaration -> [DeclaredIdentifier, ObjectType, ?AtRustAttr],
         /// `enum Foo { bli, bla, blu }`
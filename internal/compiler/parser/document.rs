@@ -101,6 +101,9 @@ pub fn parse_document(p: &mut impl Parser) -> bool {
 /// global Struct { property<int> xx; }
 /// component C { property<int> xx; }
 /// component C inherits D { }
+/// component C<T> inherits D { property <T> data; }
+/// component C<T, Delegate> { Delegate { } }
+/// component C<T> inherits D<T, Text> { }
 /// ```
 pub fn parse_component(p: &mut impl Parser) -> bool {
     let simple_component = p.nth(1).kind() == SyntaxKind::ColonEqual;
@@ -120,6 +123,9 @@ pub fn parse_component(p: &mut impl Parser) -> bool {
         drop(p.start_node(SyntaxKind::Element));
         return false;
     }
+    if is_new_component && p.peek().kind() == SyntaxKind::LAngle {
+        parse_generic_parameters(&mut *p);
+    }
     if is_global {
         if p.peek().kind() == SyntaxKind::ColonEqual {
             p.warning("':=' to declare a global is deprecated. Remove the ':='");
@@ -158,6 +164,29 @@ pub fn parse_component(p: &mut impl Parser) -> bool {
     parse_element(&mut *p)
 }
 
+#[cfg_attr(test, parser_test)]
+/// ```test,GenericParameters
+/// <T>
+/// <T, Delegate>
+/// <T, Delegate,>
+/// ```
+fn parse_generic_parameters(p: &mut impl Parser) {
+    let mut p = p.start_node(SyntaxKind::GenericParameters);
+    p.expect(SyntaxKind::LAngle);
+    while p.nth(0).kind() != SyntaxKind::RAngle {
+        {
+            let mut p = p.start_node(SyntaxKind::DeclaredIdentifier);
+            if !p.expect(SyntaxKind::Identifier) {
+                return;
+            }
+        }
+        if !p.test(SyntaxKind::Comma) {
+            break;
+        }
+    }
+    p.expect(SyntaxKind::RAngle);
+}
+
 #[cfg_attr(test, parser_test)]
 /// ```test,QualifiedName
 /// Rectangle
@@ -13,10 +13,18 @@
 /// ```test,Element
 /// Item { }
 /// Item { property: value; SubElement { } }
+/// List<Person, PersonRow> { }
+/// List<[int], Text> { model: [1, 2]; }
 /// ```
 pub fn parse_element(p: &mut impl Parser) -> bool {
     let mut p = p.start_node(SyntaxKind::Element);
-    if !(parse_qualified_name(&mut *p) && p.expect(SyntaxKind::LBrace)) {
+    if !parse_qualified_name(&mut *p) {
+        return false;
+    }
+    if p.nth(0).kind() == SyntaxKind::LAngle {
+        parse_generic_arguments(&mut *p);
+    }
+    if !p.expect(SyntaxKind::LBrace) {
         return false;
     }
 
@@ -25,13 +33,34 @@ pub fn parse_element(p: &mut impl Parser) -> bool {
     p.expect(SyntaxKind::RBrace)
 }
 
+#[cfg_attr(test, parser_test)]
+/// ```test,GenericArguments
+/// <Person>
+/// <Person, PersonRow>
+/// <[int], {a: string},>
+/// ```
+fn parse_generic_arguments(p: &mut impl Parser) {
+    let mut p = p.start_node(SyntaxKind::GenericArguments);
+    p.expect(SyntaxKind::LAngle);
+    while p.nth(0).kind() != SyntaxKind::RAngle {
+        parse_type(&mut *p);
+        if !p.test(SyntaxKind::Comma) {
+            break;
+        }
+    }
+    p.expect(SyntaxKind::RAngle);
+}
+
 #[cfg_attr(test, parser_test)]
 /// ```test
 /// property1: value; property2: value;
 /// sub := Sub { }
+/// list := List<Person, Row> { }
+/// List<Person, Row> { }
 /// for xx in model: Sub {}
 /// if condition : Sub {}
 /// clicked => {}
+/// changed foo => {}
 /// callback foobar;
 /// property<int> width;
 /// animate someProp { }
@@ -48,10 +77,17 @@ pub fn parse_element_content(p: &mut impl Parser) {
             SyntaxKind::Identifier => match p.nth(1).kind() {
                 SyntaxKind::Colon => parse_property_binding(&mut *p),
                 SyntaxKind::ColonEqual | SyntaxKind::LBrace => parse_sub_element(&mut *p),
+                SyntaxKind::LAngle if p.peek().as_str() != "property" => parse_sub_element(&mut *p),
                 SyntaxKind::FatArrow | SyntaxKind::LParent if p.peek().as_str() != "if" => {
                     parse_callback_connection(&mut *p)
                 }
                 SyntaxKind::DoubleArrow => parse_two_way_binding(&mut *p),
+                SyntaxKind::Identifier
+                    if p.peek().as_str() == "changed"
+                        && p.nth(2).kind() == SyntaxKind::FatArrow =>
+                {
+                    parse_changed_callback(&mut *p);
+                }
                 SyntaxKind::Identifier if p.peek().as_str() == "for" => {
                     parse_repeated_element(&mut *p);
                 }
@@ -274,6 +310,23 @@ fn parse_callback_connection(p: &mut impl Parser) {
     parse_code_block(&mut *p);
 }
 
+#[cfg_attr(test, parser_test)]
+/// ```test,ChangedCallback
+/// changed foo => {}
+/// changed has-focus => { debug(self.has-focus); }
+/// ```
+fn parse_changed_callback(p: &mut impl Parser) {
+    let mut p = p.start_node(SyntaxKind::ChangedCallback);
+    debug_assert_eq!(p.peek().as_str(), "changed");
+    p.expect(SyntaxKind::Identifier); // "changed"
+    {
+        let mut p = p.start_node(SyntaxKind::DeclaredIdentifier);
+        p.expect(SyntaxKind::Identifier);
+    }
+    p.expect(SyntaxKind::FatArrow);
+    parse_code_block(&mut *p);
+}
+
 #[cfg_attr(test, parser_test)]
 /// ```test,TwoWayBinding
 /// foo <=> bar;
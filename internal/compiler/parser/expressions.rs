@@ -2,6 +2,7 @@
 // SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-1.0 OR LicenseRef-Slint-commercial
 
 use super::document::parse_qualified_name;
+use super::element::parse_code_block;
 use super::prelude::*;
 
 #[cfg_attr(test, parser_test)]
@@ -30,6 +31,7 @@
 /// array[index]
 /// {object:42}
 /// "foo".bar.something().something.xx({a: 1.foo}.a)
+/// match (foo) { a => 1, b | c => 2, _ => 3 }
 /// ```
 pub fn parse_expression(p: &mut impl Parser) -> bool {
     p.peek(); // consume the whitespace so they aren't part of the Expression node
@@ -57,7 +59,11 @@ fn parse_expression_helper(p: &mut impl Parser, precedence: OperatorPrecedence)
     let checkpoint = p.checkpoint();
     match p.nth(0).kind() {
         SyntaxKind::Identifier => {
-            parse_qualified_name(&mut *p);
+            if p.peek().as_str() == "match" && is_match_expression(&mut *p) {
+                parse_match_expression(&mut *p);
+            } else {
+                parse_qualified_name(&mut *p);
+            }
         }
         SyntaxKind::StringLiteral => {
             if p.nth(0).as_str().ends_with('{') {
@@ -291,12 +297,18 @@ fn parse_object_notation(p: &mut impl Parser) {
 /// (foo)
 /// (foo, bar, foo)
 /// (foo, bar(), xx+xx,)
+/// (item => item.foo)
 /// ```
 fn parse_function_arguments(p: &mut impl Parser) {
     p.expect(SyntaxKind::LParent);
 
     while p.nth(0).kind() != SyntaxKind::RParent {
-        parse_expression(&mut *p);
+        if p.nth(0).kind() == SyntaxKind::Identifier && p.nth(1).kind() == SyntaxKind::FatArrow {
+            let mut p = p.start_node(SyntaxKind::Expression);
+            parse_lambda(&mut *p);
+        } else {
+            parse_expression(&mut *p);
+        }
         if !p.test(SyntaxKind::Comma) {
             break;
         }
@@ -304,6 +316,87 @@ fn parse_function_arguments(p: &mut impl Parser) {
     p.expect(SyntaxKind::RParent);
 }
 
+#[cfg_attr(test, parser_test)]
+/// ```test,Lambda
+/// item => item.value > 5
+/// x => { x * 2 }
+/// x => { let y = x * 2; y + 1 }
+/// x => { a: x }
+/// ```
+fn parse_lambda(p: &mut impl Parser) {
+    let mut p = p.start_node(SyntaxKind::Lambda);
+    {
+        let mut p = p.start_node(SyntaxKind::DeclaredIdentifier);
+        p.expect(SyntaxKind::Identifier);
+    }
+    p.expect(SyntaxKind::FatArrow);
+    if p.nth(0).kind() == SyntaxKind::LBrace && p.nth(2).kind() != SyntaxKind::Colon {
+        parse_code_block(&mut *p);
+    } else {
+        parse_expression(&mut *p);
+    }
+}
+
+/// Returns true if the `match` identifier at the cursor starts a `match (expr) { ... }` rather
+/// than a call to a function named `match`
+fn is_match_expression(p: &mut impl Parser) -> bool {
+    debug_assert_eq!(p.peek().as_str(), "match");
+    if p.nth(1).kind() != SyntaxKind::LParent {
+        return false;
+    }
+    let mut depth = 0;
+    let mut n = 1;
+    loop {
+        match p.nth(n).kind() {
+            SyntaxKind::LParent => depth += 1,
+            SyntaxKind::RParent => {
+                depth -= 1;
+                if depth == 0 {
+                    return p.nth(n + 1).kind() == SyntaxKind::LBrace;
+                }
+            }
+            SyntaxKind::Eof => return false,
+            _ => {}
+        }
+        n += 1;
+    }
+}
+
+#[cfg_attr(test, parser_test)]
+/// ```test,MatchExpression
+/// match (foo) { }
+/// match (foo) { a => 1 }
+/// match (foo.bar()) { a | Enum.b => "x", _ => "y", }
+/// match ((foo)) { a => match (bar) { _ => 1 }, _ => {x: 2}.x }
+/// match (foo) { a => { bar += 1; }, _ => { } }
+/// ```
+fn parse_match_expression(p: &mut impl Parser) {
+    let mut p = p.start_node(SyntaxKind::MatchExpression);
+    debug_assert_eq!(p.peek().as_str(), "match");
+    p.expect(SyntaxKind::Identifier); // "match"
+    p.expect(SyntaxKind::LParent);
+    parse_expression(&mut *p);
+    p.expect(SyntaxKind::RParent);
+    p.expect(SyntaxKind::LBrace);
+    while !matches!(p.nth(0).kind(), SyntaxKind::RBrace | SyntaxKind::Eof) {
+        {
+            let mut p = p.start_node(SyntaxKind::MatchArm);
+            while parse_qualified_name(&mut *p) && p.test(SyntaxKind::Pipe) {}
+            p.expect(SyntaxKind::FatArrow);
+            if p.nth(0).kind() == SyntaxKind::LBrace && p.nth(2).kind() != SyntaxKind::Colon {
+                let mut p = p.start_node(SyntaxKind::Expression);
+                parse_code_block(&mut *p);
+            } else {
+                parse_expression(&mut *p);
+            }
+        }
+        if !p.test(SyntaxKind::Comma) {
+            break;
+        }
+    }
+    p.expect(SyntaxKind::RBrace);
+}
+
 #[cfg_attr(test, parser_test)]
 /// ```test,StringTemplate
 /// "foo\{bar}"
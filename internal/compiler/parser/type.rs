@@ -11,9 +11,12 @@
 /// string
 /// [ int ]
 /// {a: string, b: int}
+/// int?
+/// [string?]?
 /// ```
 pub fn parse_type(p: &mut impl Parser) {
     let mut p = p.start_node(SyntaxKind::Type);
+    let checkpoint = p.checkpoint();
     match p.nth(0).kind() {
         SyntaxKind::LBrace => parse_type_object(&mut *p),
         SyntaxKind::LBracket => parse_type_array(&mut *p),
@@ -21,6 +24,13 @@ pub fn parse_type(p: &mut impl Parser) {
             parse_qualified_name(&mut *p);
         }
     }
+    while p.nth(0).kind() == SyntaxKind::Question {
+        {
+            let _ = p.start_node_at(checkpoint.clone(), SyntaxKind::Type);
+        }
+        let mut p = p.start_node_at(checkpoint.clone(), SyntaxKind::OptionalType);
+        p.consume(); // '?'
+    }
 }
 
 #[cfg_attr(test, parser_test)]
@@ -8,6 +8,7 @@
 mod check_rotation;
 mod clip;
 mod collect_custom_fonts;
+mod collect_debug_info;
 mod collect_globals;
 mod collect_init_code;
 mod collect_structs_and_enums;
@@ -34,6 +35,7 @@
 mod lower_states;
 mod lower_tabwidget;
 mod lower_text_input_interface;
+mod lower_timers;
 mod materialize_fake_properties;
 mod move_declarations;
 mod optimize_useless_rectangles;
@@ -84,14 +86,17 @@ pub async fn run_passes(
     for component in (root_component.used_types.borrow().sub_components.iter())
         .chain(std::iter::once(root_component))
     {
-        compile_paths::compile_paths(component, &doc.local_registry, diag);
+        if compiler_config.debug_info {
+            collect_debug_info::collect_debug_info(component);
+        }
+        compile_paths::compile_paths(component, &doc.local_registry.borrow(), diag);
         lower_tabwidget::lower_tabwidget(component, type_loader, diag).await;
         apply_default_properties_from_style::apply_default_properties_from_style(
             component,
             &style_metrics,
             diag,
         );
-        lower_states::lower_states(component, &doc.local_registry, diag);
+        lower_states::lower_states(component, &doc.local_registry.borrow(), diag);
         lower_text_input_interface::lower_text_input_interface(component);
     }
 
@@ -108,14 +113,15 @@ pub async fn run_passes(
         focus_item::erase_forward_focus_properties(component);
     }
 
-    ensure_window::ensure_window(root_component, &doc.local_registry, &style_metrics);
+    ensure_window::ensure_window(root_component, &doc.local_registry.borrow(), &style_metrics);
 
     for component in (root_component.used_types.borrow().sub_components.iter())
         .chain(std::iter::once(root_component))
     {
         flickable::handle_flickable(component, &global_type_registry.borrow());
         repeater_component::process_repeater_components(component);
-        lower_popups::lower_popups(component, &doc.local_registry, diag);
+        lower_popups::lower_popups(component, &doc.local_registry.borrow(), diag);
+        lower_timers::lower_timers(component, diag);
         lower_layout::lower_layouts(component, type_loader, diag).await;
         default_geometry::default_geometry(component, diag);
         lower_absolute_coordinates::lower_absolute_coordinates(component);
@@ -139,7 +145,7 @@ pub async fn run_passes(
             diag,
         );
         visible::handle_visible(component, &global_type_registry.borrow(), diag);
-        lower_shadows::lower_shadow_properties(component, &doc.local_registry, diag);
+        lower_shadows::lower_shadow_properties(component, &doc.local_registry.borrow(), diag);
         lower_property_to_element::lower_property_to_element(
             component,
             crate::typeregister::RESERVED_ROTATION_PROPERTIES[0].0,
@@ -162,6 +162,12 @@ fn analyze_element(
     for (_, nr) in &elem.borrow().accessibility_props.0 {
         process_property(&PropertyPath::from(nr.clone()), context, reverse_aliases, diag);
     }
+    for (nr, handler) in &elem.borrow().change_callbacks {
+        process_property(&PropertyPath::from(nr.clone()), context, reverse_aliases, diag);
+        recurse_expression(handler, &mut |prop| {
+            process_property(prop, context, reverse_aliases, diag);
+        });
+    }
 
     if let Some(component) = elem.borrow().enclosing_component.upgrade() {
         if Rc::ptr_eq(&component.root_element, elem) {
@@ -12,15 +12,21 @@
 /// - Make sure that there is no uncalled member function or macro
 pub fn check_expressions(doc: &crate::object_tree::Document, diag: &mut BuildDiagnostics) {
     for component in &doc.inner_components {
+        let diagnostic_count = diag.iter().count();
         visit_all_expressions(component, |e, _| check_expression(component, e, diag));
+        component.report_errors_at_generic_use(diag, diagnostic_count);
     }
 }
 
 fn check_expression(component: &Rc<Component>, e: &Expression, diag: &mut BuildDiagnostics) {
     match e {
-        Expression::MemberFunction { .. } => {
-            // Must already have been be reported.
-            debug_assert!(diag.has_error());
+        Expression::MemberFunction { member, .. } => {
+            if let Expression::BuiltinMacroReference(_, node) = &**member {
+                diag.push_error("Builtin function must be called".into(), node);
+            } else {
+                // Must already have been be reported.
+                debug_assert!(diag.has_error());
+            }
         }
         Expression::BuiltinMacroReference(_, node) => {
             diag.push_error("Builtin function must be called".into(), node);
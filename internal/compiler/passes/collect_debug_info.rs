@@ -0,0 +1,19 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-1.0 OR LicenseRef-Slint-commercial
+
+//! Pass that records the type name and id of each element before they are changed by the inlining
+//! and the other passes, so that the elements can be found at runtime.
+
+use crate::object_tree::{recurse_elem, Component, ElementDebugInfo};
+use std::rc::Rc;
+
+pub fn collect_debug_info(component: &Rc<Component>) {
+    recurse_elem(&component.root_element, &(), &mut |elem, _| {
+        let is_root = Rc::ptr_eq(elem, &component.root_element);
+        let mut elem = elem.borrow_mut();
+        // The root element is implicitly called `root`, that's not an id the user wrote
+        let id = if is_root && elem.id == "root" { String::new() } else { elem.id.clone() };
+        let info = ElementDebugInfo { type_name: elem.base_type.to_string(), id };
+        elem.debug.push(info);
+    });
+}
@@ -125,6 +125,18 @@ fn simplify_expression(expr: &mut Expression) -> bool {
             }
             can_inline
         }
+        Expression::FunctionCall { function, arguments, .. } => {
+            let mut can_inline = simplify_expression(function);
+            for arg in arguments.iter_mut() {
+                can_inline &= simplify_expression(arg);
+            }
+            if let Expression::BuiltinFunctionReference(f, _) = &**function {
+                if let Some(new) = fold_builtin_function(f, arguments) {
+                    *expr = new;
+                }
+            }
+            can_inline
+        }
         Expression::CallbackReference { .. } => false,
         Expression::ElementReference { .. } => false,
         // FIXME
@@ -140,6 +152,49 @@ fn simplify_expression(expr: &mut Expression) -> bool {
     }
 }
 
+/// Returns the value of a number literal, converted to the default unit of its type
+fn number_literal_value(expr: &Expression) -> Option<f64> {
+    match expr {
+        Expression::NumberLiteral(x, unit) => Some(unit.normalize(*x)),
+        Expression::UnaryOp { sub, op: '-' } => number_literal_value(sub).map(|x| -x),
+        _ => None,
+    }
+}
+
+/// Compute the result of a call to a math builtin function when all the arguments are constant
+fn fold_builtin_function(f: &BuiltinFunction, arguments: &[Expression]) -> Option<Expression> {
+    let args = arguments.iter().map(number_literal_value).collect::<Option<Vec<_>>>()?;
+    let number = |x: f64| x.is_finite().then_some(Expression::NumberLiteral(x, Unit::None));
+    let angle = |x: f64| x.is_finite().then_some(Expression::NumberLiteral(x, Unit::Deg));
+    match (f, args.as_slice()) {
+        (BuiltinFunction::Abs, [x]) => number(x.abs()),
+        (BuiltinFunction::Sqrt, [x]) => number(x.sqrt()),
+        (BuiltinFunction::Sin, [x]) => number(x.to_radians().sin()),
+        (BuiltinFunction::Cos, [x]) => number(x.to_radians().cos()),
+        (BuiltinFunction::Tan, [x]) => number(x.to_radians().tan()),
+        (BuiltinFunction::ASin, [x]) => angle(x.asin().to_degrees()),
+        (BuiltinFunction::ACos, [x]) => angle(x.acos().to_degrees()),
+        (BuiltinFunction::ATan, [x]) => angle(x.atan().to_degrees()),
+        (BuiltinFunction::ATan2, [y, x]) => angle(y.atan2(*x).to_degrees()),
+        (BuiltinFunction::Log, [x, base]) => number(x.log(*base)),
+        (BuiltinFunction::Ln, [x]) => number(x.ln()),
+        (BuiltinFunction::Pow, [x, y]) => number(x.powf(*y)),
+        (BuiltinFunction::Exp, [x]) => number(x.exp()),
+        (BuiltinFunction::Sign, [x]) => number(sign(*x)),
+        (BuiltinFunction::Clamp, [x, min, max]) => number(x.max(*min).min(*max)),
+        _ => None,
+    }
+}
+
+/// Same as `f64::signum`, except that the sign of zero is zero
+fn sign(x: f64) -> f64 {
+    if x == 0. {
+        0.
+    } else {
+        x.signum()
+    }
+}
+
 /// Will extract the property binding from the given named reference
 /// and propagate constant expression within it. If that's possible,
 /// return the new expression
@@ -176,3 +231,44 @@ fn extract_constant_property_reference(nr: &NamedReference) -> Option<Expression
     }
     Some(expression)
 }
+
+#[test]
+fn test_fold_math_functions() {
+    let mut compiler_config =
+        crate::CompilerConfiguration::new(crate::generator::OutputFormat::Interpreter);
+    compiler_config.style = Some("fluent".into());
+    let mut test_diags = crate::diagnostics::BuildDiagnostics::default();
+    let doc_node = crate::parser::parse(
+        r#"
+export component Foo {
+    out property <float> clamped: clamp(12, 0, 10);
+    out property <length> clamped-length: clamp(-2px, 0px, 10px);
+    out property <angle> angle: atan2(1, 1);
+    out property <float> exponential: exp(ln(2));
+    out property <float> negative: sign(-4);
+    out property <float> square-root: sqrt(abs(-16));
+    in property <float> input;
+    out property <float> not-const: clamp(self.input, 0, 1) + 1;
+}
+"#
+        .into(),
+        Some(std::path::Path::new("HELLO")),
+        &mut test_diags,
+    );
+    let (doc, diag) =
+        spin_on::spin_on(crate::compile_syntax_node(doc_node, test_diags, compiler_config));
+    assert!(!diag.has_error(), "{:?}", diag.to_string_vec());
+
+    let root = doc.root_component.root_element.borrow();
+    let binding = |name: &str| root.bindings.get(name).unwrap().borrow().expression.clone();
+    let number = |name: &str| match binding(name) {
+        Expression::NumberLiteral(x, _) => x,
+        e => panic!("{name} was not folded: {e:?}"),
+    };
+    assert_eq!(number("clamped"), 10.);
+    assert_eq!(number("angle"), 45.);
+    assert!((number("exponential") - 2.).abs() < 1e-10);
+    assert_eq!(number("negative"), -1.);
+    assert_eq!(number("square-root"), 4.);
+    assert!(!matches!(binding("not-const"), Expression::NumberLiteral(..)));
+}
@@ -125,7 +125,11 @@ fn collect_unconditional_read_count(expr: &Expression, result: &DedupPropState)
         Expression::Condition { condition, .. } => {
             condition.visit(|sub| collect_unconditional_read_count(sub, result))
         }
-        Expression::SelfAssignment { .. } => {
+        Expression::Match { value, .. } => {
+            value.visit(|sub| collect_unconditional_read_count(sub, result))
+        }
+        // Properties may change between iterations of a loop
+        Expression::SelfAssignment { .. } | Expression::WhileLoop { .. } => {
             result.counts.borrow_mut().has_set = true;
             return;
         }
@@ -147,7 +151,14 @@ fn process_conditional_expressions(expr: &mut Expression, state: &DedupPropState
             process_expression(true_expr, state);
             process_expression(false_expr, state);
         }
-        Expression::SelfAssignment { .. } => {
+        Expression::Match { value, arms, default } => {
+            value.visit_mut(|sub| process_conditional_expressions(sub, state));
+            arms.iter_mut().for_each(|(_, e)| process_expression(e, state));
+            if let Some(default) = default {
+                process_expression(default, state);
+            }
+        }
+        Expression::SelfAssignment { .. } | Expression::WhileLoop { .. } => {
             state.counts.borrow_mut().has_set = true;
             return;
         }
@@ -169,6 +180,9 @@ fn do_replacements(expr: &mut Expression, state: &DedupPropState) {
         Expression::Condition { condition, .. } => {
             condition.visit_mut(|sub| do_replacements(sub, state));
         }
+        Expression::Match { value, .. } => {
+            value.visit_mut(|sub| do_replacements(sub, state));
+        }
         _ => expr.visit_mut(|sub| do_replacements(sub, state)),
     }
 }
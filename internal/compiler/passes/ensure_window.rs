@@ -46,12 +46,14 @@ pub fn ensure_window(
         layout_info_prop: Default::default(),
         default_fill_parent: Default::default(),
         accessibility_props: Default::default(),
+        change_callbacks: Default::default(),
         is_flickable_viewport: false,
         item_index: Default::default(),
         item_index_of_first_children: Default::default(),
         node: win_elem_mut.node.clone(),
         inline_depth: 0,
         is_legacy_syntax: false,
+        debug: std::mem::take(&mut win_elem_mut.debug),
     };
     let new_root = Rc::new(RefCell::new(new_root));
     win_elem_mut.children.push(new_root.clone());
@@ -39,7 +39,7 @@ pub fn resolve_aliases(doc: &Document, diag: &mut BuildDiagnostics) {
                 // make it deterministic
                 need_resolving.sort();
                 for n in need_resolving {
-                    resolve_alias(elem, &n, &new_scope, &doc.local_registry, diag);
+                    resolve_alias(elem, &n, &new_scope, &doc.local_registry.borrow(), diag);
                 }
                 new_scope
             },
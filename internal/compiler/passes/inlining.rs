@@ -84,6 +84,7 @@ fn inline_element(
     let mut elem_mut = elem.borrow_mut();
     let priority_delta = 1 + elem_mut.inline_depth;
     elem_mut.base_type = inlined_component.root_element.borrow().base_type.clone();
+    elem_mut.debug.extend(inlined_component.root_element.borrow().debug.iter().cloned());
     elem_mut.property_declarations.extend(
         inlined_component.root_element.borrow().property_declarations.iter().map(clone_tuple),
     );
@@ -149,6 +150,7 @@ fn inline_element(
             .iter()
             .map(|p| duplicate_popup(p, &mut mapping, priority_delta)),
     );
+    root_component.timers.borrow_mut().extend(inlined_component.timers.borrow().iter().cloned());
     for (k, val) in inlined_component.root_element.borrow().bindings.iter() {
         match elem_mut.bindings.entry(k.clone()) {
             std::collections::btree_map::Entry::Vacant(entry) => {
@@ -164,6 +166,10 @@ fn inline_element(
         }
     }
 
+    elem_mut
+        .change_callbacks
+        .extend(inlined_component.root_element.borrow().change_callbacks.iter().cloned());
+
     if let Some(orig) = &inlined_component.root_element.borrow().layout_info_prop {
         if let Some(_new) = &mut elem_mut.layout_info_prop {
             todo!("Merge layout infos");
@@ -208,6 +214,9 @@ fn inline_element(
         fixup_reference(&mut p.x, &mapping);
         fixup_reference(&mut p.y, &mapping);
     }
+    for t in root_component.timers.borrow_mut().iter_mut() {
+        fixup_timer(t, &mapping);
+    }
 }
 
 // Duplicate the element elem and all its children. And fill the mapping to point from the old to the new
@@ -238,6 +247,7 @@ fn duplicate_element_with_mapping(
         node: elem.node.clone(),
         enclosing_component: Rc::downgrade(root_component),
         states: elem.states.clone(),
+        change_callbacks: elem.change_callbacks.clone(),
         transitions: elem
             .transitions
             .iter()
@@ -254,6 +264,7 @@ fn duplicate_element_with_mapping(
         has_popup_child: elem.has_popup_child,
         is_legacy_syntax: elem.is_legacy_syntax,
         inline_depth: elem.inline_depth + 1,
+        debug: elem.debug.clone(),
     }));
     mapping.insert(element_key(element.clone()), new.clone());
     if let ElementType::Component(c) = &mut new.borrow_mut().base_type {
@@ -305,9 +316,12 @@ fn duplicate_sub_component(
         init_code: component_to_duplicate.init_code.clone(),
         used_types: Default::default(),
         popup_windows: Default::default(),
+        timers: component_to_duplicate.timers.clone(),
         exported_global_names: component_to_duplicate.exported_global_names.clone(),
         is_root_component: Default::default(),
         private_properties: Default::default(),
+        generic: None,
+        generic_instance: None,
     };
 
     let new_component = Rc::new(new_component);
@@ -325,6 +339,9 @@ fn duplicate_sub_component(
         fixup_reference(&mut p.x, mapping);
         fixup_reference(&mut p.y, mapping);
     }
+    for t in new_component.timers.borrow_mut().iter_mut() {
+        fixup_timer(t, mapping);
+    }
     new_component
         .root_constraints
         .borrow_mut()
@@ -345,6 +362,7 @@ fn duplicate_popup(
         x: p.x.clone(),
         y: p.y.clone(),
         close_on_click: p.close_on_click.clone(),
+        is_tooltip: p.is_tooltip,
         component: duplicate_sub_component(&p.component, &parent, mapping, priority_delta),
         parent_element: mapping
             .get(&element_key(p.parent_element.clone()))
@@ -414,6 +432,12 @@ fn fixup_reference(nr: &mut NamedReference, mapping: &HashMap<ByAddress<ElementR
     }
 }
 
+fn fixup_timer(t: &mut Timer, mapping: &HashMap<ByAddress<ElementRc>, ElementRc>) {
+    fixup_reference(&mut t.interval, mapping);
+    fixup_reference(&mut t.running, mapping);
+    fixup_reference(&mut t.triggered, mapping);
+}
+
 fn fixup_element_references(
     expr: &mut Expression,
     mapping: &HashMap<ByAddress<ElementRc>, ElementRc>,
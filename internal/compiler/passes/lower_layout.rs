@@ -97,6 +97,15 @@ fn lower_element_layout(
     } else {
         return;
     };
+    if !matches!(
+        base_type.name.as_str(),
+        "Row" | "GridLayout" | "HorizontalLayout" | "VerticalLayout" | "Dialog"
+    ) {
+        return;
+    }
+
+    // Shortcuts don't take room in the layout, they are put back as children after the layout is lowered
+    let shortcuts = take_shortcut_children(elem);
     match base_type.name.as_str() {
         "Row" => panic!("Error caught at element lookup time"),
         "GridLayout" => lower_grid_layout(component, elem, diag),
@@ -104,10 +113,12 @@ fn lower_element_layout(
         "VerticalLayout" => lower_box_layout(elem, diag, Orientation::Vertical),
         "Dialog" => {
             lower_dialog_layout(elem, style_metrics, diag);
+            elem.borrow_mut().children.extend(shortcuts);
             return; // the Dialog stays in the tree as a Dialog
         }
-        _ => return,
+        _ => unreachable!(),
     };
+    elem.borrow_mut().children.extend(shortcuts);
 
     {
         let mut elem = elem.borrow_mut();
@@ -125,6 +136,14 @@ fn lower_element_layout(
     }
 }
 
+fn take_shortcut_children(elem: &ElementRc) -> Vec<ElementRc> {
+    let is_shortcut = |e: &ElementRc| matches!(&e.borrow().base_type, ElementType::Builtin(b) if b.name == "Shortcut");
+    let children = std::mem::take(&mut elem.borrow_mut().children);
+    let (shortcuts, children) = children.into_iter().partition(is_shortcut);
+    elem.borrow_mut().children = children;
+    shortcuts
+}
+
 pub fn is_layout_element(element: &ElementRc) -> bool {
     matches!(&element.borrow().base_type, ElementType::Builtin(n) if n.name == "GridLayout" || n.name == "HorizontalLayout" || n.name == "VerticalLayout")
 }
@@ -22,7 +22,7 @@ pub fn lower_popups(
         component,
         &None,
         &mut |elem, parent_element: &Option<ElementRc>| {
-            let is_popup = matches!(&elem.borrow().base_type, ElementType::Builtin(base_type) if base_type.name == "PopupWindow");
+            let is_popup = matches!(&elem.borrow().base_type, ElementType::Builtin(base_type) if base_type.name == "PopupWindow" || base_type.name == "ToolTipPopup");
             if is_popup {
                 lower_popup_window(elem, parent_element.as_ref(), &window_type, diag);
             }
@@ -67,6 +67,7 @@ fn lower_popup_window(
     );
     parent_element.borrow_mut().has_popup_child = true;
 
+    let is_tooltip = matches!(&popup_window_element.borrow().base_type, ElementType::Builtin(base_type) if base_type.name == "ToolTipPopup");
     popup_window_element.borrow_mut().base_type = window_type.clone();
 
     let close_on_click =
@@ -124,6 +125,7 @@ fn lower_popup_window(
         x: coord_x,
         y: coord_y,
         close_on_click,
+        is_tooltip,
         parent_element: parent_element.clone(),
     });
 }
@@ -0,0 +1,136 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-1.0 OR LicenseRef-Slint-commercial
+
+//! Pass that removes the Timer elements from the tree and registers them in the component
+
+use crate::diagnostics::BuildDiagnostics;
+use crate::expression_tree::{BuiltinFunction, Expression, NamedReference};
+use crate::langtype::{ElementType, Type};
+use crate::object_tree::*;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+const TIMER_PROPERTIES: [&str; 3] = ["interval", "running", "triggered"];
+
+pub fn lower_timers(component: &Rc<Component>, diag: &mut BuildDiagnostics) {
+    recurse_elem_including_sub_components_no_borrow(
+        component,
+        &None,
+        &mut |elem, parent_element: &Option<ElementRc>| {
+            let is_timer = matches!(&elem.borrow().base_type, ElementType::Builtin(base_type) if base_type.name == "Timer");
+            if is_timer {
+                lower_timer(component, elem, parent_element.as_ref(), diag);
+            }
+            Some(elem.clone())
+        },
+    )
+}
+
+fn lower_timer(
+    component: &Rc<Component>,
+    timer_element: &ElementRc,
+    parent_element: Option<&ElementRc>,
+    diag: &mut BuildDiagnostics,
+) {
+    let Some(parent_element) = parent_element else {
+        diag.push_error("A component cannot inherit from Timer".into(), &*timer_element.borrow());
+        return;
+    };
+
+    let parent_component = timer_element.borrow().enclosing_component.upgrade().unwrap();
+    if Rc::ptr_eq(&parent_component.root_element, timer_element) {
+        diag.push_error(
+            "Timer cannot be directly repeated or conditional".into(),
+            &*timer_element.borrow(),
+        );
+        return;
+    }
+    if !timer_element.borrow().children.is_empty() {
+        diag.push_error("Timer cannot have children elements".into(), &*timer_element.borrow());
+        return;
+    }
+    if !timer_element.borrow().bindings.contains_key("interval") {
+        diag.push_error(
+            "Timer must have a binding set for its 'interval' property".into(),
+            &*timer_element.borrow(),
+        );
+        return;
+    }
+
+    // Remove the timer_element from its parent
+    let old_size = parent_element.borrow().children.len();
+    parent_element.borrow_mut().children.retain(|child| !Rc::ptr_eq(child, timer_element));
+    debug_assert_eq!(
+        parent_element.borrow().children.len() + 1,
+        old_size,
+        "Exactly one child must be removed (the timer itself)"
+    );
+
+    // Move the properties of the timer into the parent element
+    let base_id = match timer_element.borrow().id.as_str() {
+        "" => format!("timer{}", parent_component.timers.borrow().len()),
+        id => id.to_string(),
+    };
+    // Make sure the new properties don't clash with the properties of the parent
+    let mut timer_id = base_id.clone();
+    let mut count = 0;
+    while TIMER_PROPERTIES.iter().any(|name| {
+        parent_element.borrow().lookup_property(&format!("{}-{}", timer_id, name)).property_type
+            != Type::Invalid
+    }) {
+        count += 1;
+        timer_id = format!("{}{}", base_id, count);
+    }
+    let map_name = |name: &str| format!("{}-{}", timer_id, name);
+    {
+        let mut timer = timer_element.borrow_mut();
+        let mut parent = parent_element.borrow_mut();
+        for name in TIMER_PROPERTIES {
+            let property_type = timer.lookup_property(name).property_type;
+            parent.property_declarations.insert(map_name(name), property_type.into());
+            let binding = timer.bindings.remove(name).unwrap_or_else(|| {
+                RefCell::new(match name {
+                    "running" => Expression::BoolLiteral(true).into(),
+                    _ => Expression::Invalid.into(),
+                })
+            });
+            if !matches!(binding.borrow().expression, Expression::Invalid)
+                || !binding.borrow().two_way_bindings.is_empty()
+            {
+                parent.bindings.insert(map_name(name), binding);
+            }
+            if let Some(analysis) = timer.property_analysis.borrow_mut().remove(name) {
+                parent.property_analysis.borrow_mut().insert(map_name(name), analysis);
+            }
+        }
+    }
+    let mut fixup_reference = |nr: &mut NamedReference| {
+        if Rc::ptr_eq(&nr.element(), timer_element) {
+            *nr = NamedReference::new(parent_element, &map_name(nr.name()));
+        }
+    };
+    visit_all_named_references(component, &mut fixup_reference);
+    let mut change_callbacks = std::mem::take(&mut timer_element.borrow_mut().change_callbacks);
+    for (nr, _) in &mut change_callbacks {
+        fixup_reference(nr);
+    }
+
+    let timer = Timer {
+        interval: NamedReference::new(parent_element, &map_name("interval")),
+        running: NamedReference::new(parent_element, &map_name("running")),
+        triggered: NamedReference::new(parent_element, &map_name("triggered")),
+    };
+    // Restart or stop the timers when their properties change
+    let update_timers = Expression::FunctionCall {
+        function: Box::new(Expression::BuiltinFunctionReference(
+            BuiltinFunction::UpdateTimers,
+            None,
+        )),
+        arguments: vec![],
+        source_location: None,
+    };
+    change_callbacks.push((timer.running.clone(), update_timers.clone()));
+    change_callbacks.push((timer.interval.clone(), update_timers));
+    parent_element.borrow_mut().change_callbacks.extend(change_callbacks);
+    parent_component.timers.borrow_mut().push(timer);
+}
@@ -44,6 +44,16 @@ fn can_optimize(elem: &ElementRc) -> bool {
         return false;
     };
 
+    if e.debug.iter().any(|info| !info.id.is_empty()) {
+        // Keep the elements with an id, so that they can be found at runtime
+        return false;
+    }
+
+    if !e.change_callbacks.is_empty() {
+        // The change callbacks are only run for the elements in the item tree
+        return false;
+    }
+
     if e.child_of_layout {
         // The `LayoutItem` still has reference to this component, so we cannot remove it
         return false;
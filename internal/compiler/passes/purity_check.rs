@@ -7,6 +7,7 @@
 /// Check that pure expression only call pure functions
 pub fn purity_check(doc: &crate::object_tree::Document, diag: &mut BuildDiagnostics) {
     for component in &doc.inner_components {
+        let diagnostic_count = diag.iter().count();
         crate::object_tree::recurse_elem_including_sub_components_no_borrow(
             component,
             &(),
@@ -15,6 +16,8 @@ pub fn purity_check(doc: &crate::object_tree::Document, diag: &mut BuildDiagnost
                     true => crate::diagnostics::DiagnosticLevel::Warning,
                     false => crate::diagnostics::DiagnosticLevel::Error,
                 };
+                // change callbacks are allowed to have side effects, so they are not visited
+                let change_callbacks = std::mem::take(&mut elem.borrow_mut().change_callbacks);
                 crate::object_tree::visit_element_expressions(elem, |expr, name, _| {
                     if let Some(name) = name {
                         let lookup = elem.borrow().lookup_property(name);
@@ -27,9 +30,11 @@ pub fn purity_check(doc: &crate::object_tree::Document, diag: &mut BuildDiagnost
                         // model expression must be pure
                         ensure_pure(expr, Some(diag), level);
                     };
-                })
+                });
+                elem.borrow_mut().change_callbacks = change_callbacks;
             },
-        )
+        );
+        component.report_errors_at_generic_use(diag, diagnostic_count);
     }
 }
 
@@ -97,6 +102,7 @@ fn ensure_pure(
                 r = false;
             }
         }
+        Expression::SelfAssignment { lhs, .. } if is_local_variable(lhs) => (),
         Expression::SelfAssignment { node, .. } => {
             if let Some(diag) = diag.as_deref_mut() {
                 diag.push_diagnostic("Assignment in a pure context".into(), node, level);
@@ -107,3 +113,12 @@ fn ensure_pure(
     });
     r
 }
+
+/// Assigning a local variable (or a field of a local struct) has no side effects
+fn is_local_variable(lhs: &Expression) -> bool {
+    match lhs {
+        Expression::ReadLocalVariable { .. } => true,
+        Expression::StructFieldAccess { base, .. } => is_local_variable(base),
+        _ => false,
+    }
+}
@@ -39,6 +39,7 @@ fn create_repeater_components(component: &Rc<Component>) {
                 enclosing_component: Default::default(),
                 states: std::mem::take(&mut elem.states),
                 transitions: std::mem::take(&mut elem.transitions),
+                change_callbacks: std::mem::take(&mut elem.change_callbacks),
                 child_of_layout: elem.child_of_layout || is_listview.is_some(),
                 layout_info_prop: elem.layout_info_prop.take(),
                 default_fill_parent: elem.default_fill_parent,
@@ -49,6 +50,7 @@ fn create_repeater_components(component: &Rc<Component>) {
                 item_index_of_first_children: Default::default(),
                 is_legacy_syntax: elem.is_legacy_syntax,
                 inline_depth: 0,
+                debug: std::mem::take(&mut elem.debug),
             })),
             parent_element,
             ..Component::default()
@@ -11,7 +11,7 @@
 use crate::diagnostics::{BuildDiagnostics, Spanned};
 use crate::expression_tree::*;
 use crate::langtype::{ElementType, Type};
-use crate::lookup::{LookupCtx, LookupObject, LookupResult};
+use crate::lookup::{local_variable_name, LookupCtx, LookupObject, LookupResult};
 use crate::object_tree::*;
 use crate::parser::{identifier_text, syntax_nodes, NodeOrToken, SyntaxKind, SyntaxNode};
 use crate::typeregister::TypeRegister;
@@ -39,6 +39,8 @@ fn resolve_expression(
             component_scope: &scope.0,
             diag,
             arguments: vec![],
+            local_variables: vec![],
+            generated_local_variables: 0,
             type_register,
             type_loader: Some(type_loader),
             current_token: None,
@@ -49,6 +51,11 @@ fn resolve_expression(
                 //FIXME: proper callback support (node is a codeblock)
                 Expression::from_callback_connection(node.clone().into(), &mut lookup_ctx)
             }
+            SyntaxKind::ChangedCallback => Expression::from_codeblock_node(
+                syntax_nodes::ChangedCallback::from(node.clone()).CodeBlock(),
+                &mut lookup_ctx,
+            )
+            .maybe_convert_to(Type::Void, node, diag),
             SyntaxKind::Function => Expression::from_function(node.clone().into(), &mut lookup_ctx),
             SyntaxKind::Expression => {
                 //FIXME again: this happen for non-binding expression (i.e: model)
@@ -76,10 +83,17 @@ pub fn resolve_expressions(
     type_loader: &crate::typeloader::TypeLoader,
     diag: &mut BuildDiagnostics,
 ) {
-    resolve_two_way_bindings(doc, &doc.local_registry, diag);
+    resolve_two_way_bindings(doc, diag);
 
     for component in doc.inner_components.iter() {
+        let diagnostic_count = diag.iter().count();
         let scope = ComponentScope(vec![]);
+        let registry = component
+            .generic_instance
+            .as_ref()
+            .map_or(&doc.local_registry, |i| &i.type_register)
+            .borrow();
+        let type_register = &*registry;
 
         recurse_elem(&component.root_element, &scope, &mut |elem, scope| {
             let mut new_scope = scope.clone();
@@ -94,7 +108,7 @@ pub fn resolve_expressions(
                         property_name,
                         property_type(),
                         scope,
-                        &doc.local_registry,
+                        type_register,
                         type_loader,
                         diag,
                     );
@@ -105,14 +119,15 @@ pub fn resolve_expressions(
                         property_name,
                         property_type(),
                         &new_scope,
-                        &doc.local_registry,
+                        type_register,
                         type_loader,
                         diag,
                     )
                 }
             });
             new_scope
-        })
+        });
+        component.report_errors_at_generic_use(diag, diagnostic_count);
     }
 }
 
@@ -126,6 +141,9 @@ enum LookupPhase {
     ResolvingTwoWayBindings,
 }
 
+/// The maximum number of times the body of a `while` loop is evaluated
+const MAX_WHILE_ITERATIONS: usize = 100_000;
+
 impl Expression {
     pub fn from_binding_expression_node(node: SyntaxNode, ctx: &mut LookupCtx) -> Self {
         debug_assert_eq!(node.kind(), SyntaxKind::BindingExpression);
@@ -161,14 +179,20 @@ pub fn from_binding_expression_node(node: SyntaxNode, ctx: &mut LookupCtx) -> Se
     fn from_codeblock_node(node: syntax_nodes::CodeBlock, ctx: &mut LookupCtx) -> Expression {
         debug_assert_eq!(node.kind(), SyntaxKind::CodeBlock);
 
+        // The local variables declared in this block are only visible within it
+        ctx.local_variables.push(vec![]);
         let mut statements_or_exprs = node
             .children()
             .filter_map(|n| match n.kind() {
                 SyntaxKind::Expression => Some(Self::from_expression_node(n.into(), ctx)),
+                SyntaxKind::LetStatement => Some(Self::from_let_statement(n.into(), ctx)),
                 SyntaxKind::ReturnStatement => Some(Self::from_return_statement(n.into(), ctx)),
+                SyntaxKind::ForStatement => Some(Self::from_for_statement(n.into(), ctx)),
+                SyntaxKind::WhileStatement => Some(Self::from_while_statement(n.into(), ctx)),
                 _ => None,
             })
             .collect::<Vec<_>>();
+        ctx.local_variables.pop();
 
         let exit_points_and_return_types = statements_or_exprs
             .iter()
@@ -211,6 +235,199 @@ fn from_return_statement(
         }))
     }
 
+    /// Add a local variable to the innermost scope of `ctx`
+    fn declare_local_variable(name: String, ty: Type, node: &dyn Spanned, ctx: &mut LookupCtx) {
+        if ctx.local_variables.iter().flatten().any(|(n, _)| *n == name) {
+            ctx.diag.push_error(format!("Redeclaration of local variable '{name}'"), node);
+        }
+        ctx.local_variables
+            .last_mut()
+            .expect("local variable outside of a code block")
+            .push((name, ty));
+    }
+
+    fn from_let_statement(node: syntax_nodes::LetStatement, ctx: &mut LookupCtx) -> Expression {
+        let name = identifier_text(&node.DeclaredIdentifier()).unwrap_or_default();
+        let value = Self::from_expression_node(node.Expression(), ctx);
+        let ty = match node.Type() {
+            Some(ty) => type_from_node(ty, ctx.diag, ctx.type_register),
+            None => {
+                let ty = value.ty();
+                if matches!(&ty, Type::Array(inner) if **inner == Type::Invalid) {
+                    ctx.diag.push_error(
+                        format!("Cannot infer the type of '{name}', specify it with `let {name}: type = ...`"),
+                        &node,
+                    );
+                    Type::Invalid
+                } else {
+                    ty
+                }
+            }
+        };
+        let value = value.maybe_convert_to(ty.clone(), &node.Expression(), ctx.diag);
+        if ty != Type::Invalid && !ty.is_property_type() {
+            ctx.diag.push_error(format!("Cannot declare a local variable of type {ty}"), &node);
+        }
+        Self::declare_local_variable(name.clone(), ty, &node.DeclaredIdentifier(), ctx);
+        Expression::StoreLocalVariable { name: local_variable_name(&name), value: Box::new(value) }
+    }
+
+    /// A `for` loop is lowered to a `while` loop over the index of the model:
+    /// ```text
+    /// let model = <model>;
+    /// let index = 0;
+    /// while (index < model.length) { let item = model[index]; <body>; index += 1; }
+    /// ```
+    fn from_for_statement(node: syntax_nodes::ForStatement, ctx: &mut LookupCtx) -> Expression {
+        let model = Self::from_expression_node(node.Expression(), ctx);
+        let model_ty = model.ty();
+        let item_ty = match &model_ty {
+            Type::Array(ty) => (**ty).clone(),
+            Type::Invalid => Type::Invalid,
+            ty => {
+                ctx.diag.push_error(
+                    format!("Cannot iterate over a value of type {ty}, only arrays are supported"),
+                    &node.Expression(),
+                );
+                Type::Invalid
+            }
+        };
+
+        let count = ctx.unique_local_variable_suffix();
+        let (model_var, index_var) = (format!("for_model{count}"), format!("for_index{count}"));
+        let read_model = Expression::ReadLocalVariable { name: model_var.clone(), ty: model_ty };
+        let read_index = Expression::ReadLocalVariable { name: index_var.clone(), ty: Type::Int32 };
+
+        ctx.local_variables.push(vec![]);
+        let item_name = identifier_text(&node.DeclaredIdentifier()).unwrap_or_default();
+        let mut body = vec![Expression::StoreLocalVariable {
+            name: local_variable_name(&item_name),
+            value: Box::new(Expression::ArrayIndex {
+                array: Box::new(read_model.clone()),
+                index: Box::new(read_index.clone()),
+            }),
+        }];
+        Self::declare_local_variable(item_name, item_ty.clone(), &node.DeclaredIdentifier(), ctx);
+        if let Some(index) = node.RepeatedIndex() {
+            let index_name = identifier_text(&index).unwrap_or_default();
+            body.push(Expression::StoreLocalVariable {
+                name: local_variable_name(&index_name),
+                value: Box::new(read_index.clone()),
+            });
+            Self::declare_local_variable(index_name, Type::Int32, &index, ctx);
+        }
+        body.push(Self::from_codeblock_node(node.CodeBlock(), ctx));
+        ctx.local_variables.pop();
+        body.push(Expression::SelfAssignment {
+            lhs: Box::new(read_index.clone()),
+            rhs: Box::new(Expression::NumberLiteral(1., Unit::None)),
+            op: '+',
+            node: None,
+        });
+
+        if item_ty == Type::Invalid {
+            return Expression::Invalid;
+        }
+        Expression::CodeBlock(vec![
+            Expression::StoreLocalVariable { name: model_var, value: Box::new(model) },
+            Expression::StoreLocalVariable {
+                name: index_var,
+                value: Box::new(Expression::Cast {
+                    from: Box::new(Expression::NumberLiteral(0., Unit::None)),
+                    to: Type::Int32,
+                }),
+            },
+            Expression::WhileLoop {
+                condition: Box::new(Expression::BinaryExpression {
+                    lhs: Box::new(read_index),
+                    rhs: Box::new(Expression::FunctionCall {
+                        function: Box::new(Expression::BuiltinFunctionReference(
+                            BuiltinFunction::ArrayLength,
+                            None,
+                        )),
+                        arguments: vec![read_model],
+                        source_location: None,
+                    }),
+                    op: '<',
+                }),
+                body: Box::new(Expression::CodeBlock(body)),
+            },
+        ])
+    }
+
+    /// A `while` loop is bounded by a counter, so that a condition that stays true can't block
+    /// the event loop forever. Reaching the bound is reported at run-time:
+    /// ```text
+    /// let iterations = 0;
+    /// while (<condition> ? (iterations < MAX_WHILE_ITERATIONS ? true : { debug(...); false }) : false) {
+    ///     <body>; iterations += 1;
+    /// }
+    /// ```
+    fn from_while_statement(node: syntax_nodes::WhileStatement, ctx: &mut LookupCtx) -> Expression {
+        let condition = Self::from_expression_node(node.Expression(), ctx).maybe_convert_to(
+            Type::Bool,
+            &node.Expression(),
+            ctx.diag,
+        );
+        let body = Self::from_codeblock_node(node.CodeBlock(), ctx);
+
+        let source_location = Some(node.to_source_location());
+        let stopped_message = format!(
+            "{}:{}: while loop stopped after {MAX_WHILE_ITERATIONS} iterations",
+            node.source_file.path().file_name().unwrap_or_default().to_string_lossy(),
+            node.source_file.line_column(node.text_range().start().into()).0,
+        );
+        let iterations_var = format!("while_iterations{}", ctx.unique_local_variable_suffix());
+        let read_iterations =
+            Expression::ReadLocalVariable { name: iterations_var.clone(), ty: Type::Int32 };
+        Expression::CodeBlock(vec![
+            Expression::StoreLocalVariable {
+                name: iterations_var,
+                value: Box::new(Expression::Cast {
+                    from: Box::new(Expression::NumberLiteral(0., Unit::None)),
+                    to: Type::Int32,
+                }),
+            },
+            Expression::WhileLoop {
+                condition: Box::new(Expression::Condition {
+                    condition: Box::new(condition),
+                    true_expr: Box::new(Expression::Condition {
+                        condition: Box::new(Expression::BinaryExpression {
+                            lhs: Box::new(read_iterations.clone()),
+                            rhs: Box::new(Expression::NumberLiteral(
+                                MAX_WHILE_ITERATIONS as _,
+                                Unit::None,
+                            )),
+                            op: '<',
+                        }),
+                        true_expr: Box::new(Expression::BoolLiteral(true)),
+                        false_expr: Box::new(Expression::CodeBlock(vec![
+                            Expression::FunctionCall {
+                                function: Box::new(Expression::BuiltinFunctionReference(
+                                    BuiltinFunction::Debug,
+                                    source_location.clone(),
+                                )),
+                                arguments: vec![Expression::StringLiteral(stopped_message)],
+                                source_location,
+                            },
+                            Expression::BoolLiteral(false),
+                        ])),
+                    }),
+                    false_expr: Box::new(Expression::BoolLiteral(false)),
+                }),
+                body: Box::new(Expression::CodeBlock(vec![
+                    body,
+                    Expression::SelfAssignment {
+                        lhs: Box::new(read_iterations),
+                        rhs: Box::new(Expression::NumberLiteral(1., Unit::None)),
+                        op: '+',
+                        node: None,
+                    },
+                ])),
+            },
+        ])
+    }
+
     fn from_callback_connection(
         node: syntax_nodes::CallbackConnection,
         ctx: &mut LookupCtx,
@@ -303,10 +520,20 @@ fn from_expression_node(node: syntax_nodes::Expression, ctx: &mut LookupCtx) ->
             .or_else(|| {
                 node.ConditionalExpression().map(|n| Self::from_conditional_expression_node(n, ctx))
             })
+            .or_else(|| node.MatchExpression().map(|n| Self::from_match_expression_node(n, ctx)))
             .or_else(|| node.ObjectLiteral().map(|n| Self::from_object_literal_node(n, ctx)))
             .or_else(|| node.Array().map(|n| Self::from_array_node(n, ctx)))
             .or_else(|| node.CodeBlock().map(|n| Self::from_codeblock_node(n, ctx)))
             .or_else(|| node.StringTemplate().map(|n| Self::from_string_template_node(n, ctx)))
+            .or_else(|| {
+                node.Lambda().map(|n| {
+                    ctx.diag.push_error(
+                        "Lambdas can only be used as argument of array functions such as `filter` or `map`".into(),
+                        &n,
+                    );
+                    Self::Invalid
+                })
+            })
             .unwrap_or(Self::Invalid)
     }
 
@@ -837,6 +1064,23 @@ fn from_function_call_node(
                 .unwrap_or_else(|| Self::from_expression_node(n, ctx))
         });
 
+        let function = match function {
+            Expression::MemberFunction { base, member, .. }
+                if matches!(
+                    &*member,
+                    Expression::BuiltinMacroReference(
+                        BuiltinMacroFunction::ArrayOperation(_)
+                            | BuiltinMacroFunction::ArrayContains,
+                        _
+                    )
+                ) =>
+            {
+                let Expression::BuiltinMacroReference(mac, _) = *member else { unreachable!() };
+                return Self::from_array_function(mac, *base, sub_expr.collect(), &node, ctx);
+            }
+            function => function,
+        };
+
         let sub_expr = sub_expr.map(|n| {
             (Self::from_expression_node(n.clone(), ctx), Some(NodeOrToken::from((*n).clone())))
         });
@@ -844,7 +1088,15 @@ fn from_function_call_node(
         let function = match function {
             Expression::BuiltinMacroReference(mac, n) => {
                 arguments.extend(sub_expr);
-                return crate::builtin_macros::lower_macro(mac, n, arguments.into_iter(), ctx.diag);
+                return crate::builtin_macros::lower_macro(mac, n, arguments.into_iter(), ctx);
+            }
+            Expression::MemberFunction { base, base_node, member }
+                if matches!(*member, Expression::BuiltinMacroReference(..)) =>
+            {
+                let Expression::BuiltinMacroReference(mac, n) = *member else { unreachable!() };
+                arguments.push((*base, base_node));
+                arguments.extend(sub_expr);
+                return crate::builtin_macros::lower_macro(mac, n, arguments.into_iter(), ctx);
             }
             Expression::MemberFunction { base, base_node, member } => {
                 arguments.push((*base, base_node));
@@ -887,6 +1139,145 @@ fn from_function_call_node(
         }
     }
 
+    /// `array.filter(item => ...)` and the other functions on arrays that are not builtin functions
+    /// because they take a lambda or have an argument of the type of the items
+    fn from_array_function(
+        mac: BuiltinMacroFunction,
+        array: Expression,
+        arguments: Vec<syntax_nodes::Expression>,
+        node: &syntax_nodes::FunctionCallExpression,
+        ctx: &mut LookupCtx,
+    ) -> Expression {
+        let item_ty = match array.ty() {
+            Type::Array(ty) => *ty,
+            _ => Type::Invalid,
+        };
+        let expected_arguments =
+            if matches!(mac, BuiltinMacroFunction::ArrayOperation(ArrayOperation::Reverse)) {
+                0
+            } else {
+                1
+            };
+        if arguments.len() != expected_arguments {
+            ctx.diag.push_error(
+                format!(
+                    "The callback or function expects {} arguments, but {} are provided",
+                    expected_arguments,
+                    arguments.len()
+                ),
+                node,
+            );
+            return Expression::Invalid;
+        }
+        let operation =
+            match mac {
+                BuiltinMacroFunction::ArrayOperation(ArrayOperation::Reverse) => {
+                    return Expression::ArrayOperation {
+                        array: Box::new(array),
+                        operation: ArrayOperation::Reverse,
+                        lambda: None,
+                    }
+                }
+                BuiltinMacroFunction::ArrayOperation(operation) => operation,
+                _ => {
+                    // `contains(value)` is `find-index(item => item == value) != -1`
+                    let value = Self::from_expression_node(arguments[0].clone(), ctx)
+                        .maybe_convert_to(item_ty.clone(), &arguments[0], ctx.diag);
+                    let count = ctx.unique_local_variable_suffix();
+                    let (value_var, item_var) =
+                        (format!("contains_value{count}"), format!("contains_item{count}"));
+                    let read = |name: &String| Expression::ReadLocalVariable {
+                        name: name.clone(),
+                        ty: item_ty.clone(),
+                    };
+                    let condition = Expression::BinaryExpression {
+                        lhs: Box::new(read(&item_var)),
+                        rhs: Box::new(read(&value_var)),
+                        op: '=',
+                    };
+                    return Expression::CodeBlock(vec![
+                        Expression::StoreLocalVariable { name: value_var, value: Box::new(value) },
+                        Expression::BinaryExpression {
+                            lhs: Box::new(Expression::ArrayOperation {
+                                array: Box::new(array),
+                                operation: ArrayOperation::FindIndex,
+                                lambda: Some((item_var, Box::new(condition))),
+                            }),
+                            rhs: Box::new(Expression::Cast {
+                                from: Box::new(Expression::NumberLiteral(-1., Unit::None)),
+                                to: Type::Int32,
+                            }),
+                            op: '!',
+                        },
+                    ]);
+                }
+            };
+
+        let Some(lambda) = arguments[0].Lambda() else {
+            ctx.diag.push_error(
+                "Expected a lambda such as `item => ...` as argument".into(),
+                &arguments[0],
+            );
+            return Expression::Invalid;
+        };
+        ctx.local_variables.push(vec![]);
+        let item_name = identifier_text(&lambda.DeclaredIdentifier()).unwrap_or_default();
+        Self::declare_local_variable(
+            item_name.clone(),
+            item_ty.clone(),
+            &lambda.DeclaredIdentifier(),
+            ctx,
+        );
+        let (body, body_node): (_, SyntaxNode) = match (lambda.Expression(), lambda.CodeBlock()) {
+            (Some(e), _) => (Self::from_expression_node(e.clone(), ctx), e.into()),
+            (None, Some(c)) => {
+                if let Some(node) =
+                    c.descendants().find(|n| n.kind() == SyntaxKind::ReturnStatement)
+                {
+                    let node = SyntaxNode { node, source_file: c.source_file.clone() };
+                    ctx.diag.push_error("Cannot return from a lambda".into(), &node);
+                    ctx.local_variables.pop();
+                    return Expression::Invalid;
+                }
+                (Self::from_codeblock_node(c.clone(), ctx), c.into())
+            }
+            (None, None) => (Expression::Invalid, lambda.clone().into()),
+        };
+        ctx.local_variables.pop();
+
+        let body = match operation {
+            ArrayOperation::Filter | ArrayOperation::FindIndex => {
+                body.maybe_convert_to(Type::Bool, &body_node, ctx.diag)
+            }
+            ArrayOperation::SortBy => {
+                let ty = body.ty();
+                if !matches!(ty, Type::String | Type::Invalid) && ty.as_unit_product().is_none() {
+                    ctx.diag.push_error(
+                        format!("Cannot sort by a value of type {ty}, only numbers and strings are supported"),
+                        &body_node,
+                    );
+                }
+                body
+            }
+            ArrayOperation::Map => {
+                let ty = body.ty();
+                if ty != Type::Invalid && !ty.is_property_type() {
+                    ctx.diag.push_error(format!("Cannot map to a value of type {ty}"), &body_node);
+                }
+                body
+            }
+            ArrayOperation::Reverse => unreachable!(),
+        };
+        if item_ty == Type::Invalid {
+            return Expression::Invalid;
+        }
+        Expression::ArrayOperation {
+            array: Box::new(array),
+            operation,
+            lambda: Some((local_variable_name(&item_name), Box::new(body))),
+        }
+    }
+
     fn from_member_access_node(
         node: syntax_nodes::MemberAccess,
         ctx: &mut LookupCtx,
@@ -1093,6 +1484,110 @@ fn from_conditional_expression_node(
         }
     }
 
+    fn from_match_expression_node(
+        node: syntax_nodes::MatchExpression,
+        ctx: &mut LookupCtx,
+    ) -> Expression {
+        let value = Self::from_expression_node(node.Expression(), ctx);
+        let enumeration = match value.ty() {
+            Type::Enumeration(e) => Some(e),
+            Type::Invalid => None,
+            ty => {
+                ctx.diag.push_error(
+                    format!(
+                        "Cannot match on a value of type {ty}, only enumerations are supported"
+                    ),
+                    &node.Expression(),
+                );
+                None
+            }
+        };
+
+        let mut covered = vec![false; enumeration.as_ref().map_or(0, |e| e.values.len())];
+        let mut arms = vec![];
+        let mut default = None;
+        for arm in node.MatchArm() {
+            let mut values = vec![];
+            let mut is_default = false;
+            for pattern in arm.QualifiedName() {
+                if pattern.text().to_string().trim() == "_" {
+                    if default.is_some() || is_default {
+                        ctx.diag.push_error("Duplicated '_' pattern".into(), &pattern);
+                    } else if !covered.is_empty() && covered.iter().all(|c| *c) {
+                        ctx.diag.push_warning(
+                            "Unreachable '_' pattern: all values are already matched".into(),
+                            &pattern,
+                        );
+                    }
+                    is_default = true;
+                    continue;
+                }
+                let Some(enumeration) = &enumeration else { continue };
+                let name = QualifiedTypeName::from_node(pattern.clone());
+                let value_name = match name.members.as_slice() {
+                    [value] => Some(value),
+                    [enum_name, value] if *enum_name == enumeration.name => Some(value),
+                    _ => None,
+                };
+                match value_name.and_then(|v| enumeration.values.iter().position(|x| x == v)) {
+                    Some(v) if covered[v] => ctx.diag.push_error(
+                        format!("'{}' is already matched by a previous pattern", name),
+                        &pattern,
+                    ),
+                    Some(v) => {
+                        covered[v] = true;
+                        values.push(v);
+                    }
+                    None => ctx.diag.push_error(
+                        format!("'{}' is not a value of the enum {}", name, enumeration.name),
+                        &pattern,
+                    ),
+                }
+            }
+            let expr = Self::from_expression_node(arm.Expression(), ctx);
+            if is_default {
+                default.get_or_insert((expr, arm.Expression()));
+            } else {
+                arms.push((values, expr, arm.Expression()));
+            }
+        }
+
+        let Some(enumeration) = enumeration else { return Expression::Invalid };
+        let missing = enumeration
+            .values
+            .iter()
+            .zip(&covered)
+            .filter(|(_, c)| !**c)
+            .map(|(v, _)| format!("'{v}'"))
+            .collect::<Vec<_>>();
+        if arms.is_empty() && default.is_none() {
+            ctx.diag.push_error("A match expression needs at least one arm".into(), &node);
+            return Expression::Invalid;
+        }
+        if default.is_none() && !missing.is_empty() {
+            ctx.diag.push_error(
+                format!(
+                    "Non-exhaustive match: {} not covered. Add the missing values or a '_' pattern",
+                    missing.join(", ")
+                ),
+                &node,
+            );
+        }
+
+        let result_ty = Self::common_target_type_for_type_list(
+            arms.iter().map(|(_, e, _)| e).chain(default.as_ref().map(|(e, _)| e)).map(|e| e.ty()),
+        );
+        Expression::Match {
+            value: Box::new(value),
+            arms: arms
+                .into_iter()
+                .map(|(values, e, n)| (values, e.maybe_convert_to(result_ty.clone(), &n, ctx.diag)))
+                .collect(),
+            default: default
+                .map(|(e, n)| Box::new(e.maybe_convert_to(result_ty.clone(), &n, ctx.diag))),
+        }
+    }
+
     fn from_index_expression_node(
         node: syntax_nodes::IndexExpression,
         ctx: &mut LookupCtx,
@@ -1223,6 +1718,12 @@ fn common_target_type_for_type_list(types: impl Iterator<Item = Type>) -> Type {
                         }
                     }
                     (Type::Color, Type::Brush) | (Type::Brush, Type::Color) => Type::Brush,
+                    // `none` mixed with values of another type
+                    (Type::Optional(none), ty) | (ty, Type::Optional(none))
+                        if *none == Type::Void && !matches!(ty, Type::Optional(_)) =>
+                    {
+                        Type::Optional(Box::new(ty))
+                    }
                     (target_type, expr_ty) => {
                         if expr_ty.can_convert(&target_type) {
                             target_type
@@ -1420,13 +1921,16 @@ fn maybe_lookup_object(
 }
 
 /// Go through all the two way binding and resolve them first
-fn resolve_two_way_bindings(
-    doc: &Document,
-    type_register: &TypeRegister,
-    diag: &mut BuildDiagnostics,
-) {
+fn resolve_two_way_bindings(doc: &Document, diag: &mut BuildDiagnostics) {
     for component in doc.inner_components.iter() {
+        let diagnostic_count = diag.iter().count();
         let scope = ComponentScope(vec![]);
+        let registry = component
+            .generic_instance
+            .as_ref()
+            .map_or(&doc.local_registry, |i| &i.type_register)
+            .borrow();
+        let type_register = &*registry;
 
         recurse_elem(&component.root_element, &scope, &mut |elem, scope| {
             let mut new_scope = scope.clone();
@@ -1447,6 +1951,8 @@ fn resolve_two_way_bindings(
                             component_scope: &new_scope.0,
                             diag,
                             arguments: vec![],
+                            local_variables: vec![],
+                            generated_local_variables: 0,
                             type_register,
                             type_loader: None,
                             current_token: Some(node.clone().into()),
@@ -1525,7 +2031,8 @@ fn resolve_two_way_bindings(
                 }
             }
             new_scope
-        })
+        });
+        component.report_errors_at_generic_use(diag, diagnostic_count);
     }
 
     fn marked_linked_read_only(elem: &ElementRc, prop_name: &str) {
@@ -187,7 +187,7 @@ pub fn reserved_member_function(name: &str) -> Option<BuiltinFunction> {
     None
 }
 
-#[derive(Debug, Default)]
+#[derive(Debug, Default, Clone)]
 pub struct TypeRegister {
     /// The set of property types.
     types: HashMap<String, Type>,
@@ -252,28 +252,30 @@ pub fn builtin() -> Rc<RefCell<Self>> {
             .for_each(|ty| ty.collect_contextual_types(&mut context_restricted_types));
         register.context_restricted_types = context_restricted_types;
 
-        match &mut register.elements.get_mut("PopupWindow").unwrap() {
-            ElementType::Builtin(ref mut b) => {
-                Rc::get_mut(b).unwrap().properties.insert(
-                    "show".into(),
-                    BuiltinPropertyInfo::new(BuiltinFunction::ShowPopupWindow.ty()),
-                );
-                Rc::get_mut(b)
-                    .unwrap()
-                    .member_functions
-                    .insert("show".into(), BuiltinFunction::ShowPopupWindow);
-                Rc::get_mut(b).unwrap().properties.insert(
-                    "close".into(),
-                    BuiltinPropertyInfo::new(BuiltinFunction::ClosePopupWindow.ty()),
-                );
-                Rc::get_mut(b)
-                    .unwrap()
-                    .member_functions
-                    .insert("close".into(), BuiltinFunction::ClosePopupWindow);
-            }
+        for popup_type in ["PopupWindow", "ToolTipPopup"] {
+            match &mut register.elements.get_mut(popup_type).unwrap() {
+                ElementType::Builtin(ref mut b) => {
+                    Rc::get_mut(b).unwrap().properties.insert(
+                        "show".into(),
+                        BuiltinPropertyInfo::new(BuiltinFunction::ShowPopupWindow.ty()),
+                    );
+                    Rc::get_mut(b)
+                        .unwrap()
+                        .member_functions
+                        .insert("show".into(), BuiltinFunction::ShowPopupWindow);
+                    Rc::get_mut(b).unwrap().properties.insert(
+                        "close".into(),
+                        BuiltinPropertyInfo::new(BuiltinFunction::ClosePopupWindow.ty()),
+                    );
+                    Rc::get_mut(b)
+                        .unwrap()
+                        .member_functions
+                        .insert("close".into(), BuiltinFunction::ClosePopupWindow);
+                }
 
-            _ => unreachable!(),
-        };
+                _ => unreachable!(),
+            };
+        }
 
         Rc::new(RefCell::new(register))
     }
@@ -355,6 +357,10 @@ pub fn add_with_name(&mut self, name: String, comp: Rc<Component>) {
         self.elements.insert(name, ElementType::Component(comp));
     }
 
+    pub fn insert_element_with_name(&mut self, name: String, ty: ElementType) {
+        self.elements.insert(name, ty);
+    }
+
     pub fn add_builtin(&mut self, builtin: Rc<BuiltinElement>) {
         self.elements.insert(builtin.name.clone(), ElementType::Builtin(builtin));
     }
@@ -101,6 +101,18 @@ pub struct ComponentVTable {
         result: &mut SharedString,
     ),
 
+    /// Returns the type names and ids of the elements of the .slint sources the item comes from,
+    /// one `TypeName::id` per line. Returns false if the component was compiled without debug info.
+    pub item_element_infos: extern "C" fn(
+        core::pin::Pin<VRef<ComponentVTable>>,
+        item_index: usize,
+        result: &mut SharedString,
+    ) -> bool,
+
+    /// Return the indices in the item tree of the `Shortcut` items, and of the dynamic nodes whose
+    /// components contain some, so that the shortcuts can be found without visiting all the items.
+    pub get_shortcut_index: extern "C" fn(core::pin::Pin<VRef<ComponentVTable>>) -> Slice<u32>,
+
     /// in-place destructor (for VRc)
     pub drop_in_place: unsafe fn(VRefMut<ComponentVTable>) -> vtable::Layout,
     /// dealloc function (for VRc)
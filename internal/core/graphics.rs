@@ -50,7 +50,6 @@
 #[cfg(feature = "std")]
 pub mod rendering_metrics_collector;
 
-#[cfg(feature = "box-shadow-cache")]
 pub mod boxshadowcache;
 
 /// CachedGraphicsData allows the graphics backend to store an arbitrary piece of data associated with
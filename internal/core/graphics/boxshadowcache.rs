@@ -5,9 +5,11 @@
 This module contains a cache helper for caching box shadow textures.
 */
 
-use std::{cell::RefCell, collections::BTreeMap};
+use alloc::collections::BTreeMap;
+use core::cell::{Cell, RefCell};
 
 use super::euclid;
+#[cfg(feature = "std")]
 use crate::items::ItemRc;
 use crate::{
     lengths::{PhysicalPx, ScaleFactor},
@@ -31,23 +33,23 @@ pub struct BoxShadowOptions {
 
 impl Eq for BoxShadowOptions {}
 impl Ord for BoxShadowOptions {
-    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
         if (other.width, other.height, other.color, other.blur, other.radius)
             < (self.width, self.height, self.color, self.blur, self.radius)
         {
-            std::cmp::Ordering::Less
+            core::cmp::Ordering::Less
         } else if (self.width, self.height, self.color, self.blur, self.radius)
             < (other.width, other.height, other.color, other.blur, other.radius)
         {
-            std::cmp::Ordering::Greater
+            core::cmp::Ordering::Greater
         } else {
-            std::cmp::Ordering::Equal
+            core::cmp::Ordering::Equal
         }
     }
 }
 
 impl PartialOrd for BoxShadowOptions {
-    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
         Some(self.cmp(other))
     }
 }
@@ -57,7 +59,7 @@ impl BoxShadowOptions {
     /// coordinates to physical pixels used in the BoxShadowOptions. Returns None if for example the
     /// alpha on the box shadow would imply that no shadow is to be rendered.
     pub fn new(
-        box_shadow: std::pin::Pin<&crate::items::BoxShadow>,
+        box_shadow: core::pin::Pin<&crate::items::BoxShadow>,
         scale_factor: ScaleFactor,
     ) -> Option<Self> {
         let color = box_shadow.color();
@@ -80,7 +82,13 @@ pub fn new(
 }
 
 /// Cache to hold box textures for given box shadow options.
-pub struct BoxShadowCache<ImageType>(RefCell<BTreeMap<BoxShadowOptions, ImageType>>);
+pub struct BoxShadowCache<ImageType>(RefCell<BTreeMap<BoxShadowOptions, CacheEntry<ImageType>>>);
+
+struct CacheEntry<ImageType> {
+    image: ImageType,
+    /// Set when the texture was looked up since the last call to [`BoxShadowCache::remove_unused`]
+    used: Cell<bool>,
+}
 
 impl<ImageType> Default for BoxShadowCache<ImageType> {
     fn default() -> Self {
@@ -90,22 +98,49 @@ fn default() -> Self {
 
 impl<ImageType: Clone> BoxShadowCache<ImageType> {
     /// Look up a box shadow texture for a given box shadow item, or create a new one if needed.
+    #[cfg(feature = "std")]
     pub fn get_box_shadow(
         &self,
         item_rc: &ItemRc,
         item_cache: &crate::item_rendering::ItemCache<Option<ImageType>>,
-        box_shadow: std::pin::Pin<&crate::items::BoxShadow>,
+        box_shadow: core::pin::Pin<&crate::items::BoxShadow>,
         scale_factor: ScaleFactor,
         shadow_render_fn: impl FnOnce(&BoxShadowOptions) -> ImageType,
     ) -> Option<ImageType> {
         item_cache.get_or_update_cache_entry(item_rc, || {
             let shadow_options = BoxShadowOptions::new(box_shadow, scale_factor)?;
-            self.0
-                .borrow_mut()
-                .entry(shadow_options.clone())
-                .or_insert_with(|| shadow_render_fn(&shadow_options))
-                .clone()
-                .into()
+            self.get_or_insert_with(shadow_options, shadow_render_fn).into()
         })
     }
+
+    /// Look up a box shadow texture for the given options, or create a new one with
+    /// `shadow_render_fn` if needed.
+    pub fn get_or_insert_with(
+        &self,
+        shadow_options: BoxShadowOptions,
+        shadow_render_fn: impl FnOnce(&BoxShadowOptions) -> ImageType,
+    ) -> ImageType {
+        let mut cache = self.0.borrow_mut();
+        let entry = cache.entry(shadow_options).or_insert_with_key(|shadow_options| CacheEntry {
+            image: shadow_render_fn(shadow_options),
+            used: Cell::new(false),
+        });
+        entry.used.set(true);
+        entry.image.clone()
+    }
+
+    /// Removes the textures that were not looked up since the previous call to this function.
+    pub fn remove_unused(&self) {
+        self.0.borrow_mut().retain(|_, entry| entry.used.replace(false));
+    }
+
+    /// Returns the number of textures in the cache
+    pub fn len(&self) -> usize {
+        self.0.borrow().len()
+    }
+
+    /// Returns true if the cache contains no texture
+    pub fn is_empty(&self) -> bool {
+        self.0.borrow().is_empty()
+    }
 }
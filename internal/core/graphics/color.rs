@@ -174,7 +174,7 @@ pub fn alpha(self) -> u8 {
     pub fn brighter(&self, factor: f32) -> Self {
         let rgba: RgbaColor<f32> = (*self).into();
         let mut hsva: HsvaColor = rgba.into();
-        hsva.v *= 1. + factor;
+        hsva.value *= 1. + factor;
         let rgba: RgbaColor<f32> = hsva.into();
         rgba.into()
     }
@@ -188,7 +188,7 @@ pub fn brighter(&self, factor: f32) -> Self {
     pub fn darker(&self, factor: f32) -> Self {
         let rgba: RgbaColor<f32> = (*self).into();
         let mut hsva: HsvaColor = rgba.into();
-        hsva.v /= 1. + factor;
+        hsva.value /= 1. + factor;
         let rgba: RgbaColor<f32> = hsva.into();
         rgba.into()
     }
@@ -288,6 +288,42 @@ fn lerp(v1: u8, v2: u8, f: f32) -> u8 {
         Self { red, green, blue, alpha }
     }
 
+    /// Construct a color from the hue, saturation, value and alpha components of the HSV color space.
+    /// The hue is in degrees, the other components are between 0 and 1.
+    pub fn from_hsva(hue: f32, saturation: f32, value: f32, alpha: f32) -> Self {
+        RgbaColor::<f32>::from(HsvaColor {
+            hue: num_traits::Euclid::rem_euclid(&hue, &360.),
+            saturation: saturation.clamp(0., 1.),
+            value: value.clamp(0., 1.),
+            alpha: alpha.clamp(0., 1.),
+        })
+        .into()
+    }
+
+    /// Construct a color from the hue, saturation, lightness and alpha components of the HSL color space.
+    /// The hue is in degrees, the other components are between 0 and 1.
+    pub fn from_hsla(hue: f32, saturation: f32, lightness: f32, alpha: f32) -> Self {
+        let hsva = HsvaColor::from(HslaColor {
+            hue,
+            saturation: saturation.clamp(0., 1.),
+            lightness: lightness.clamp(0., 1.),
+            alpha,
+        });
+        Self::from_hsva(hsva.hue, hsva.saturation, hsva.value, hsva.alpha)
+    }
+
+    /// Converts this color to the HSV color space.
+    pub fn to_hsva(&self) -> HsvaColor {
+        let mut hsva = HsvaColor::from(RgbaColor::<f32>::from(*self));
+        hsva.hue = num_traits::Euclid::rem_euclid(&hsva.hue, &360.);
+        hsva
+    }
+
+    /// Converts this color to the HSL color space.
+    pub fn to_hsla(&self) -> HslaColor {
+        self.to_hsva().into()
+    }
+
     /// Returns a new version of this color with the opacity set to `alpha`.
     #[must_use]
     pub fn with_alpha(&self, alpha: f32) -> Self {
@@ -314,12 +350,36 @@ fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq)]
-struct HsvaColor {
-    h: f32,
-    s: f32,
-    v: f32,
-    alpha: f32,
+/// HsvaColor stores the hue, saturation, value and alpha components of a color
+/// in the HSV color space. The hue is in degrees between 0 and 360, the other
+/// components are normalized between 0 and 1.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[repr(C)]
+pub struct HsvaColor {
+    /// The hue component in degrees.
+    pub hue: f32,
+    /// The saturation component.
+    pub saturation: f32,
+    /// The value (brightness) component.
+    pub value: f32,
+    /// The alpha component.
+    pub alpha: f32,
+}
+
+/// HslaColor stores the hue, saturation, lightness and alpha components of a color
+/// in the HSL color space. The hue is in degrees between 0 and 360, the other
+/// components are normalized between 0 and 1.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[repr(C)]
+pub struct HslaColor {
+    /// The hue component in degrees.
+    pub hue: f32,
+    /// The saturation component.
+    pub saturation: f32,
+    /// The lightness component.
+    pub lightness: f32,
+    /// The alpha component.
+    pub alpha: f32,
 }
 
 impl From<RgbaColor<f32>> for HsvaColor {
@@ -348,7 +408,7 @@ fn from(col: RgbaColor<f32>) -> Self {
 
         let saturation = if max == 0. { 0. } else { chroma / max };
 
-        Self { h: hue, s: saturation, v: max, alpha: col.alpha }
+        Self { hue, saturation, value: max, alpha: col.alpha }
     }
 }
 
@@ -356,11 +416,11 @@ impl From<HsvaColor> for RgbaColor<f32> {
     fn from(col: HsvaColor) -> Self {
         // RGB to HSL conversion from https://en.wikipedia.org/wiki/HSL_and_HSV#Color_conversion_formulae
 
-        let chroma = col.s * col.v;
+        let chroma = col.saturation * col.value;
 
-        let x = chroma * (1. - ((col.h / 60.) % 2. - 1.).abs());
+        let x = chroma * (1. - ((col.hue / 60.) % 2. - 1.).abs());
 
-        let (red, green, blue) = match (col.h / 60.0) as usize {
+        let (red, green, blue) = match (col.hue / 60.0) as usize {
             0 => (chroma, x, 0.),
             1 => (x, chroma, 0.),
             2 => (0., chroma, x),
@@ -370,35 +430,79 @@ fn from(col: HsvaColor) -> Self {
             _ => (0., 0., 0.),
         };
 
-        let m = col.v - chroma;
+        let m = col.value - chroma;
 
         Self { red: red + m, green: green + m, blue: blue + m, alpha: col.alpha }
     }
 }
 
+impl From<HsvaColor> for HslaColor {
+    fn from(col: HsvaColor) -> Self {
+        // HSV to HSL conversion from https://en.wikipedia.org/wiki/HSL_and_HSV#HSV_to_HSL
+        let lightness = col.value * (1. - col.saturation / 2.);
+        let saturation = if lightness == 0. || lightness == 1. {
+            0.
+        } else {
+            (col.value - lightness) / lightness.min(1. - lightness)
+        };
+        Self { hue: col.hue, saturation, lightness, alpha: col.alpha }
+    }
+}
+
+impl From<HslaColor> for HsvaColor {
+    fn from(col: HslaColor) -> Self {
+        // HSL to HSV conversion from https://en.wikipedia.org/wiki/HSL_and_HSV#HSL_to_HSV
+        let value = col.lightness + col.saturation * col.lightness.min(1. - col.lightness);
+        let saturation = if value == 0. { 0. } else { 2. * (1. - col.lightness / value) };
+        Self { hue: col.hue, saturation, value, alpha: col.alpha }
+    }
+}
+
 #[test]
 fn test_rgb_to_hsv() {
     // White
     assert_eq!(
         HsvaColor::from(RgbaColor::<f32> { red: 1., green: 1., blue: 1., alpha: 0.5 }),
-        HsvaColor { h: 0., s: 0., v: 1., alpha: 0.5 }
+        HsvaColor { hue: 0., saturation: 0., value: 1., alpha: 0.5 }
     );
     assert_eq!(
-        RgbaColor::<f32>::from(HsvaColor { h: 0., s: 0., v: 1., alpha: 0.3 }),
+        RgbaColor::<f32>::from(HsvaColor { hue: 0., saturation: 0., value: 1., alpha: 0.3 }),
         RgbaColor::<f32> { red: 1., green: 1., blue: 1., alpha: 0.3 }
     );
 
     // Bright greenish, verified via colorizer.org
     assert_eq!(
         HsvaColor::from(RgbaColor::<f32> { red: 0., green: 0.9, blue: 0., alpha: 1.0 }),
-        HsvaColor { h: 120., s: 1., v: 0.9, alpha: 1.0 }
+        HsvaColor { hue: 120., saturation: 1., value: 0.9, alpha: 1.0 }
     );
     assert_eq!(
-        RgbaColor::<f32>::from(HsvaColor { h: 120., s: 1., v: 0.9, alpha: 1.0 }),
+        RgbaColor::<f32>::from(HsvaColor { hue: 120., saturation: 1., value: 0.9, alpha: 1.0 }),
         RgbaColor::<f32> { red: 0., green: 0.9, blue: 0., alpha: 1.0 }
     );
 }
 
+#[test]
+fn test_hsv_hsl() {
+    let orange = Color::from_rgb_u8(255, 128, 0);
+    let hsva = orange.to_hsva();
+    assert!((hsva.hue - 30.).abs() < 0.5);
+    assert_eq!(hsva.saturation, 1.);
+    assert_eq!(hsva.value, 1.);
+    assert_eq!(Color::from_hsva(hsva.hue, hsva.saturation, hsva.value, hsva.alpha), orange);
+
+    let hsla = orange.to_hsla();
+    assert!((hsla.hue - 30.).abs() < 0.5);
+    assert_eq!(hsla.saturation, 1.);
+    assert_eq!(hsla.lightness, 0.5);
+    assert_eq!(Color::from_hsla(hsla.hue, hsla.saturation, hsla.lightness, hsla.alpha), orange);
+
+    // Hues are normalized between 0 and 360
+    let magenta = Color::from_rgb_u8(255, 0, 128);
+    assert!((magenta.to_hsva().hue - 330.).abs() < 0.5);
+    assert_eq!(Color::from_hsva(-30., 1., 1., 1.), Color::from_hsva(330., 1., 1., 1.));
+    assert_eq!(Color::from_hsla(0., 0., 1., 0.5), Color::from_argb_u8(128, 255, 255, 255));
+}
+
 #[test]
 fn test_brighter_darker() {
     let blue = Color::from_rgb_u8(0, 0, 128);
@@ -436,6 +540,38 @@ pub(crate) mod ffi {
         core::ptr::write(out, col1.mix(col2, factor))
     }
 
+    #[no_mangle]
+    pub unsafe extern "C" fn slint_color_from_hsva(
+        hue: f32,
+        saturation: f32,
+        value: f32,
+        alpha: f32,
+        out: *mut Color,
+    ) {
+        core::ptr::write(out, Color::from_hsva(hue, saturation, value, alpha))
+    }
+
+    #[no_mangle]
+    pub unsafe extern "C" fn slint_color_from_hsla(
+        hue: f32,
+        saturation: f32,
+        lightness: f32,
+        alpha: f32,
+        out: *mut Color,
+    ) {
+        core::ptr::write(out, Color::from_hsla(hue, saturation, lightness, alpha))
+    }
+
+    #[no_mangle]
+    pub extern "C" fn slint_color_to_hsva(col: &Color, out: &mut HsvaColor) {
+        *out = col.to_hsva()
+    }
+
+    #[no_mangle]
+    pub extern "C" fn slint_color_to_hsla(col: &Color, out: &mut HslaColor) {
+        *out = col.to_hsla()
+    }
+
     #[no_mangle]
     pub unsafe extern "C" fn slint_color_with_alpha(col: &Color, alpha: f32, out: *mut Color) {
         core::ptr::write(out, col.with_alpha(alpha))
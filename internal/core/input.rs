@@ -158,6 +158,14 @@ fn from(k: Key) -> Self {
                     char::from(k).into()
                 }
             }
+
+            /// Returns the key code for the name of a special key, such as `"Escape"` or `"F1"`
+            pub(crate) fn key_code_from_name(name: &str) -> Option<char> {
+                match name {
+                    $(stringify!($name) => Some($name),)*
+                    _ => None,
+                }
+            }
         };
     }
 
@@ -346,8 +354,9 @@ pub fn shortcut(&self) -> Option<StandardShortcut> {
             }
         } else if self.modifiers.control && self.modifiers.shift {
             match self.text.as_str() {
+                // With shift pressed, the text is usually upper case
                 #[cfg(not(target_os = "windows"))]
-                "z" => Some(StandardShortcut::Redo),
+                "z" | "Z" => Some(StandardShortcut::Redo),
                 _ => None,
             }
         } else {
@@ -439,7 +448,67 @@ pub fn text_shortcut(&self) -> Option<TextShortcut> {
     }
 }
 
+/// Returns true if the key event activates the shortcut described by `keys`.
+///
+/// `keys` is either the name of a [`StandardShortcut`] such as `"Copy"` or `"Undo"`, which matches
+/// the key combination of the current platform, or a list of modifiers followed by a key, separated
+/// by `+`, such as `"Ctrl+Shift+S"` or `"Alt+F4"`. The key is a character or the name of a
+/// special key (see [`key_codes::Key`]). Letters match regardless of their case.
+pub fn key_event_matches_shortcut(event: &KeyEvent, keys: &str) -> bool {
+    if let Ok(standard) = keys.trim().parse::<StandardShortcut>() {
+        return event.shortcut() == Some(standard);
+    }
+
+    let mut modifiers = KeyboardModifiers::default();
+    let mut parts = keys.split('+').map(str::trim).peekable();
+    let mut key = None;
+    while let Some(part) = parts.next() {
+        if parts.peek().is_none() {
+            key = Some(part);
+            break;
+        }
+        match part.to_ascii_lowercase().as_str() {
+            "ctrl" | "control" => modifiers.control = true,
+            "alt" => modifiers.alt = true,
+            "shift" => modifiers.shift = true,
+            "meta" | "cmd" | "command" | "super" => modifiers.meta = true,
+            // An empty part means that the key itself is `+`, as in `"Ctrl++"`
+            "" if parts.peek() == Some(&"") => {
+                parts.next();
+                key = Some("+");
+                break;
+            }
+            _ => return false,
+        }
+    }
+    let Some(key) = key else { return false };
+
+    let mut key_chars = key.chars();
+    let (Some(key_char), None) = (key_chars.next(), key_chars.next()) else {
+        // The name of a special key
+        return match key_codes::key_code_from_name(key) {
+            Some(code) => {
+                event.text.chars().eq(core::iter::once(code)) && event.modifiers == modifiers
+            }
+            None => false,
+        };
+    };
+
+    let mut event_chars = event.text.chars();
+    let (Some(event_char), None) = (event_chars.next(), event_chars.next()) else { return false };
+    if key_char.is_alphabetic() {
+        event_char.to_lowercase().eq(key_char.to_lowercase()) && event.modifiers == modifiers
+    } else {
+        // Shift is needed to type some characters, so it is only compared for letters
+        event_char == key_char
+            && event.modifiers.control == modifiers.control
+            && event.modifiers.alt == modifiers.alt
+            && event.modifiers.meta == modifiers.meta
+    }
+}
+
 /// Represents a non context specific shortcut.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, strum::EnumString)]
 pub enum StandardShortcut {
     /// Copy Something
     Copy,
@@ -587,6 +656,13 @@ pub struct MouseInputState {
     delayed_exit_items: Vec<ItemWeak>,
 }
 
+impl MouseInputState {
+    /// Returns true if an item has grabbed the mouse
+    pub(crate) fn is_grabbed(&self) -> bool {
+        self.grabbed
+    }
+}
+
 /// Try to handle the mouse grabber. Return None if the event has been handled, otherwise
 /// return the event that must be handled
 fn handle_mouse_grab(
@@ -655,6 +731,15 @@ fn handle_mouse_grab(
     None
 }
 
+/// Sends an exit event to all the items that are under the mouse according to the `mouse_input_state`,
+/// for example because the following mouse events are sent to another component.
+pub(crate) fn send_exit_events_to_all(
+    mouse_input_state: MouseInputState,
+    window_adapter: &Rc<dyn WindowAdapter>,
+) {
+    send_exit_events(&mouse_input_state, &mut MouseInputState::default(), None, window_adapter);
+}
+
 fn send_exit_events(
     old_input_state: &MouseInputState,
     new_input_state: &mut MouseInputState,
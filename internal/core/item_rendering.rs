@@ -402,8 +402,92 @@ fn metrics(&self) -> crate::graphics::rendering_metrics_collector::RenderingMetr
     }
 }
 
+/// The data of an item kept in the [`PartialRenderingCache`]
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct CachedItemGeometry {
+    /// The geometry of the item, relative to its parent
+    pub geometry: LogicalRect,
+    /// The area in which the item draws, relative to its parent. It is larger than the
+    /// geometry for items that draw outside of it, such as the BoxShadow.
+    pub bounding_rect: LogicalRect,
+    /// The rotation that a Rotate item applies to its children
+    pub children_rotation: Option<ChildrenRotation>,
+}
+
+/// The rotation that a Rotate item applies to its children
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ChildrenRotation {
+    /// The angle of the rotation, clockwise
+    pub angle_in_degrees: f32,
+    /// The origin of the rotation, relative to the item
+    pub origin: LogicalPoint,
+}
+
+impl CachedItemGeometry {
+    fn new(item: Pin<ItemRef>) -> Self {
+        let geometry = item.as_ref().geometry();
+        let mut bounding_rect = geometry;
+        let mut children_rotation = None;
+        if let Some(clip) = ItemRef::downcast_pin::<Clip>(item) {
+            // Make sure we register a dependency on the clip
+            clip.clip();
+        } else if let Some(shadow) = ItemRef::downcast_pin::<BoxShadow>(item) {
+            let offset = LogicalVector::from_lengths(shadow.offset_x(), shadow.offset_y());
+            // One more pixel to account for the rounding of the shadow's size
+            let inflate = shadow.blur().get().max(0 as _) + 1 as Coord;
+            bounding_rect =
+                bounding_rect.union(&geometry.translate(offset).inflate(inflate, inflate));
+        } else if let Some(rotate) = ItemRef::downcast_pin::<Rotate>(item) {
+            children_rotation = Some(ChildrenRotation {
+                angle_in_degrees: rotate.rotation_angle(),
+                origin: LogicalPoint::from_lengths(
+                    rotate.rotation_origin_x(),
+                    rotate.rotation_origin_y(),
+                ),
+            });
+        }
+        Self { geometry, bounding_rect, children_rotation }
+    }
+}
+
+/// The coordinate system of an item when computing the dirty regions: the offset of the item
+/// within the closest rotated ancestor, and the transform from that ancestor to the window.
+#[derive(Clone, Copy, PartialEq)]
+struct DirtyRegionFrame {
+    offset: euclid::Vector2D<Coord, LogicalPx>,
+    transform: Option<euclid::Transform2D<f32, LogicalPx, LogicalPx>>,
+}
+
+impl DirtyRegionFrame {
+    /// Returns the frame of the children of an item with that geometry
+    fn children_frame(mut self, geometry: &CachedItemGeometry) -> Self {
+        self.offset += geometry.geometry.origin.to_vector();
+        if let Some(rotation) = geometry.children_rotation {
+            let origin = rotation.origin.cast::<f32>().to_vector();
+            let rotation_transform = euclid::Transform2D::translation(-origin.x, -origin.y)
+                .then_rotate(euclid::Angle::degrees(rotation.angle_in_degrees))
+                .then_translate(self.offset.cast() + origin);
+            self.transform = Some(match self.transform {
+                Some(transform) => rotation_transform.then(&transform),
+                None => rotation_transform,
+            });
+            self.offset = Default::default();
+        }
+        self
+    }
+
+    /// Maps a rect in this frame to the window
+    fn map_to_window(&self, rect: LogicalRect) -> LogicalRect {
+        let rect = rect.translate(self.offset);
+        match &self.transform {
+            Some(transform) => transform.outer_transformed_rect(&rect.cast()).round_out().cast(),
+            None => rect,
+        }
+    }
+}
+
 /// The cache that needs to be held by the Window for the partial rendering
-pub type PartialRenderingCache = RenderingCache<LogicalRect>;
+pub type PartialRenderingCache = RenderingCache<CachedItemGeometry>;
 
 /// FIXME: Should actually be a region and not just a rectangle
 pub type DirtyRegion = euclid::Box2D<Coord, LogicalPx>;
@@ -431,8 +515,8 @@ pub fn new(
     pub fn compute_dirty_regions(&mut self, component: &ComponentRc, origin: LogicalPoint) {
         #[derive(Clone, Copy)]
         struct ComputeDirtyRegionState {
-            offset: euclid::Vector2D<Coord, LogicalPx>,
-            old_offset: euclid::Vector2D<Coord, LogicalPx>,
+            frame: DirtyRegionFrame,
+            old_frame: DirtyRegionFrame,
             clipped: LogicalRect,
             must_refresh_children: bool,
         }
@@ -453,18 +537,23 @@ struct ComputeDirtyRegionState {
                             let old_geom = *cached_geom;
                             drop(borrowed);
                             let geom = crate::properties::evaluate_no_tracking(|| {
-                                item.as_ref().geometry()
+                                CachedItemGeometry::new(item)
                             });
 
-                            self.mark_dirty_rect(old_geom, state.old_offset, &state.clipped);
-                            self.mark_dirty_rect(geom, state.offset, &state.clipped);
+                            self.mark_dirty_rect(
+                                old_geom.bounding_rect,
+                                &state.old_frame,
+                                &state.clipped,
+                            );
+                            self.mark_dirty_rect(geom.bounding_rect, &state.frame, &state.clipped);
 
-                            new_state.offset += geom.origin.to_vector();
-                            new_state.old_offset += old_geom.origin.to_vector();
+                            new_state.frame = state.frame.children_frame(&geom);
+                            new_state.old_frame = state.old_frame.children_frame(&old_geom);
                             if ItemRef::downcast_pin::<Clip>(item).is_some()
                                 || ItemRef::downcast_pin::<Opacity>(item).is_some()
+                                || ItemRef::downcast_pin::<Rotate>(item).is_some()
                             {
-                                // When the opacity or the clip change, this will impact all the children, including
+                                // When the opacity, the clip or the rotation change, this will impact all the children, including
                                 // the ones outside the element, regardless if they are themselves dirty or not.
                                 new_state.must_refresh_children = true;
                             }
@@ -473,26 +562,30 @@ struct ComputeDirtyRegionState {
                         } else {
                             tr.as_ref().register_as_dependency_to_current_binding();
 
-                            if state.must_refresh_children
-                                || new_state.offset != new_state.old_offset
-                            {
+                            if state.must_refresh_children || state.frame != state.old_frame {
                                 self.mark_dirty_rect(
-                                    *cached_geom,
-                                    state.old_offset,
+                                    cached_geom.bounding_rect,
+                                    &state.old_frame,
+                                    &state.clipped,
+                                );
+                                self.mark_dirty_rect(
+                                    cached_geom.bounding_rect,
+                                    &state.frame,
                                     &state.clipped,
                                 );
-                                self.mark_dirty_rect(*cached_geom, state.offset, &state.clipped);
                             }
 
-                            new_state.offset += cached_geom.origin.to_vector();
-                            new_state.old_offset += cached_geom.origin.to_vector();
+                            new_state.frame = state.frame.children_frame(cached_geom);
+                            new_state.old_frame = state.old_frame.children_frame(cached_geom);
                             if crate::properties::evaluate_no_tracking(|| is_clipping_item(item)) {
+                                let geometry = cached_geom.geometry;
                                 new_state.clipped = new_state
                                     .clipped
                                     .intersection(
-                                        &cached_geom
-                                            .translate(state.offset)
-                                            .union(&cached_geom.translate(state.old_offset)),
+                                        &state
+                                            .frame
+                                            .map_to_window(geometry)
+                                            .union(&state.old_frame.map_to_window(geometry)),
                                     )
                                     .unwrap_or_default();
                             }
@@ -502,25 +595,25 @@ struct ComputeDirtyRegionState {
                     _ => {
                         drop(borrowed);
                         let geom = crate::properties::evaluate_no_tracking(|| {
-                            let geom = item.as_ref().geometry();
-                            new_state.offset += geom.origin.to_vector();
-                            new_state.old_offset += geom.origin.to_vector();
+                            let geom = CachedItemGeometry::new(item);
+                            new_state.frame = state.frame.children_frame(&geom);
+                            new_state.old_frame = state.old_frame.children_frame(&geom);
                             if is_clipping_item(item) {
                                 new_state.clipped = new_state
                                     .clipped
-                                    .intersection(&geom.translate(state.offset))
+                                    .intersection(&state.frame.map_to_window(geom.geometry))
                                     .unwrap_or_default();
                             }
                             geom
                         });
-                        self.mark_dirty_rect(geom, state.offset, &state.clipped);
+                        self.mark_dirty_rect(geom.bounding_rect, &state.frame, &state.clipped);
                         ItemVisitorResult::Continue(new_state)
                     }
                 }
             },
             ComputeDirtyRegionState {
-                offset: origin.to_vector(),
-                old_offset: origin.to_vector(),
+                frame: DirtyRegionFrame { offset: origin.to_vector(), transform: None },
+                old_frame: DirtyRegionFrame { offset: origin.to_vector(), transform: None },
                 clipped: euclid::rect(0 as Coord, 0 as Coord, Coord::MAX, Coord::MAX),
                 must_refresh_children: false,
             },
@@ -530,11 +623,11 @@ struct ComputeDirtyRegionState {
     fn mark_dirty_rect(
         &mut self,
         rect: LogicalRect,
-        offset: euclid::Vector2D<Coord, LogicalPx>,
+        frame: &DirtyRegionFrame,
         clip_rect: &LogicalRect,
     ) {
         if !rect.is_empty() {
-            if let Some(rect) = rect.translate(offset).intersection(clip_rect) {
+            if let Some(rect) = frame.map_to_window(rect).intersection(clip_rect) {
                 self.dirty_region = self.dirty_region.union(&rect.to_box2d());
             }
         }
@@ -543,14 +636,21 @@ fn mark_dirty_rect(
     fn do_rendering(
         cache: &RefCell<PartialRenderingCache>,
         rendering_data: &CachedRenderingData,
-        render_fn: impl FnOnce() -> LogicalRect,
+        render_fn: impl FnOnce() -> CachedItemGeometry,
     ) {
-        if let Some(entry) = rendering_data.get_entry(&mut cache.borrow_mut()) {
+        // Take the tracker out of the cache while rendering, because rendering may access the
+        // cache for other items (e.g. the children of a layer)
+        let tracker = rendering_data.get_entry(&mut cache.borrow_mut()).map(|entry| {
             entry
                 .dependency_tracker
-                .get_or_insert_with(|| Box::pin(crate::properties::PropertyTracker::default()))
-                .as_ref()
-                .evaluate(render_fn);
+                .take()
+                .unwrap_or_else(|| Box::pin(crate::properties::PropertyTracker::default()))
+        });
+        if let Some(tracker) = tracker {
+            tracker.as_ref().evaluate(render_fn);
+            if let Some(entry) = rendering_data.get_entry(&mut cache.borrow_mut()) {
+                entry.dependency_tracker = Some(tracker);
+            }
         } else {
             let cache_entry = crate::graphics::CachedGraphicsData::new(render_fn);
             let mut cache = cache.borrow_mut();
@@ -571,7 +671,7 @@ fn $fn(&mut self, obj: Pin<&$Ty>, item_rc: &ItemRc, size: LogicalSize) $(-> $Ret
             let mut ret = None;
             Self::do_rendering(&self.cache, &obj.cached_rendering_data, || {
                 ret = Some(self.actual_renderer.$fn(obj, item_rc, size));
-                obj.geometry()
+                CachedItemGeometry::new(item_rc.borrow())
             });
             ret.unwrap_or_default()
         }
@@ -580,13 +680,7 @@ fn $fn(&mut self, obj: Pin<&$Ty>, item_rc: &ItemRc, size: LogicalSize) $(-> $Ret
 
 impl<'a, T: ItemRenderer> ItemRenderer for PartialRenderer<'a, T> {
     fn filter_item(&mut self, item: Pin<ItemRef>) -> (bool, LogicalRect) {
-        let eval = || {
-            if let Some(clip) = ItemRef::downcast_pin::<Clip>(item) {
-                // Make sure we register a dependency on the clip
-                clip.clip();
-            }
-            item.as_ref().geometry()
-        };
+        let eval = || CachedItemGeometry::new(item);
 
         let rendering_data = item.cached_rendering_data_offset();
         let mut cache = self.cache.borrow_mut();
@@ -596,11 +690,11 @@ fn filter_item(&mut self, item: Pin<ItemRef>) -> (bool, LogicalRect) {
                     .get_or_insert_with(|| Box::pin(crate::properties::PropertyTracker::default()))
                     .as_ref()
                     .evaluate_if_dirty(|| *data = eval());
-                *data
+                data.geometry
             }
             None => {
                 let cache_entry = crate::graphics::CachedGraphicsData::new(eval);
-                let geom = cache_entry.data;
+                let geom = cache_entry.data.geometry;
                 rendering_data.cache_index.set(cache.insert(cache_entry));
                 rendering_data.cache_generation.set(cache.generation());
                 geom
@@ -626,6 +720,7 @@ fn filter_item(&mut self, item: Pin<ItemRef>) -> (bool, LogicalRect) {
 
     forward_rendering_call!(fn visit_clip(Clip) -> RenderingResult);
     forward_rendering_call!(fn visit_opacity(Opacity) -> RenderingResult);
+    forward_rendering_call!(fn visit_layer(Layer) -> RenderingResult);
 
     fn combine_clip(
         &mut self,
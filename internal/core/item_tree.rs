@@ -180,6 +180,14 @@ pub fn accessible_string_property(
         result
     }
 
+    /// Returns the type names and ids of the elements this item comes from, one `TypeName::id`
+    /// per line, or None if the component was compiled without debug info.
+    pub fn element_infos(&self) -> Option<SharedString> {
+        let comp_ref_pin = vtable::VRc::borrow_pin(&self.component);
+        let mut result = Default::default();
+        comp_ref_pin.as_ref().item_element_infos(self.index, &mut result).then_some(result)
+    }
+
     pub fn geometry(&self) -> LogicalRect {
         self.borrow().as_ref().geometry()
     }
@@ -920,6 +928,14 @@ fn accessible_string_property(
             _: &mut SharedString,
         ) {
         }
+
+        fn item_element_infos(self: Pin<&Self>, _: usize, _: &mut SharedString) -> bool {
+            false
+        }
+
+        fn get_shortcut_index(self: Pin<&Self>) -> Slice<u32> {
+            Slice::default()
+        }
     }
 
     crate::component::ComponentVTable_static!(static TEST_COMPONENT_VT for TestComponent);
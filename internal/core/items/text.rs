@@ -25,10 +25,12 @@
 use crate::rtti::*;
 use crate::window::{InputMethodRequest, WindowAdapter, WindowInner};
 use crate::{Callback, Coord, Property, SharedString};
+use alloc::boxed::Box;
 use alloc::rc::Rc;
 use alloc::string::String;
+use alloc::vec::Vec;
 use const_field_offset::FieldOffsets;
-use core::cell::Cell;
+use core::cell::{Cell, RefCell};
 use core::pin::Pin;
 #[allow(unused)]
 use euclid::num::Ceil;
@@ -226,6 +228,8 @@ pub struct TextInput {
     pub vertical_alignment: Property<TextVerticalAlignment>,
     pub wrap: Property<TextWrap>,
     pub input_type: Property<InputType>,
+    pub input_mask: Property<SharedString>,
+    pub max_length: Property<i32>,
     pub letter_spacing: Property<LogicalLength>,
     pub x: Property<LogicalLength>,
     pub y: Property<LogicalLength>,
@@ -251,6 +255,7 @@ pub struct TextInput {
     preferred_x_pos: Cell<Coord>,
     /// 0 = not pressed, 1 = single press, 2 = double clicked+press , ...
     pressed: Cell<u8>,
+    undo_history: TextInputUndoHistoryBox,
 }
 
 impl Item for TextInput {
@@ -497,7 +502,18 @@ fn key_event(
                             self.cut(window_adapter, self_rc);
                             return KeyEventResult::EventAccepted;
                         }
-                        StandardShortcut::Paste | StandardShortcut::Cut => {
+                        StandardShortcut::Undo if !self.read_only() => {
+                            self.undo(window_adapter, self_rc);
+                            return KeyEventResult::EventAccepted;
+                        }
+                        StandardShortcut::Redo if !self.read_only() => {
+                            self.redo(window_adapter, self_rc);
+                            return KeyEventResult::EventAccepted;
+                        }
+                        StandardShortcut::Paste
+                        | StandardShortcut::Cut
+                        | StandardShortcut::Undo
+                        | StandardShortcut::Redo => {
                             return KeyEventResult::EventIgnored;
                         }
                         _ => (),
@@ -507,25 +523,20 @@ fn key_event(
                 if self.read_only() || event.modifiers.control {
                     return KeyEventResult::EventIgnored;
                 }
-                self.delete_selection(window_adapter, self_rc);
-
-                let mut text: String = self.text().into();
 
                 // FIXME: respect grapheme boundaries
-                let insert_pos = self.selection_anchor_and_cursor().1;
-                text.insert_str(insert_pos, &event.text);
-
-                self.as_ref().text.set(text.into());
-                let new_cursor_pos = (insert_pos + event.text.len()) as i32;
-                self.as_ref().anchor_position_byte_offset.set(new_cursor_pos);
-                self.set_cursor_position(new_cursor_pos, true, window_adapter, self_rc);
+                self.replace_selection(
+                    &event.text,
+                    UndoItemKind::Typing,
+                    None,
+                    window_adapter,
+                    self_rc,
+                );
 
                 // Keep the cursor visible when inserting text. Blinking should only occur when
                 // nothing is entered or the cursor isn't moved.
                 self.as_ref().show_cursor(window_adapter);
 
-                Self::FIELD_OFFSETS.edited.apply_pin(self).call(&());
-
                 KeyEventResult::EventAccepted
             }
             KeyEventType::UpdateComposition => {
@@ -909,10 +920,16 @@ fn select_and_delete(
         window_adapter: &Rc<dyn WindowAdapter>,
         self_rc: &ItemRc,
     ) {
-        if !self.has_selection() {
-            self.move_cursor(step, AnchorMode::KeepAnchor, window_adapter, self_rc);
+        if self.has_selection() {
+            self.delete_selection(window_adapter, self_rc);
+            return;
+        }
+        let cursor = self.cursor_position(&self.text());
+        self.move_cursor(step, AnchorMode::KeepAnchor, window_adapter, self_rc);
+        if self.has_selection() {
+            // Undo should restore the cursor, not the selection made for the deletion
+            self.replace_selection("", UndoItemKind::Other, Some(cursor), window_adapter, self_rc);
         }
-        self.delete_selection(window_adapter, self_rc);
     }
 
     pub fn delete_selection(
@@ -920,8 +937,7 @@ pub fn delete_selection(
         window_adapter: &Rc<dyn WindowAdapter>,
         self_rc: &ItemRc,
     ) {
-        let text: String = self.text().into();
-        if text.is_empty() {
+        if self.text().is_empty() {
             return;
         }
 
@@ -930,11 +946,7 @@ pub fn delete_selection(
             return;
         }
 
-        let text = [text.split_at(anchor).0, text.split_at(cursor).1].concat();
-        self.text.set(text.into());
-        self.anchor_position_byte_offset.set(anchor as i32);
-        self.set_cursor_position(anchor as i32, true, window_adapter, self_rc);
-        Self::FIELD_OFFSETS.edited.apply_pin(self).call(&());
+        self.replace_selection("", UndoItemKind::Other, None, window_adapter, self_rc);
     }
 
     pub fn anchor_position(self: Pin<&Self>, text: &str) -> usize {
@@ -970,18 +982,155 @@ pub fn insert(
         window_adapter: &Rc<dyn WindowAdapter>,
         self_rc: &ItemRc,
     ) {
-        self.delete_selection(window_adapter, self_rc);
-        let mut text: String = self.text().into();
-        let cursor_pos = self.selection_anchor_and_cursor().1;
-        if text_to_insert.contains('\n') && self.single_line() {
-            text.insert_str(cursor_pos, &text_to_insert.replace('\n', " "));
+        let text_to_insert = if text_to_insert.contains('\n') && self.single_line() {
+            alloc::borrow::Cow::Owned(text_to_insert.replace('\n', " "))
         } else {
-            text.insert_str(cursor_pos, text_to_insert);
+            alloc::borrow::Cow::Borrowed(text_to_insert)
+        };
+        self.replace_selection(&text_to_insert, UndoItemKind::Other, None, window_adapter, self_rc);
+    }
+
+    /// Replace the selected text (or insert at the cursor if nothing is selected), and record the
+    /// change in the undo history.
+    /// `undo_cursor` is the cursor position to restore on undo, instead of the current selection.
+    fn replace_selection(
+        self: Pin<&Self>,
+        replacement: &str,
+        kind: UndoItemKind,
+        undo_cursor: Option<usize>,
+        window_adapter: &Rc<dyn WindowAdapter>,
+        self_rc: &ItemRc,
+    ) {
+        let text = self.text();
+        let (anchor, cursor) = self.selection_anchor_and_cursor();
+        let Some((new_text, new_cursor_pos)) =
+            self.apply_input_constraints(&text, anchor, cursor, replacement)
+        else {
+            return;
+        };
+        if new_text == text.as_str() {
+            // For example backspace over a literal character of the input mask only moves the cursor
+            self.anchor_position_byte_offset.set(new_cursor_pos as i32);
+            self.set_cursor_position(new_cursor_pos as i32, true, window_adapter, self_rc);
+            return;
         }
-        let cursor_pos = cursor_pos + text_to_insert.len();
-        self.text.set(text.into());
-        self.anchor_position_byte_offset.set(cursor_pos as i32);
-        self.set_cursor_position(cursor_pos as i32, true, window_adapter, self_rc);
+        let new_text: SharedString = new_text.into();
+
+        // The input mask may also have changed the text after the selection, so record the range
+        // that actually differs.
+        let pos = text
+            .char_indices()
+            .zip(new_text.chars())
+            .find(|((_, a), b)| a != b)
+            .map_or(text.len().min(new_text.len()), |((i, _), _)| i)
+            .min(anchor);
+        let suffix: usize = text[pos..]
+            .chars()
+            .rev()
+            .zip(new_text[pos..].chars().rev())
+            .take_while(|(a, b)| a == b)
+            .map(|(a, _)| a.len_utf8())
+            .sum();
+        self.undo_history.record(
+            &text,
+            UndoItem {
+                pos,
+                removed: text[pos..text.len() - suffix].into(),
+                inserted: new_text[pos..new_text.len() - suffix].into(),
+                cursor_before: undo_cursor.unwrap_or_else(|| self.cursor_position(&text)),
+                anchor_before: undo_cursor.unwrap_or_else(|| self.anchor_position(&text)),
+                cursor_after: new_cursor_pos,
+                kind,
+            },
+            new_text.clone(),
+        );
+        self.text.set(new_text);
+        self.anchor_position_byte_offset.set(new_cursor_pos as i32);
+        self.set_cursor_position(new_cursor_pos as i32, true, window_adapter, self_rc);
+        Self::FIELD_OFFSETS.edited.apply_pin(self).call(&());
+    }
+
+    /// Returns the text and the cursor position resulting from replacing `text[anchor..cursor]`
+    /// with `replacement`, after dropping the characters that the input type, the input mask, or
+    /// the maximum length don't allow. Returns None if nothing of a non-empty replacement is left.
+    fn apply_input_constraints(
+        self: Pin<&Self>,
+        text: &str,
+        anchor: usize,
+        cursor: usize,
+        replacement: &str,
+    ) -> Option<(String, usize)> {
+        let (before, after) = (&text[..anchor], &text[cursor..]);
+        let mut filtered = match self.input_type() {
+            input_type @ (InputType::Number | InputType::Decimal) => {
+                // A single '-' is accepted at the start of the text
+                let mut sign_allowed = before.is_empty() && !after.starts_with('-');
+                let mut has_point =
+                    input_type != InputType::Decimal || before.contains('.') || after.contains('.');
+                replacement
+                    .chars()
+                    .filter(|c| {
+                        let accepted = c.is_ascii_digit()
+                            || (*c == '-' && sign_allowed)
+                            || (*c == '.' && !core::mem::replace(&mut has_point, true));
+                        sign_allowed &= !accepted;
+                        accepted
+                    })
+                    .collect()
+            }
+            _ => String::from(replacement),
+        };
+
+        let max_length = self.max_length();
+        if max_length > 0 {
+            let length = |s: &str| s.graphemes(true).count();
+            let available = (max_length as usize).saturating_sub(length(before) + length(after));
+            if let Some((end, _)) = filtered.grapheme_indices(true).nth(available) {
+                filtered.truncate(end);
+            }
+        }
+
+        if filtered.is_empty() && !replacement.is_empty() {
+            return None;
+        }
+
+        let new_text = [before, &filtered, after].concat();
+        let new_cursor_pos = anchor + filtered.len();
+        let input_mask = self.input_mask();
+        if input_mask.is_empty() {
+            Some((new_text, new_cursor_pos))
+        } else {
+            Some(apply_input_mask(&input_mask, &new_text, new_cursor_pos))
+        }
+    }
+
+    /// Revert the last edit, if any
+    pub fn undo(self: Pin<&Self>, window_adapter: &Rc<dyn WindowAdapter>, self_rc: &ItemRc) {
+        let text = self.text();
+        let Some(item) = self.undo_history.undo(&text) else { return };
+        let new_text: SharedString =
+            [&text[..item.pos], &item.removed, &text[item.pos + item.inserted.len()..]]
+                .concat()
+                .into();
+        self.undo_history.set_current_text(new_text.clone());
+        self.text.set(new_text);
+        self.anchor_position_byte_offset.set(item.anchor_before as i32);
+        self.set_cursor_position(item.cursor_before as i32, true, window_adapter, self_rc);
+        Self::FIELD_OFFSETS.edited.apply_pin(self).call(&());
+    }
+
+    /// Re-apply the last edit that was reverted with [`Self::undo`], if any
+    pub fn redo(self: Pin<&Self>, window_adapter: &Rc<dyn WindowAdapter>, self_rc: &ItemRc) {
+        let text = self.text();
+        let Some(item) = self.undo_history.redo(&text) else { return };
+        let new_text: SharedString =
+            [&text[..item.pos], &item.inserted, &text[item.pos + item.removed.len()..]]
+                .concat()
+                .into();
+        self.undo_history.set_current_text(new_text.clone());
+        self.text.set(new_text);
+        self.anchor_position_byte_offset.set(item.cursor_after as i32);
+        self.set_cursor_position(item.cursor_after as i32, true, window_adapter, self_rc);
         Self::FIELD_OFFSETS.edited.apply_pin(self).call(&());
     }
 
@@ -1181,6 +1330,219 @@ fn byte_offset_for_position(
     }
 }
 
+/// One position of an input mask
+#[derive(Clone, Copy, PartialEq, Debug)]
+enum InputMaskSlot {
+    /// `9` in the mask
+    Digit,
+    /// `a` in the mask
+    Letter,
+    /// `*` in the mask
+    Any,
+    /// Any other character, or one escaped with `\`. It is inserted automatically.
+    Literal(char),
+}
+
+impl InputMaskSlot {
+    fn parse(input_mask: &str) -> Vec<Self> {
+        let mut chars = input_mask.chars();
+        core::iter::from_fn(|| {
+            Some(match chars.next()? {
+                '9' => Self::Digit,
+                'a' => Self::Letter,
+                '*' => Self::Any,
+                '\\' => Self::Literal(chars.next().unwrap_or('\\')),
+                c => Self::Literal(c),
+            })
+        })
+        .collect()
+    }
+
+    fn accepts(self, c: char) -> bool {
+        match self {
+            Self::Digit => c.is_ascii_digit(),
+            Self::Letter => c.is_alphabetic(),
+            Self::Any => true,
+            Self::Literal(l) => c == l,
+        }
+    }
+}
+
+/// Returns `text` made to follow `input_mask`, and the byte offset in the result that corresponds
+/// to `pos` in `text`. The characters that don't fit the mask are dropped, and the literal
+/// characters of the mask are inserted in front of the accepted ones where they are missing.
+fn apply_input_mask(input_mask: &str, text: &str, pos: usize) -> (String, usize) {
+    let slots = InputMaskSlot::parse(input_mask);
+    let mut result = String::new();
+    let mut new_pos = None;
+    let mut index = 0;
+    for (offset, c) in text.char_indices() {
+        if offset == pos {
+            new_pos = Some(result.len());
+        }
+        let (rollback_len, rollback_index) = (result.len(), index);
+        loop {
+            match slots.get(index) {
+                Some(InputMaskSlot::Literal(l)) => {
+                    result.push(*l);
+                    index += 1;
+                    if *l == c {
+                        break;
+                    }
+                }
+                Some(slot) if slot.accepts(c) => {
+                    result.push(c);
+                    index += 1;
+                    break;
+                }
+                // Rejected, or past the end of the mask: drop the character and the literals
+                // that were inserted for it
+                _ => {
+                    result.truncate(rollback_len);
+                    index = rollback_index;
+                    break;
+                }
+            }
+        }
+    }
+    let new_pos = new_pos.unwrap_or(result.len());
+    (result, new_pos)
+}
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+enum UndoItemKind {
+    /// Text entered with the keyboard. Consecutive typing is merged into words
+    Typing,
+    Other,
+}
+
+/// One edit of the text: at the byte offset `pos`, `removed` was replaced by `inserted`
+#[derive(Clone, Debug)]
+struct UndoItem {
+    pos: usize,
+    removed: String,
+    inserted: String,
+    cursor_before: usize,
+    anchor_before: usize,
+    cursor_after: usize,
+    kind: UndoItemKind,
+}
+
+impl UndoItem {
+    /// Merge `next` into this item if both are typing, and `next` continues the same word
+    fn try_merge(&mut self, next: &UndoItem) -> bool {
+        let continues_word = |prev: &str, next: &str| {
+            let prev_is_space = prev.chars().next_back().map_or(false, char::is_whitespace);
+            let next_is_space = next.chars().next().map_or(false, char::is_whitespace);
+            !prev_is_space || next_is_space
+        };
+        if self.kind != UndoItemKind::Typing
+            || next.kind != UndoItemKind::Typing
+            || !next.removed.is_empty()
+            || self.cursor_after != next.pos
+            || !continues_word(&self.inserted, &next.inserted)
+        {
+            return false;
+        }
+        self.inserted.push_str(&next.inserted);
+        self.cursor_after = next.cursor_after;
+        true
+    }
+}
+
+/// The maximum number of edits that can be undone. Older edits are forgotten.
+const MAX_UNDO_ITEMS: usize = 100;
+
+#[derive(Default, Debug)]
+struct TextInputUndoHistoryInner {
+    undo_stack: Vec<UndoItem>,
+    redo_stack: Vec<UndoItem>,
+    /// The text after the last recorded edit. If the text property doesn't match, it was changed
+    /// programmatically, and the history no longer applies.
+    current_text: SharedString,
+}
+
+impl TextInputUndoHistoryInner {
+    fn check_text(&mut self, text: &SharedString) {
+        if self.current_text != *text {
+            self.undo_stack.clear();
+            self.redo_stack.clear();
+            self.current_text = text.clone();
+        }
+    }
+}
+
+/// The undo/redo history of a TextInput
+#[derive(Default, Debug)]
+pub struct TextInputUndoHistory {
+    inner: RefCell<TextInputUndoHistoryInner>,
+}
+
+impl TextInputUndoHistory {
+    fn record(&self, text: &SharedString, item: UndoItem, new_text: SharedString) {
+        let mut inner = self.inner.borrow_mut();
+        inner.check_text(text);
+        if item.removed.is_empty() && item.inserted.is_empty() {
+            return;
+        }
+        inner.redo_stack.clear();
+        if !inner.undo_stack.last_mut().map_or(false, |last| last.try_merge(&item)) {
+            if inner.undo_stack.len() >= MAX_UNDO_ITEMS {
+                inner.undo_stack.remove(0);
+            }
+            inner.undo_stack.push(item);
+        }
+        inner.current_text = new_text;
+    }
+
+    /// Returns the item to revert, and moves it to the redo stack
+    fn undo(&self, text: &SharedString) -> Option<UndoItem> {
+        let mut inner = self.inner.borrow_mut();
+        inner.check_text(text);
+        let item = inner.undo_stack.pop()?;
+        // Typing after an undo/redo starts a new word
+        inner.redo_stack.push(UndoItem { kind: UndoItemKind::Other, ..item.clone() });
+        Some(item)
+    }
+
+    /// Returns the item to re-apply, and moves it back to the undo stack
+    fn redo(&self, text: &SharedString) -> Option<UndoItem> {
+        let mut inner = self.inner.borrow_mut();
+        inner.check_text(text);
+        let item = inner.redo_stack.pop()?;
+        inner.undo_stack.push(item.clone());
+        Some(item)
+    }
+
+    fn set_current_text(&self, text: SharedString) {
+        self.inner.borrow_mut().current_text = text;
+    }
+}
+
+#[repr(C)]
+/// Wraps the undo/redo history of a TextInput
+pub struct TextInputUndoHistoryBox(core::ptr::NonNull<TextInputUndoHistory>);
+
+impl Default for TextInputUndoHistoryBox {
+    fn default() -> Self {
+        TextInputUndoHistoryBox(Box::leak(Box::new(TextInputUndoHistory::default())).into())
+    }
+}
+impl Drop for TextInputUndoHistoryBox {
+    fn drop(&mut self) {
+        // Safety: the self.0 was constructed from a Box::leak in TextInputUndoHistoryBox::default
+        drop(unsafe { Box::from_raw(self.0.as_ptr()) });
+    }
+}
+
+impl core::ops::Deref for TextInputUndoHistoryBox {
+    type Target = TextInputUndoHistory;
+    fn deref(&self) -> &Self::Target {
+        // Safety: initialized in TextInputUndoHistoryBox::default
+        unsafe { self.0.as_ref() }
+    }
+}
+
 fn next_paragraph_boundary(text: &str, last_cursor_pos: usize) -> usize {
     text.as_bytes()
         .iter()
@@ -1273,3 +1635,46 @@ fn next_word_boundary(text: &str, last_cursor_pos: usize) -> usize {
     let self_rc = ItemRc::new(self_component.clone(), self_index);
     Pin::new_unchecked(&*text_input).as_ref().paste(window_adapter, &self_rc);
 }
+
+#[cfg(feature = "ffi")]
+#[no_mangle]
+pub unsafe extern "C" fn slint_textinput_undo(
+    text_input: *const TextInput,
+    window_adapter: *const crate::window::ffi::WindowAdapterRcOpaque,
+    self_component: &vtable::VRc<crate::component::ComponentVTable>,
+    self_index: usize,
+) {
+    let window_adapter = &*(window_adapter as *const Rc<dyn WindowAdapter>);
+    let self_rc = ItemRc::new(self_component.clone(), self_index);
+    Pin::new_unchecked(&*text_input).as_ref().undo(window_adapter, &self_rc);
+}
+
+#[cfg(feature = "ffi")]
+#[no_mangle]
+pub unsafe extern "C" fn slint_textinput_redo(
+    text_input: *const TextInput,
+    window_adapter: *const crate::window::ffi::WindowAdapterRcOpaque,
+    self_component: &vtable::VRc<crate::component::ComponentVTable>,
+    self_index: usize,
+) {
+    let window_adapter = &*(window_adapter as *const Rc<dyn WindowAdapter>);
+    let self_rc = ItemRc::new(self_component.clone(), self_index);
+    Pin::new_unchecked(&*text_input).as_ref().redo(window_adapter, &self_rc);
+}
+
+/// # Safety
+/// This must be called using a non-null pointer pointing to a chunk of memory big enough to
+/// hold a TextInputUndoHistoryBox
+#[cfg(feature = "ffi")]
+#[no_mangle]
+pub unsafe extern "C" fn slint_textinput_undo_history_init(data: *mut TextInputUndoHistoryBox) {
+    core::ptr::write(data, TextInputUndoHistoryBox::default());
+}
+
+/// # Safety
+/// This must be called using a non-null pointer pointing to an initialized TextInputUndoHistoryBox
+#[cfg(feature = "ffi")]
+#[no_mangle]
+pub unsafe extern "C" fn slint_textinput_undo_history_free(data: *mut TextInputUndoHistoryBox) {
+    core::ptr::drop_in_place(data);
+}
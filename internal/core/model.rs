@@ -11,7 +11,7 @@
 use crate::layout::Orientation;
 use crate::lengths::{LogicalLength, RectLengths};
 use crate::{Coord, Property, SharedString, SharedVector};
-pub use adapters::{FilterModel, MapModel, SortModel};
+pub use adapters::{FilterModel, FlattenedTreeModel, MapModel, SortModel};
 use alloc::boxed::Box;
 use alloc::rc::Rc;
 use alloc::vec::Vec;
@@ -240,6 +240,53 @@ fn sort_by<F>(self, sort_function: F) -> SortModel<Self, F>
 
 impl<T: Model> ModelExt for T {}
 
+/// A tree of items, that can be shown in a `StandardTreeView` through a [`FlattenedTreeModel`].
+///
+/// The nodes are identified by their path: the index of each ancestor among its siblings, followed
+/// by the index of the node itself. The empty path is the invisible root of the tree, its children
+/// are the top-level items.
+///
+/// ## Example
+///
+/// ```
+/// # use slint::{FlattenedTreeModel, Model, SharedString, TreeModel};
+/// /// A tree where each node has three children, down to the third level
+/// struct Numbers;
+///
+/// impl TreeModel for Numbers {
+///     fn child_count(&self, path: &[usize]) -> usize {
+///         if path.len() < 3 { 3 } else { 0 }
+///     }
+///     fn text(&self, path: &[usize]) -> SharedString {
+///         path.iter().map(|i| i.to_string()).collect::<Vec<_>>().join(".").into()
+///     }
+/// }
+///
+/// let model = FlattenedTreeModel::new(Numbers);
+/// assert_eq!(model.row_count(), 3);
+/// model.expand(1);
+/// assert_eq!(model.row_count(), 6);
+/// assert_eq!(model.row_data(2).unwrap().text, "1.0");
+/// ```
+pub trait TreeModel {
+    /// Returns the number of children of the node at `path`.
+    ///
+    /// This is only called for the root, and for the nodes that are expanded, so the children
+    /// can be loaded lazily.
+    fn child_count(&self, path: &[usize]) -> usize;
+
+    /// Returns the text of the node at `path`.
+    fn text(&self, path: &[usize]) -> SharedString;
+
+    /// Returns whether the node at `path` has children, and can be expanded.
+    ///
+    /// The default implementation calls [`Self::child_count`]. Re-implement it if loading the
+    /// children is expensive and should only happen when the node is expanded.
+    fn has_children(&self, path: &[usize]) -> bool {
+        self.child_count(path) > 0
+    }
+}
+
 /// An iterator over the elements of a model.
 /// This struct is created by the [`Model::iter()`] trait function.
 pub struct ModelIterator<'a, T> {
@@ -674,6 +721,74 @@ fn as_any(&self) -> &dyn core::any::Any {
     }
 }
 
+/// Iterate over all rows of the model, registering a dependency to the row count and to the
+/// data of each row in the current binding.
+fn tracked_rows<T>(model: &ModelRc<T>) -> impl Iterator<Item = T> + '_ {
+    model.model_tracker().track_row_count_changes();
+    (0..model.row_count()).filter_map(|row| model.row_data_tracked(row))
+}
+
+/// Register a dependency to the row count and to the data of each row of the model in the
+/// current binding, without reading the rows.
+/// The adapter models returned by the array functions have their own tracker, which is gone
+/// when the adapter is only used temporarily within the binding (as in `filter(...).length`).
+fn track_rows<T>(model: &ModelRc<T>) {
+    let tracker = model.model_tracker();
+    tracker.track_row_count_changes();
+    for row in 0..model.row_count() {
+        tracker.track_row_data_changes(row);
+    }
+}
+
+/// Returns a [`FilterModel`] with the rows of `model` for which `predicate` returns true.
+/// This implements the `filter` function of arrays in .slint files.
+pub fn filter_rows<T: 'static>(
+    model: &ModelRc<T>,
+    predicate: impl Fn(&T) -> bool + 'static,
+) -> ModelRc<T> {
+    track_rows(model);
+    ModelRc::new(FilterModel::new(model.clone(), predicate))
+}
+
+/// Returns a [`MapModel`] with the result of `map_function` for each row of `model`.
+/// This implements the `map` function of arrays in .slint files.
+pub fn map_rows<T: 'static, U: 'static>(
+    model: &ModelRc<T>,
+    map_function: impl Fn(T) -> U + 'static,
+) -> ModelRc<U> {
+    track_rows(model);
+    ModelRc::new(MapModel::new(model.clone(), map_function))
+}
+
+/// Returns a [`SortModel`] with the rows of `model` sorted in ascending order of the key
+/// returned by `key_function`.
+/// This implements the `sort-by` function of arrays in .slint files.
+pub fn sort_rows_by_key<T: 'static, K: PartialOrd>(
+    model: &ModelRc<T>,
+    key_function: impl Fn(&T) -> K + 'static,
+) -> ModelRc<T> {
+    track_rows(model);
+    ModelRc::new(SortModel::new(model.clone(), move |a, b| {
+        key_function(a).partial_cmp(&key_function(b)).unwrap_or(core::cmp::Ordering::Equal)
+    }))
+}
+
+/// Returns the index of the first row of `model` for which `predicate` returns true, or -1.
+/// This implements the `find-index` function of arrays in .slint files.
+pub fn find_row<T>(model: &ModelRc<T>, mut predicate: impl FnMut(&T) -> bool) -> i32 {
+    tracked_rows(model).position(|x| predicate(&x)).map_or(-1, |i| i as i32)
+}
+
+/// Returns a model with the rows of `model` in reverse order.
+/// This implements the `reverse` function of arrays in .slint files.
+/// Unlike the other functions, there is no adapter model for this, so the rows are copied into a
+/// [`VecModel`] and the binding is re-evaluated when the source model changes.
+pub fn reverse_rows<T: Clone + 'static>(model: &ModelRc<T>) -> ModelRc<T> {
+    let mut rows = tracked_rows(model).collect::<Vec<_>>();
+    rows.reverse();
+    ModelRc::new(VecModel::from(rows))
+}
+
 /// Component that can be instantiated by a repeater.
 pub trait RepeatedComponent:
     crate::component::Component + vtable::HasStaticVTable<ComponentVTable> + 'static
@@ -1224,6 +1339,72 @@ fn from(value: &str) -> Self {
     }
 }
 
+/// Represents an item in a StandardTreeView. This is the Rust/C++ type for the StandardTreeViewItem
+/// type in Slint files, when declaring for example a `property <[StandardTreeViewItem]> my-tree-view-model;`.
+///
+/// The model of a StandardTreeView is flat: the children of an expanded item are the rows that
+/// follow it and have a higher level. Use a [`FlattenedTreeModel`] to provide it from a [`TreeModel`].
+#[repr(C)]
+#[derive(Clone, Default, Debug, PartialEq)]
+#[non_exhaustive]
+pub struct StandardTreeViewItem {
+    /// The text content of the item.
+    pub text: SharedString,
+    /// The depth of the item in the tree. Top-level items have the level 0.
+    pub level: i32,
+    /// Whether the item has children, and can be expanded.
+    pub has_children: bool,
+    /// Whether the item is expanded, and its children are shown.
+    pub expanded: bool,
+}
+
+impl From<SharedString> for StandardTreeViewItem {
+    fn from(value: SharedString) -> Self {
+        StandardTreeViewItem { text: value, ..Default::default() }
+    }
+}
+
+impl From<&str> for StandardTreeViewItem {
+    fn from(value: &str) -> Self {
+        StandardTreeViewItem { text: value.into(), ..Default::default() }
+    }
+}
+
+/// Represents an item in a ContextMenu or a MenuBar. This is the Rust/C++ type for the StandardMenuItem
+/// type in Slint files, when declaring for example a `property <[StandardMenuItem]> my-menu-model;`.
+///
+/// The model of a menu is flat: the submenu of an item is formed by the items that follow it and
+/// have a higher level.
+#[repr(C)]
+#[derive(Clone, Default, Debug, PartialEq)]
+#[non_exhaustive]
+pub struct StandardMenuItem {
+    /// The text content of the item.
+    pub text: SharedString,
+    /// The keyboard shortcut that activates the item, such as `"Ctrl+S"`.
+    pub shortcut: SharedString,
+    /// Whether the item can be checked and unchecked.
+    pub checkable: bool,
+    /// Whether the item is checked.
+    pub checked: bool,
+    /// Whether the item is a separator line instead of an entry.
+    pub is_separator: bool,
+    /// The depth of the item in the menu. Top-level items have the level 0.
+    pub level: i32,
+}
+
+impl From<SharedString> for StandardMenuItem {
+    fn from(value: SharedString) -> Self {
+        StandardMenuItem { text: value, ..Default::default() }
+    }
+}
+
+impl From<&str> for StandardMenuItem {
+    fn from(value: &str) -> Self {
+        StandardMenuItem { text: value.into(), ..Default::default() }
+    }
+}
+
 /// Represent an TableColumn header
 #[repr(C)]
 #[derive(Clone, Default, Debug, PartialEq)]
@@ -1328,6 +1509,39 @@ fn test_data_tracking() {
     assert!(tracker.is_dirty());
 }
 
+#[test]
+fn test_array_functions() {
+    let model: Rc<VecModel<i32>> = Rc::new(VecModel::from(vec![3, 1, 4, 1, 5]));
+    let handle = ModelRc::from(model.clone());
+    let rows = |m: ModelRc<i32>| m.iter().collect::<Vec<_>>();
+    assert_eq!(rows(filter_rows(&handle, |x| *x > 1)), vec![3, 4, 5]);
+    assert_eq!(rows(map_rows(&handle, |x| x * 2)), vec![6, 2, 8, 2, 10]);
+    assert_eq!(rows(sort_rows_by_key(&handle, |x| -x)), vec![5, 4, 3, 1, 1]);
+    assert_eq!(rows(reverse_rows(&handle)), vec![5, 1, 4, 1, 3]);
+    assert_eq!(find_row(&handle, |x| *x == 4), 2);
+    assert_eq!(find_row(&handle, |x| *x == 42), -1);
+    assert_eq!(find_row(&ModelRc::default(), |_: &i32| true), -1);
+
+    // filter, map and sort-by follow the changes of the source model
+    let filtered = filter_rows(&handle, |x| *x > 1);
+    let sorted = sort_rows_by_key(&handle, |x| *x);
+    let mapped = map_rows(&handle, |x| x + 1);
+    model.push(2);
+    assert_eq!(rows(filtered), vec![3, 4, 5, 2]);
+    assert_eq!(rows(sorted), vec![1, 1, 2, 3, 4, 5]);
+    assert_eq!(rows(mapped), vec![4, 2, 5, 2, 6, 3]);
+    model.remove(5);
+
+    let tracker = Box::pin(crate::properties::PropertyTracker::default());
+    assert_eq!(tracker.as_ref().evaluate(|| find_row(&handle, |x| *x == 5)), 4);
+    assert!(!tracker.is_dirty());
+    model.set_row_data(4, 6);
+    assert!(tracker.is_dirty());
+    assert_eq!(tracker.as_ref().evaluate(|| find_row(&handle, |x| *x == 5)), -1);
+    model.push(5);
+    assert!(tracker.is_dirty());
+}
+
 #[test]
 fn test_vecmodel_set_vec() {
     #[derive(Default)]
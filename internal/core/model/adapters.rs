@@ -945,3 +945,226 @@ fn test_sorted_model_changed() {
         assert_eq!(sorted_model.row_data(3).unwrap(), 3);
     }
 }
+
+struct FlattenedTreeRow {
+    path: Vec<usize>,
+    expanded: bool,
+}
+
+/// Provides the rows of a [`TreeModel`] for a `StandardTreeView`: the top-level items, followed
+/// by the children of each expanded item.
+///
+/// The children of an item are only loaded from the tree when the item is expanded, with
+/// [`Self::expand`], or when the `StandardTreeView` sets the `expanded` field of its row.
+/// Collapsing an item forgets its children, so they are loaded again when it is expanded again.
+///
+/// ## Example
+///
+/// ```
+/// # use slint::{FlattenedTreeModel, Model, SharedString, TreeModel};
+/// struct Files;
+///
+/// impl TreeModel for Files {
+///     fn child_count(&self, path: &[usize]) -> usize {
+///         match path {
+///             [] => 2,
+///             [0] => 1,
+///             _ => 0,
+///         }
+///     }
+///     fn text(&self, path: &[usize]) -> SharedString {
+///         match path {
+///             [0] => "src".into(),
+///             [0, 0] => "main.rs".into(),
+///             _ => "Cargo.toml".into(),
+///         }
+///     }
+/// }
+///
+/// let model = FlattenedTreeModel::new(Files);
+/// assert_eq!(model.row_count(), 2);
+/// assert!(model.row_data(0).unwrap().has_children);
+///
+/// // Setting the `expanded` field, as the StandardTreeView does, loads the children
+/// let mut src = model.row_data(0).unwrap();
+/// src.expanded = true;
+/// model.set_row_data(0, src);
+/// assert_eq!(model.row_count(), 3);
+/// assert_eq!(model.row_data(1).unwrap().text, "main.rs");
+/// assert_eq!(model.row_data(1).unwrap().level, 1);
+/// assert_eq!(model.path(1), Some(vec![0, 0]));
+/// ```
+pub struct FlattenedTreeModel<T> {
+    tree: T,
+    rows: RefCell<Vec<FlattenedTreeRow>>,
+    notify: ModelNotify,
+}
+
+impl<T: TreeModel> FlattenedTreeModel<T> {
+    /// Creates a new FlattenedTreeModel showing the top-level items of `tree`, all collapsed.
+    pub fn new(tree: T) -> Self {
+        let model = Self { tree, rows: Default::default(), notify: Default::default() };
+        *model.rows.borrow_mut() = model.top_level_rows();
+        model
+    }
+
+    fn top_level_rows(&self) -> Vec<FlattenedTreeRow> {
+        (0..self.tree.child_count(&[]))
+            .map(|i| FlattenedTreeRow { path: alloc::vec![i], expanded: false })
+            .collect()
+    }
+
+    /// Returns a reference to the tree.
+    pub fn tree(&self) -> &T {
+        &self.tree
+    }
+
+    /// Returns the path in the tree of the node shown at `row`.
+    pub fn path(&self, row: usize) -> Option<Vec<usize>> {
+        self.rows.borrow().get(row).map(|r| r.path.clone())
+    }
+
+    /// Shows the children of the node at `row`, after loading them from the tree.
+    pub fn expand(&self, row: usize) {
+        let (path, count) = {
+            let rows = self.rows.borrow();
+            let Some(r) = rows.get(row) else { return };
+            if r.expanded || !self.tree.has_children(&r.path) {
+                return;
+            }
+            (r.path.clone(), self.tree.child_count(&r.path))
+        };
+        {
+            let mut rows = self.rows.borrow_mut();
+            rows[row].expanded = true;
+            rows.splice(
+                row + 1..row + 1,
+                (0..count).map(|i| {
+                    let mut path = path.clone();
+                    path.push(i);
+                    FlattenedTreeRow { path, expanded: false }
+                }),
+            );
+        }
+        self.notify.row_changed(row);
+        if count > 0 {
+            self.notify.row_added(row + 1, count);
+        }
+    }
+
+    /// Hides the children of the node at `row`, and of all its descendants.
+    pub fn collapse(&self, row: usize) {
+        let count = {
+            let mut rows = self.rows.borrow_mut();
+            let Some(r) = rows.get_mut(row) else { return };
+            if !r.expanded {
+                return;
+            }
+            r.expanded = false;
+            let level = r.path.len();
+            let count = rows[row + 1..].iter().take_while(|r| r.path.len() > level).count();
+            rows.drain(row + 1..row + 1 + count);
+            count
+        };
+        self.notify.row_changed(row);
+        if count > 0 {
+            self.notify.row_removed(row + 1, count);
+        }
+    }
+
+    /// Reloads the top-level items from the tree, collapsing all items. Call this when the
+    /// tree has changed.
+    pub fn reset(&self) {
+        *self.rows.borrow_mut() = self.top_level_rows();
+        self.notify.reset();
+    }
+}
+
+impl<T: TreeModel + 'static> Model for FlattenedTreeModel<T> {
+    type Data = StandardTreeViewItem;
+
+    fn row_count(&self) -> usize {
+        self.rows.borrow().len()
+    }
+
+    fn row_data(&self, row: usize) -> Option<Self::Data> {
+        let rows = self.rows.borrow();
+        let r = rows.get(row)?;
+        Some(StandardTreeViewItem {
+            text: self.tree.text(&r.path),
+            level: r.path.len() as i32 - 1,
+            has_children: self.tree.has_children(&r.path),
+            expanded: r.expanded,
+        })
+    }
+
+    /// Expands or collapses the node at `row` according to the `expanded` field of `data`.
+    /// The other fields are given by the tree, and are ignored.
+    fn set_row_data(&self, row: usize, data: Self::Data) {
+        if data.expanded {
+            self.expand(row);
+        } else {
+            self.collapse(row);
+        }
+    }
+
+    fn model_tracker(&self) -> &dyn ModelTracker {
+        &self.notify
+    }
+
+    fn as_any(&self) -> &dyn core::any::Any {
+        self
+    }
+}
+
+#[test]
+fn test_flattened_tree_model() {
+    struct Tree;
+    impl TreeModel for Tree {
+        fn child_count(&self, path: &[usize]) -> usize {
+            match path {
+                [] => 2,
+                [0] => 2,
+                [0, 1] => 1,
+                _ => 0,
+            }
+        }
+        fn text(&self, path: &[usize]) -> SharedString {
+            let mut text = String::from("n");
+            for i in path {
+                text.push_str(&i.to_string());
+            }
+            text.into()
+        }
+    }
+
+    let model = FlattenedTreeModel::new(Tree);
+    let texts = || model.iter().map(|item| item.text).collect::<Vec<_>>();
+    assert_eq!(texts(), ["n0", "n1"]);
+    assert!(model.row_data(0).unwrap().has_children);
+    assert!(!model.row_data(1).unwrap().has_children);
+
+    model.expand(0);
+    assert_eq!(texts(), ["n0", "n00", "n01", "n1"]);
+    model.expand(2);
+    assert_eq!(texts(), ["n0", "n00", "n01", "n010", "n1"]);
+    assert_eq!(model.row_data(3).unwrap().level, 2);
+    assert_eq!(model.path(3), Some(vec![0, 1, 0]));
+
+    // Items without children can't be expanded
+    model.expand(1);
+    assert_eq!(model.row_count(), 5);
+    assert!(!model.row_data(1).unwrap().expanded);
+
+    model.set_row_data(0, StandardTreeViewItem { expanded: false, ..Default::default() });
+    assert_eq!(texts(), ["n0", "n1"]);
+    assert!(!model.row_data(0).unwrap().expanded);
+
+    // The descendants were collapsed too
+    model.expand(0);
+    assert_eq!(texts(), ["n0", "n00", "n01", "n1"]);
+    assert!(!model.row_data(2).unwrap().expanded);
+
+    model.reset();
+    assert_eq!(texts(), ["n0", "n1"]);
+}
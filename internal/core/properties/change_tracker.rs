@@ -0,0 +1,181 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-1.0 OR LicenseRef-Slint-commercial
+
+//! The [`ChangeTracker`] calls a handler when the value of an expression changes.
+
+use super::{evaluate_no_tracking, PropertyDirtyHandler, PropertyTracker};
+use alloc::boxed::Box;
+use alloc::rc::{Rc, Weak};
+use alloc::vec::Vec;
+use core::cell::RefCell;
+use core::pin::Pin;
+
+/// How many times [`ChangeTracker::run_change_handlers()`] processes the trackers that became dirty
+/// again because of the handlers, before giving up.
+const MAX_ROUNDS: usize = 10;
+
+#[cfg(all(not(feature = "std"), feature = "unsafe-single-threaded"))]
+use crate::unsafe_single_threaded::thread_local;
+
+thread_local!(static PENDING_CHANGE_TRACKERS: RefCell<Vec<Weak<ChangeTrackerInner>>> = RefCell::default());
+
+struct MarkPending(Weak<ChangeTrackerInner>);
+
+impl PropertyDirtyHandler for MarkPending {
+    fn notify(&self) {
+        // The properties are locked while this is called, so only queue the tracker for later.
+        PENDING_CHANGE_TRACKERS.with(|pending| pending.borrow_mut().push(self.0.clone()));
+    }
+}
+
+struct ChangeTrackerInner {
+    tracker: PropertyTracker<MarkPending>,
+    /// Evaluates the expression, and returns true if its value changed since the last evaluation
+    eval_fn: Box<dyn Fn() -> bool>,
+    notify_fn: Box<dyn Fn()>,
+}
+
+impl ChangeTrackerInner {
+    /// Re-evaluate the expression, and returns true if the value changed.
+    fn evaluate(&self) -> bool {
+        // Safety: the ChangeTrackerInner is never moved out of its Rc
+        let tracker = unsafe { Pin::new_unchecked(&self.tracker) };
+        tracker.evaluate_as_dependency_root(|| (self.eval_fn)())
+    }
+}
+
+/// Calls a handler when the value of an expression changes.
+///
+/// The handler is not called right away when one of the properties used by the expression changes,
+/// but later, from [`Self::run_change_handlers()`], once the bindings have settled. It is then only
+/// called once even if the properties changed several times, and only if the value is different.
+#[derive(Default)]
+pub struct ChangeTracker {
+    inner: RefCell<Option<Rc<ChangeTrackerInner>>>,
+}
+
+impl ChangeTracker {
+    /// Start tracking the value returned by `eval_fn`, and call `notify_fn` when it changes.
+    ///
+    /// `eval_fn` is evaluated right away to get the initial value. Calling this function again
+    /// replaces the previous expression and handler.
+    pub fn init<T: PartialEq + 'static>(
+        &self,
+        eval_fn: impl Fn() -> T + 'static,
+        notify_fn: impl Fn() + 'static,
+    ) {
+        let value = RefCell::new(None::<T>);
+        let eval_fn = move || {
+            let new_value = eval_fn();
+            let mut value = value.borrow_mut();
+            let changed = value.as_ref().map_or(false, |old| *old != new_value);
+            *value = Some(new_value);
+            changed
+        };
+        self.init_erased(Box::new(eval_fn), Box::new(notify_fn));
+    }
+
+    /// Same as [`Self::init()`], but `eval_fn` does the comparison itself and returns true if the value changed
+    pub(crate) fn init_erased(&self, eval_fn: Box<dyn Fn() -> bool>, notify_fn: Box<dyn Fn()>) {
+        let inner = Rc::new_cyclic(|weak| ChangeTrackerInner {
+            tracker: PropertyTracker::new_with_dirty_handler(MarkPending(weak.clone())),
+            eval_fn,
+            notify_fn,
+        });
+        inner.evaluate();
+        *self.inner.borrow_mut() = Some(inner);
+    }
+
+    /// Call the handlers of the change trackers whose value changed.
+    ///
+    /// This is called by the event loop before rendering. The handlers may change properties
+    /// that other trackers depend on; their handlers are then called as well.
+    pub fn run_change_handlers() {
+        for _ in 0..MAX_ROUNDS {
+            let pending =
+                PENDING_CHANGE_TRACKERS.with(|pending| core::mem::take(&mut *pending.borrow_mut()));
+            if pending.is_empty() {
+                return;
+            }
+            for inner in pending.iter().filter_map(Weak::upgrade) {
+                if inner.tracker.is_dirty() && inner.evaluate() {
+                    evaluate_no_tracking(|| (inner.notify_fn)());
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn change_tracker() {
+    use super::Property;
+    let prop = Rc::pin(Property::new(1));
+    let calls = Rc::new(RefCell::new(Vec::new()));
+    let tracker = ChangeTracker::default();
+    tracker.init(
+        {
+            let prop = prop.clone();
+            move || prop.as_ref().get() % 10
+        },
+        {
+            let prop = prop.clone();
+            let calls = calls.clone();
+            move || calls.borrow_mut().push(prop.as_ref().get())
+        },
+    );
+    ChangeTracker::run_change_handlers();
+    assert!(calls.borrow().is_empty());
+
+    // Called once, after the changes
+    prop.as_ref().set(2);
+    prop.as_ref().set(3);
+    assert!(calls.borrow().is_empty());
+    ChangeTracker::run_change_handlers();
+    assert_eq!(*calls.borrow(), [3]);
+
+    // Not called if the value did not change
+    prop.as_ref().set(13);
+    ChangeTracker::run_change_handlers();
+    assert_eq!(*calls.borrow(), [3]);
+
+    // Not called anymore once dropped
+    prop.as_ref().set(4);
+    drop(tracker);
+    ChangeTracker::run_change_handlers();
+    assert_eq!(*calls.borrow(), [3]);
+}
+
+#[test]
+fn change_tracker_chain() {
+    use super::Property;
+    let first = Rc::pin(Property::new(0));
+    let second = Rc::pin(Property::new(0));
+    let tracker1 = ChangeTracker::default();
+    tracker1.init(
+        {
+            let first = first.clone();
+            move || first.as_ref().get()
+        },
+        {
+            let (first, second) = (first.clone(), second.clone());
+            move || second.as_ref().set(first.as_ref().get() * 2)
+        },
+    );
+    let calls = Rc::new(core::cell::Cell::new(0));
+    let tracker2 = ChangeTracker::default();
+    tracker2.init(
+        {
+            let second = second.clone();
+            move || second.as_ref().get()
+        },
+        {
+            let calls = calls.clone();
+            move || calls.set(calls.get() + 1)
+        },
+    );
+    first.as_ref().set(21);
+    ChangeTracker::run_change_handlers();
+    assert_eq!(second.as_ref().get(), 42);
+    assert_eq!(calls.get(), 1);
+    drop((tracker1, tracker2));
+}
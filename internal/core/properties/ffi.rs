@@ -451,6 +451,60 @@ pub struct PropertyTrackerOpaque {
     core::ptr::drop_in_place(handle as *mut PropertyTracker);
 }
 
+#[repr(C)]
+/// Opaque type representing the ChangeTracker
+pub struct ChangeTrackerOpaque {
+    inner: [usize; 2],
+}
+
+static_assertions::assert_eq_align!(ChangeTrackerOpaque, ChangeTracker);
+static_assertions::assert_eq_size!(ChangeTrackerOpaque, ChangeTracker);
+
+/// Initialize the ChangeTracker.
+/// `out` is assumed to be uninitialized
+/// slint_change_tracker_drop need to be called after that
+#[no_mangle]
+pub unsafe extern "C" fn slint_change_tracker_construct(out: *mut ChangeTrackerOpaque) {
+    core::ptr::write(out as *mut ChangeTracker, ChangeTracker::default());
+}
+
+/// Start tracking. `eval_fn` evaluates the expression and returns true if its value changed since
+/// the last call, `notify_fn` is the change handler.
+#[no_mangle]
+pub unsafe extern "C" fn slint_change_tracker_init(
+    handle: *const ChangeTrackerOpaque,
+    user_data: *mut c_void,
+    drop_user_data: Option<extern "C" fn(*mut c_void)>,
+    eval_fn: extern "C" fn(user_data: *mut c_void) -> bool,
+    notify_fn: extern "C" fn(user_data: *mut c_void),
+) {
+    struct CUserData {
+        user_data: *mut c_void,
+        drop_user_data: Option<extern "C" fn(*mut c_void)>,
+    }
+
+    impl Drop for CUserData {
+        fn drop(&mut self) {
+            if let Some(x) = self.drop_user_data {
+                x(self.user_data)
+            }
+        }
+    }
+
+    let user_data = alloc::rc::Rc::new(CUserData { user_data, drop_user_data });
+    let eval_user_data = user_data.clone();
+    (*(handle as *const ChangeTracker)).init_erased(
+        Box::new(move || eval_fn(eval_user_data.user_data)),
+        Box::new(move || notify_fn(user_data.user_data)),
+    );
+}
+
+/// Destroy the ChangeTracker
+#[no_mangle]
+pub unsafe extern "C" fn slint_change_tracker_drop(handle: *mut ChangeTrackerOpaque) {
+    core::ptr::drop_in_place(handle as *mut ChangeTracker);
+}
+
 /// return the current animation tick for the `animation-tick` function
 #[no_mangle]
 pub extern "C" fn slint_animation_tick() -> u64 {
@@ -5,13 +5,19 @@
 
 #![warn(missing_docs)]
 
+mod box_shadow;
 mod draw_functions;
 mod fonts;
+mod layer_cache;
+#[cfg(feature = "std")]
+mod path;
+mod pixel_formats;
 
 use crate::api::Window;
+use crate::graphics::boxshadowcache::{BoxShadowCache, BoxShadowOptions};
 use crate::graphics::{IntRect, PixelFormat, SharedImageBuffer, SharedPixelBuffer};
 use crate::item_rendering::ItemRenderer;
-use crate::items::{ImageFit, ItemRc, TextOverflow};
+use crate::items::{ImageFit, ItemRc, RenderingResult, TextOverflow};
 use crate::lengths::{
     LogicalLength, LogicalPoint, LogicalRect, LogicalSize, LogicalVector, PhysicalPx, PointLengths,
     RectLengths, ScaleFactor, SizeLengths,
@@ -30,6 +36,10 @@
 use num_traits::Float;
 
 pub use draw_functions::{PremultipliedRgbaColor, Rgb565Pixel, TargetPixel};
+pub use pixel_formats::{
+    Bgr565SwappedPixel, DitheredPixel, DitheringLineBuffer, Gray8Pixel, MonochromePixel,
+    Xrgb8888Pixel,
+};
 
 use self::fonts::GlyphRenderer;
 
@@ -122,6 +132,10 @@ pub struct SoftwareRenderer {
     /// Only used if repaint_buffer_type == RepaintBufferType::SwappedBuffers
     prev_frame_dirty: Cell<DirtyRegion>,
     window: RefCell<Option<Weak<dyn crate::window::WindowAdapter>>>,
+    /// The alpha maps of the blurred box shadows, shared by all the shadows with the same options
+    box_shadow_cache: BoxShadowCache<SharedBufferData>,
+    /// The rendering of the elements with `cache-rendering-hint: true`
+    layer_cache: layer_cache::LayerCache,
 }
 
 impl SoftwareRenderer {
@@ -147,6 +161,8 @@ pub fn new(
             force_dirty: Default::default(),
             force_screen_refresh: Default::default(),
             prev_frame_dirty: Default::default(),
+            box_shadow_cache: Default::default(),
+            layer_cache: Default::default(),
         }
     }
 
@@ -165,6 +181,8 @@ pub fn new_without_window(repaint_buffer_type: RepaintBufferType) -> Self {
             force_dirty: Default::default(),
             force_screen_refresh: Default::default(),
             prev_frame_dirty: Default::default(),
+            box_shadow_cache: Default::default(),
+            layer_cache: Default::default(),
         }
     }
 
@@ -176,6 +194,15 @@ pub fn set_window(&self, window: &crate::api::Window) {
             Some(Rc::downgrade(&WindowInner::from_pub(window).window_adapter().clone()));
     }
 
+    /// Sets the maximum amount of memory, in bytes, used to keep the rendering of the elements
+    /// with `cache-rendering-hint: true`.
+    ///
+    /// When the budget is exceeded, the least recently drawn elements are removed from the cache.
+    /// A budget of 0 disables the caching. The default is 512 KiB.
+    pub fn set_layer_cache_budget(&self, bytes: usize) {
+        self.layer_cache.set_budget(bytes);
+    }
+
     /// Internal function to apply a dirty region depending on the dirty_tracking_policy.
     /// Returns the region to actually draw.
     fn apply_dirty_region(
@@ -202,6 +229,20 @@ fn apply_dirty_region(
         .unwrap_or_default()
     }
 
+    /// Free the box shadow alpha maps that are no longer in use after rendering `rendered_region`.
+    ///
+    /// Only a full redraw visits every visible shadow, so unless the cache grew too much, keep
+    /// the textures of the shadows which were outside of a partial redraw.
+    fn collect_box_shadows(&self, rendered_region: DirtyRegion, screen_size: PhysicalSize) {
+        /// Number of box shadow textures kept in the cache between two full redraws
+        const MAX_CACHED_BOX_SHADOWS: usize = 32;
+        if rendered_region.size == screen_size
+            || self.box_shadow_cache.len() > MAX_CACHED_BOX_SHADOWS
+        {
+            self.box_shadow_cache.remove_unused();
+        }
+    }
+
     /// Render the window to the given frame buffer.
     ///
     /// The renderer uses a cache internally and will only render the part of the window
@@ -236,6 +277,9 @@ pub fn render(&self, buffer: &mut [impl TargetPixel], pixel_stride: usize) -> Ph
             factor,
             window_inner,
             RenderToBuffer { buffer, stride: pixel_stride },
+            &self.box_shadow_cache,
+            &self.layer_cache,
+            &self.partial_cache,
         );
         let mut renderer = crate::item_rendering::PartialRenderer::new(
             &self.partial_cache,
@@ -261,11 +305,24 @@ pub fn render(&self, buffer: &mut [impl TargetPixel], pixel_stride: usize) -> Ph
                 );
 
                 if !background.is_transparent() {
-                    // FIXME: gradient
-                    renderer
-                        .actual_renderer
-                        .processor
-                        .process_rectangle(to_draw, background.color().into());
+                    let window_rect = euclid::Rect::from_size(size.cast());
+                    match GradientFill::new(&background, window_rect, 1.) {
+                        Some(gradient) => {
+                            let processor = &mut renderer.actual_renderer.processor;
+                            for y in to_draw.y_range() {
+                                let line = &mut processor.buffer[y as usize * processor.stride..];
+                                gradient.blend_line(
+                                    y,
+                                    to_draw.x_range(),
+                                    &mut line[to_draw.min_x() as usize..],
+                                );
+                            }
+                        }
+                        None => renderer
+                            .actual_renderer
+                            .processor
+                            .process_rectangle(to_draw, background.color().into()),
+                    }
                 }
                 for (component, origin) in components {
                     crate::item_rendering::render_component_items(
@@ -275,6 +332,8 @@ pub fn render(&self, buffer: &mut [impl TargetPixel], pixel_stride: usize) -> Ph
                     );
                 }
 
+                self.collect_box_shadows(to_draw, size);
+
                 PhysicalRegion(to_draw)
             })
             .unwrap_or_default()
@@ -480,12 +539,13 @@ fn text_input_cursor_rect_for_byte_offset(
 
     fn free_graphics_resources(
         &self,
-        _component: crate::component::ComponentRef,
+        component: crate::component::ComponentRef,
         items: &mut dyn Iterator<Item = Pin<crate::items::ItemRef<'_>>>,
     ) -> Result<(), crate::platform::PlatformError> {
         for item in items {
             item.cached_rendering_data_offset().release(&mut self.partial_cache.borrow_mut());
         }
+        self.layer_cache.component_destroyed(component);
         // We don't have a way to determine the screen region of the delete items, what's in the cache is relative. So
         // as a last resort, refresh everything.
         self.force_screen_refresh.set(true);
@@ -534,8 +594,10 @@ fn render_window_frame_by_line(
 
     debug_assert!(scene.current_line >= dirty_region.origin.y_length());
 
-    // FIXME gradient
     let background_color = background.color().into();
+    let background_gradient =
+        GradientFill::new(&background, euclid::Rect::from_size(size.cast()), 1.);
+    let mut effect_scratch = Vec::new();
 
     while scene.current_line < dirty_region.origin.y_length() + dirty_region.size.height_length() {
         line_buffer.process_line(
@@ -544,7 +606,14 @@ fn render_window_frame_by_line(
             |line_buffer| {
                 let offset = dirty_region.min_x() as usize;
 
-                TargetPixel::blend_slice(line_buffer, background_color);
+                match &background_gradient {
+                    Some(gradient) => gradient.blend_line(
+                        scene.current_line.get(),
+                        dirty_region.x_range(),
+                        line_buffer,
+                    ),
+                    None => TargetPixel::blend_slice(line_buffer, background_color),
+                }
                 for span in scene.items[0..scene.current_items_index].iter().rev() {
                     debug_assert!(scene.current_line >= span.pos.y_length());
                     debug_assert!(
@@ -611,6 +680,16 @@ fn render_window_frame_by_line(
                                 line_buffer,
                             );
                         }
+                        SceneCommand::Effect { effect_index } => {
+                            let effect = &scene.vectors.effects[effect_index as usize];
+                            effect.draw_line(
+                                &PhysicalRect { origin: span.pos, size: span.size },
+                                scene.current_line,
+                                &mut effect_scratch,
+                                line_buffer,
+                                offset as i16,
+                            );
+                        }
                     }
                 }
             },
@@ -629,6 +708,7 @@ struct SceneVectors {
     rounded_rectangles: Vec<RoundedRectangle>,
     shared_buffers: Vec<SharedBufferCommand>,
     gradients: Vec<GradientCommand>,
+    effects: Vec<EffectCommand>,
 }
 
 struct Scene {
@@ -825,6 +905,10 @@ enum SceneCommand {
     Gradient {
         gradient_index: u16,
     },
+    /// effect_index is an index in the [`SceneVectors::effects`] array
+    Effect {
+        effect_index: u16,
+    },
 }
 
 struct SceneTexture<'a> {
@@ -839,6 +923,7 @@ struct SceneTexture<'a> {
     alpha: u8,
 }
 
+#[derive(Clone)]
 enum SharedBufferData {
     SharedImage(SharedImageBuffer),
     AlphaMap { data: Rc<[u8]>, width: u16 },
@@ -949,7 +1034,15 @@ fn prepare_scene(
     software_renderer: &SoftwareRenderer,
 ) -> Scene {
     let factor = ScaleFactor::new(window.scale_factor());
-    let prepare_scene = SceneBuilder::new(size, factor, window, PrepareScene::default());
+    let prepare_scene = SceneBuilder::new(
+        size,
+        factor,
+        window,
+        PrepareScene::default(),
+        &software_renderer.box_shadow_cache,
+        &software_renderer.layer_cache,
+        &software_renderer.partial_cache,
+    );
     let mut renderer = crate::item_rendering::PartialRenderer::new(
         &software_renderer.partial_cache,
         software_renderer.force_dirty.take(),
@@ -974,6 +1067,7 @@ fn prepare_scene(
             crate::item_rendering::render_component_items(component, &mut renderer, *origin);
         }
     });
+    software_renderer.collect_box_shadows(dirty_region, size);
 
     let prepare_scene = renderer.into_inner();
     Scene::new(prepare_scene.processor.items, prepare_scene.processor.vectors, dirty_region)
@@ -985,6 +1079,7 @@ trait ProcessScene {
     fn process_rounded_rectangle(&mut self, geometry: PhysicalRect, data: RoundedRectangle);
     fn process_shared_image_buffer(&mut self, geometry: PhysicalRect, buffer: SharedBufferCommand);
     fn process_gradient(&mut self, geometry: PhysicalRect, gradient: GradientCommand);
+    fn process_effect(&mut self, geometry: PhysicalRect, effect: EffectCommand);
 }
 
 struct RenderToBuffer<'a, TargetPixel> {
@@ -1047,6 +1142,19 @@ fn process_gradient(&mut self, geometry: PhysicalRect, g: GradientCommand) {
             );
         }
     }
+
+    fn process_effect(&mut self, geometry: PhysicalRect, effect: EffectCommand) {
+        let mut scratch = Vec::new();
+        for line in geometry.min_y()..geometry.max_y() {
+            effect.draw_line(
+                &geometry,
+                PhysicalLength::new(line),
+                &mut scratch,
+                &mut self.buffer[line as usize * self.stride..],
+                0,
+            );
+        }
+    }
 }
 
 #[derive(Default)]
@@ -1120,6 +1228,20 @@ fn process_gradient(&mut self, geometry: PhysicalRect, gradient: GradientCommand
             });
         }
     }
+
+    fn process_effect(&mut self, geometry: PhysicalRect, effect: EffectCommand) {
+        let size = geometry.size;
+        if !size.is_empty() {
+            let effect_index = self.vectors.effects.len() as u16;
+            self.vectors.effects.push(effect);
+            self.items.push(SceneItem {
+                pos: geometry.origin,
+                size,
+                z: self.items.len() as u16,
+                command: SceneCommand::Effect { effect_index },
+            });
+        }
+    }
 }
 
 struct SceneBuilder<'a, T> {
@@ -1128,6 +1250,13 @@ struct SceneBuilder<'a, T> {
     current_state: RenderState,
     scale_factor: ScaleFactor,
     window: &'a WindowInner,
+    box_shadow_cache: &'a BoxShadowCache<SharedBufferData>,
+    layer_cache: &'a layer_cache::LayerCache,
+    /// The cache of the partial renderer, used to track the children of the layers
+    partial_cache: &'a RefCell<crate::item_rendering::PartialRenderingCache>,
+    /// The whole window, in logical coordinates. Unlike the initial clip, it doesn't depend on
+    /// the dirty region.
+    window_rect: LogicalRect,
 }
 
 impl<'a, T: ProcessScene> SceneBuilder<'a, T> {
@@ -1136,23 +1265,129 @@ fn new(
         scale_factor: ScaleFactor,
         window: &'a WindowInner,
         processor: T,
+        box_shadow_cache: &'a BoxShadowCache<SharedBufferData>,
+        layer_cache: &'a layer_cache::LayerCache,
+        partial_cache: &'a RefCell<crate::item_rendering::PartialRenderingCache>,
     ) -> Self {
+        let window_rect =
+            LogicalRect::new(LogicalPoint::default(), (size.cast() / scale_factor).cast());
         Self {
             processor,
             state_stack: vec![],
             current_state: RenderState {
                 alpha: 1.,
                 offset: LogicalPoint::default(),
-                clip: LogicalRect::new(
-                    LogicalPoint::default(),
-                    (size.cast() / scale_factor).cast(),
-                ),
+                clip: window_rect,
+                rounded_clip: None,
+                rotation: None,
             },
             scale_factor,
             window,
+            box_shadow_cache,
+            layer_cache,
+            partial_cache,
+            window_rect,
+        }
+    }
+
+    fn process_texture(&mut self, geometry: PhysicalRect, texture: SceneTexture<'static>) {
+        if self.needs_effect(&geometry) {
+            self.process_effect(geometry, EffectSource::Texture(texture), EffectPaint::Source)
+        } else {
+            self.processor.process_texture(geometry, texture)
+        }
+    }
+
+    fn process_rectangle(&mut self, geometry: PhysicalRect, color: PremultipliedRgbaColor) {
+        if self.needs_effect(&geometry) {
+            self.process_effect(geometry, EffectSource::Rectangle(color), EffectPaint::Source)
+        } else {
+            self.processor.process_rectangle(geometry, color)
         }
     }
 
+    fn process_rounded_rectangle(&mut self, geometry: PhysicalRect, data: RoundedRectangle) {
+        if self.needs_effect(&geometry) {
+            self.process_effect(geometry, EffectSource::RoundedRectangle(data), EffectPaint::Source)
+        } else {
+            self.processor.process_rounded_rectangle(geometry, data)
+        }
+    }
+
+    fn process_shared_image_buffer(&mut self, geometry: PhysicalRect, buffer: SharedBufferCommand) {
+        if self.needs_effect(&geometry) {
+            self.process_effect(geometry, EffectSource::SharedBuffer(buffer), EffectPaint::Source)
+        } else {
+            self.processor.process_shared_image_buffer(geometry, buffer)
+        }
+    }
+
+    fn process_gradient(&mut self, geometry: PhysicalRect, gradient: GradientCommand) {
+        if self.needs_effect(&geometry) {
+            let source = EffectSource::Gradient(gradient, Default::default());
+            self.process_effect(geometry, source, EffectPaint::Source)
+        } else {
+            self.processor.process_gradient(geometry, gradient)
+        }
+    }
+
+    /// Returns true if a command covering `geometry` cannot be given directly to the processor,
+    /// because it needs to be rotated or masked by a rounded clip.
+    fn needs_effect(&self, geometry: &PhysicalRect) -> bool {
+        self.current_state.rotation.is_some()
+            || self
+                .current_state
+                .rounded_clip
+                .as_ref()
+                .map_or(false, |clip| !clip.contains(geometry))
+    }
+
+    /// Draws a command with a gradient: the alpha of the pixels of the source, drawn with
+    /// an opaque white, is the coverage of the gradient.
+    fn process_with_gradient(
+        &mut self,
+        geometry: PhysicalRect,
+        gradient: &GradientFill,
+        source: EffectSource,
+    ) {
+        self.process_effect(geometry, source, EffectPaint::Gradient(gradient.clone()))
+    }
+
+    /// Gives the source covering `geometry` to the processor as an effect, which applies the paint,
+    /// the rounded clip and, if the current coordinate system is rotated, the rotation.
+    fn process_effect(&mut self, geometry: PhysicalRect, source: EffectSource, paint: EffectPaint) {
+        if geometry.is_empty() {
+            return;
+        }
+        let rotation = self.current_state.rotation.as_ref().map(|r| r.physical(self.scale_factor));
+        let target = match (&rotation, &self.current_state.rotation) {
+            (Some(physical), Some(rotation)) => {
+                let target =
+                    rotated_bounding_box(geometry.cast::<f32>(), physical.sin, physical.cos)
+                        .translate(physical.pivot.to_vector())
+                        .round_out()
+                        .cast::<i16>();
+                let window_clip: PhysicalRect =
+                    (rotation.window_clip.cast() * self.scale_factor).round().cast();
+                match target.intersection(&window_clip) {
+                    Some(target) => target,
+                    None => return,
+                }
+            }
+            _ => geometry,
+        };
+        self.processor.process_effect(
+            target,
+            EffectCommand {
+                source,
+                source_geometry: geometry,
+                paint,
+                clip: self.current_state.rounded_clip.clone(),
+                rotation,
+            },
+        );
+    }
+
     fn should_draw(&self, rect: &LogicalRect) -> bool {
         !rect.size.is_empty()
             && self.current_state.alpha > 0.01
@@ -1243,7 +1478,7 @@ fn draw_image_impl(
                             global_alpha_u16
                         } as u8;
 
-                        self.processor.process_texture(
+                        self.process_texture(
                             target_rect.cast(),
                             SceneTexture {
                                 data: &data.as_slice()[(t.index
@@ -1284,7 +1519,7 @@ fn draw_image_impl(
                             global_alpha_u16
                         } as u8;
 
-                        self.processor.process_shared_image_buffer(
+                        self.process_shared_image_buffer(
                             target_rect.cast(),
                             SharedBufferCommand {
                                 buffer: SharedBufferData::SharedImage(buffer),
@@ -1316,6 +1551,7 @@ fn draw_text_paragraph<'b, Font: AbstractFont>(
         physical_clip: euclid::Rect<f32, PhysicalPx>,
         offset: euclid::Vector2D<f32, PhysicalPx>,
         color: Color,
+        gradient: Option<&GradientFill>,
         selection: Option<SelectionInfo>,
     ) where
         Font: crate::textlayout::TextShaper<Length = PhysicalLength>,
@@ -1333,7 +1569,7 @@ fn draw_text_paragraph<'b, Font: AbstractFont>(
                             paragraph.layout.font.height().get(),
                         );
                         if let Some(clipped_src) = geometry.intersection(&physical_clip.cast()) {
-                            self.processor.process_rectangle(
+                            self.process_rectangle(
                                 clipped_src.translate(offset.cast()),
                                 selection.selection_background.into(),
                             );
@@ -1351,11 +1587,11 @@ fn draw_text_paragraph<'b, Font: AbstractFont>(
                         )
                         .cast();
 
-                        let color = match &selection {
+                        let (color, gradient) = match &selection {
                             Some(s) if s.selection.contains(&positioned_glyph.text_byte_offset) => {
-                                s.selection_color
+                                (s.selection_color, None)
                             }
-                            _ => color,
+                            _ => (color, gradient),
                         };
 
                         if let Some(clipped_src) = src_rect.intersection(&physical_clip) {
@@ -1368,36 +1604,52 @@ fn draw_text_paragraph<'b, Font: AbstractFont>(
 
                             match &glyph.alpha_map {
                                 fonts::GlyphAlphaMap::Static(data) => {
-                                    self.processor.process_texture(
-                                        geometry,
-                                        SceneTexture {
-                                            data: &data[actual_x + actual_y * stride as usize..],
-                                            stride,
-                                            source_size: geometry.size,
-                                            format: PixelFormat::AlphaMap,
-                                            color,
-                                            // color already is mixed with global alpha
-                                            alpha: color.alpha(),
-                                        },
-                                    );
+                                    let data = &data[actual_x + actual_y * stride as usize..];
+                                    let texture = move |color: Color| SceneTexture {
+                                        data,
+                                        stride,
+                                        source_size: geometry.size,
+                                        format: PixelFormat::AlphaMap,
+                                        color,
+                                        // color already is mixed with global alpha
+                                        alpha: color.alpha(),
+                                    };
+                                    match gradient {
+                                        Some(gradient) => self.process_with_gradient(
+                                            geometry,
+                                            gradient,
+                                            EffectSource::Texture(texture(Color::from_rgb_u8(
+                                                255, 255, 255,
+                                            ))),
+                                        ),
+                                        None => self.process_texture(geometry, texture(color)),
+                                    }
                                 }
                                 fonts::GlyphAlphaMap::Shared(data) => {
-                                    self.processor.process_shared_image_buffer(
-                                        geometry,
-                                        SharedBufferCommand {
-                                            buffer: SharedBufferData::AlphaMap {
-                                                data: data.clone(),
-                                                width: stride,
-                                            },
-                                            source_rect: PhysicalRect::new(
-                                                PhysicalPoint::new(actual_x as _, actual_y as _),
-                                                geometry.size,
-                                            ),
-                                            colorize: color,
-                                            // color already is mixed with global alpha
-                                            alpha: color.alpha(),
+                                    let command = |color: Color| SharedBufferCommand {
+                                        buffer: SharedBufferData::AlphaMap {
+                                            data: data.clone(),
+                                            width: stride,
                                         },
-                                    );
+                                        source_rect: PhysicalRect::new(
+                                            PhysicalPoint::new(actual_x as _, actual_y as _),
+                                            geometry.size,
+                                        ),
+                                        colorize: color,
+                                        // color already is mixed with global alpha
+                                        alpha: color.alpha(),
+                                    };
+                                    match gradient {
+                                        Some(gradient) => self.process_with_gradient(
+                                            geometry,
+                                            gradient,
+                                            EffectSource::SharedBuffer(command(
+                                                Color::from_rgb_u8(255, 255, 255),
+                                            )),
+                                        ),
+                                        None => self
+                                            .process_shared_image_buffer(geometry, command(color)),
+                                    }
                                 }
                             };
                         }
@@ -1409,6 +1661,101 @@ fn draw_text_paragraph<'b, Font: AbstractFont>(
             .ok();
     }
 
+    /// Draws a premultiplied pixmap with its top left corner at `origin` (in physical pixels
+    /// relative to the current offset), clipped by the current clip.
+    fn draw_pixmap(
+        &mut self,
+        pixmap: SharedImageBuffer,
+        origin: euclid::Vector2D<f32, PhysicalPx>,
+    ) {
+        let physical_clip = self.current_state.clip.cast() * self.scale_factor;
+        let src_rect =
+            euclid::rect(0., 0., pixmap.width() as f32, pixmap.height() as f32).translate(origin);
+
+        if let Some(clipped_src) = src_rect.intersection(&physical_clip) {
+            let offset = self.current_state.offset.to_vector().cast() * self.scale_factor;
+            let geometry = clipped_src.translate(offset).round();
+            let source_origin = ((geometry.origin - offset.round()) - src_rect.origin).round();
+            let geometry = geometry.cast();
+
+            self.process_shared_image_buffer(
+                geometry,
+                SharedBufferCommand {
+                    buffer: SharedBufferData::SharedImage(pixmap),
+                    source_rect: PhysicalRect::new(source_origin.to_point().cast(), geometry.size),
+                    colorize: Default::default(),
+                    alpha: (self.current_state.alpha * 255.) as u8,
+                },
+            );
+        }
+    }
+
+    /// Draws a rectangle with a border where the background or the border is a gradient.
+    ///
+    /// The shape is drawn with the background in the red channel and the border in the green
+    /// channel, which are then used as the coverage of each brush.
+    fn draw_border_rectangle_with_gradient(
+        &mut self,
+        geom: LogicalRect,
+        radius: LogicalLength,
+        border: LogicalLength,
+        (background, background_gradient): (&Brush, Option<GradientFill>),
+        (border_brush, border_gradient): (&Brush, Option<GradientFill>),
+    ) {
+        let clipped = match geom.intersection(&self.current_state.clip) {
+            Some(clipped) => clipped,
+            None => return,
+        };
+        let radius = radius
+            .max(LogicalLength::zero())
+            .min(geom.width_length() / 2 as Coord)
+            .min(geom.height_length() / 2 as Coord);
+        let geom2 = geom.cast() * self.scale_factor;
+        let clipped2 = clipped.cast() * self.scale_factor;
+        let geometry: PhysicalRect =
+            (clipped.translate(self.current_state.offset.to_vector()).cast() * self.scale_factor)
+                .round()
+                .cast();
+        if geometry.is_empty() {
+            return;
+        }
+        // Add a small value to make sure that the clip is always positive despite floating point shenanigans
+        const E: f32 = 0.00001;
+        let shape = RoundedRectangle {
+            radius: (radius.cast() * self.scale_factor).cast(),
+            width: (border.cast() * self.scale_factor).cast(),
+            border_color: PremultipliedRgbaColor { red: 0, green: 255, blue: 0, alpha: 255 },
+            inner_color: PremultipliedRgbaColor { red: 255, green: 0, blue: 0, alpha: 255 },
+            top_clip: PhysicalLength::new((clipped2.min_y() - geom2.min_y() + E) as _),
+            bottom_clip: PhysicalLength::new((geom2.max_y() - clipped2.max_y() + E) as _),
+            left_clip: PhysicalLength::new((clipped2.min_x() - geom2.min_x() + E) as _),
+            right_clip: PhysicalLength::new((geom2.max_x() - clipped2.max_x() + E) as _),
+        };
+        let background_color = PremultipliedRgbaColor::from(self.alpha_color(background.color()));
+        let border_color = if border.get() as f32 > 0.01 {
+            PremultipliedRgbaColor::from(self.alpha_color(border_brush.color()))
+        } else {
+            PremultipliedRgbaColor::default()
+        };
+        self.process_effect(
+            geometry,
+            EffectSource::RoundedRectangle(shape),
+            EffectPaint::BorderRectangle {
+                background: (background_color, background_gradient),
+                border: (border_color, border_gradient),
+            },
+        );
+    }
+
+    /// Returns the gradient of the brush covering `rect`, or None if the brush is a plain color
+    fn gradient_fill(&self, brush: &Brush, rect: LogicalRect) -> Option<GradientFill> {
+        GradientFill::new(
+            brush,
+            rect.translate(self.current_state.offset.to_vector()).cast() * self.scale_factor,
+            self.current_state.alpha,
+        )
+    }
+
     /// Returns the color, mixed with the current_state's alpha
     fn alpha_color(&self, color: Color) -> Color {
         if self.current_state.alpha < 1.0 {
@@ -1430,11 +1777,554 @@ struct SelectionInfo {
     selection: core::ops::Range<usize>,
 }
 
-#[derive(Clone, Copy)]
+#[derive(Clone)]
 struct RenderState {
     alpha: f32,
     offset: LogicalPoint,
     clip: LogicalRect,
+    /// The rounded clips that are active, in addition to `clip`
+    rounded_clip: Option<Rc<RoundedClip>>,
+    /// When set, `offset` and `clip` are in the coordinate system of that rotation
+    rotation: Option<RotationState>,
+}
+
+#[derive(Clone)]
+struct RotationState {
+    /// The total angle of the rotation, clockwise
+    angle_in_degrees: f32,
+    /// The point, in window coordinates, around which the rotation is done
+    pivot: LogicalPoint,
+    /// The clip in window coordinates that was active when the rotation started
+    window_clip: LogicalRect,
+}
+
+impl RotationState {
+    fn sin_cos(&self) -> (f32, f32) {
+        let angle = positive_remainder(self.angle_in_degrees, 360.);
+        if self.is_right_angle() {
+            // avoid floating point imprecision for the common cases
+            match (angle / 90.).round() as i32 % 4 {
+                0 => (0., 1.),
+                1 => (1., 0.),
+                2 => (0., -1.),
+                _ => (-1., 0.),
+            }
+        } else {
+            angle.to_radians().sin_cos()
+        }
+    }
+
+    /// Returns true if the angle is a multiple of 90 degrees
+    fn is_right_angle(&self) -> bool {
+        let angle = positive_remainder(self.angle_in_degrees, 90.);
+        !(0.001..=90. - 0.001).contains(&angle)
+    }
+
+    fn physical(&self, scale_factor: ScaleFactor) -> PhysicalRotation {
+        let (sin, cos) = self.sin_cos();
+        PhysicalRotation {
+            pivot: self.pivot.cast() * scale_factor,
+            sin,
+            cos,
+            right_angle: self.is_right_angle(),
+        }
+    }
+
+    /// Maps a point in the rotated coordinate system to the window coordinates
+    fn map_to_window(&self, point: LogicalPoint) -> LogicalPoint {
+        let (sin, cos) = self.sin_cos();
+        let (x, y) = (point.x as f32, point.y as f32);
+        self.pivot + euclid::vec2((x * cos - y * sin) as Coord, (x * sin + y * cos) as Coord)
+    }
+}
+
+/// A rotated coordinate system, in physical pixels
+#[derive(Clone, Copy)]
+struct PhysicalRotation {
+    /// The origin of the rotated coordinate system, in window coordinates
+    pivot: euclid::Point2D<f32, PhysicalPx>,
+    sin: f32,
+    cos: f32,
+    /// True if the angle is a multiple of 90 degrees
+    right_angle: bool,
+}
+
+impl PhysicalRotation {
+    /// Maps a point in window coordinates to the rotated coordinate system
+    fn map_from_window(&self, x: f32, y: f32) -> (f32, f32) {
+        let (x, y) = (x - self.pivot.x, y - self.pivot.y);
+        (x * self.cos + y * self.sin, -x * self.sin + y * self.cos)
+    }
+}
+
+/// The command drawn by an [`EffectCommand`]
+enum EffectSource {
+    Rectangle(PremultipliedRgbaColor),
+    Texture(SceneTexture<'static>),
+    SharedBuffer(SharedBufferCommand),
+    RoundedRectangle(RoundedRectangle),
+    /// The pixels of the gradient are computed the first time one of them is sampled
+    Gradient(GradientCommand, RefCell<Vec<PremultipliedRgbaColor>>),
+}
+
+impl EffectSource {
+    /// Draws the line of the command covering `span` in the line buffer
+    fn draw_line(
+        &self,
+        span: &PhysicalRect,
+        line: PhysicalLength,
+        line_buffer: &mut [PremultipliedRgbaColor],
+    ) {
+        match self {
+            Self::Rectangle(color) => TargetPixel::blend_slice(
+                &mut line_buffer[span.min_x() as usize..span.max_x() as usize],
+                *color,
+            ),
+            Self::Texture(texture) => {
+                draw_functions::draw_texture_line(span, line, texture, line_buffer)
+            }
+            Self::SharedBuffer(buffer) => {
+                draw_functions::draw_texture_line(span, line, &buffer.as_texture(), line_buffer)
+            }
+            Self::RoundedRectangle(rr) => {
+                draw_functions::draw_rounded_rectangle_line(span, line, rr, line_buffer)
+            }
+            Self::Gradient(gradient, _) => {
+                draw_functions::draw_gradient_line(span, line, gradient, line_buffer)
+            }
+        }
+    }
+
+    /// Returns the pixel (x, y) of the command drawn in a rectangle of the given size at the origin
+    fn pixel(&self, size: PhysicalSize, x: i32, y: i32) -> PremultipliedRgbaColor {
+        if x < 0 || y < 0 || x >= size.width as i32 || y >= size.height as i32 {
+            return PremultipliedRgbaColor::default();
+        }
+        match self {
+            Self::Rectangle(color) => *color,
+            Self::Texture(texture) => {
+                draw_functions::texture_pixel(size, texture, x as usize, y as usize)
+            }
+            Self::SharedBuffer(buffer) => {
+                draw_functions::texture_pixel(size, &buffer.as_texture(), x as usize, y as usize)
+            }
+            Self::RoundedRectangle(rr) => rounded_rectangle_pixel(size, rr, x, y),
+            Self::Gradient(_, pixels) => {
+                // The gradient is only drawn line by line, so draw all its lines once
+                let width = size.width as usize;
+                let mut pixels = pixels.borrow_mut();
+                if pixels.is_empty() {
+                    pixels.resize(width * size.height as usize, Default::default());
+                    for (line, buffer) in pixels.chunks_mut(width).enumerate() {
+                        self.draw_line(
+                            &PhysicalRect::from_size(size),
+                            PhysicalLength::new(line as i16),
+                            buffer,
+                        );
+                    }
+                }
+                pixels[y as usize * width + x as usize]
+            }
+        }
+    }
+}
+
+/// Returns the pixel (x, y) of the rounded rectangle drawn in a rectangle of the given size,
+/// computed from the distance to its edges.
+fn rounded_rectangle_pixel(
+    size: PhysicalSize,
+    rr: &RoundedRectangle,
+    x: i32,
+    y: i32,
+) -> PremultipliedRgbaColor {
+    // The whole shape, of which `size` is only the part that is not clipped
+    let rect = euclid::rect::<f32, PhysicalPx>(
+        -rr.left_clip.get() as f32,
+        -rr.top_clip.get() as f32,
+        (size.width + rr.left_clip.get() + rr.right_clip.get()) as f32,
+        (size.height + rr.top_clip.get() + rr.bottom_clip.get()) as f32,
+    );
+    let (x, y) = (x as f32 + 0.5, y as f32 + 0.5);
+    let radius = rr.radius.get() as f32;
+    let outer = (0.5 - rounded_rectangle_distance(&rect, radius, x, y)).clamp(0., 1.);
+    if outer <= 0. {
+        return PremultipliedRgbaColor::default();
+    }
+    let border = rr.width.get() as f32;
+    let inner = if border > 0. {
+        let inner_rect = rect.inflate(-border, -border);
+        let inner_radius = (radius - border).max(0.);
+        (0.5 - rounded_rectangle_distance(&inner_rect, inner_radius, x, y)).clamp(0., 1.)
+    } else {
+        outer
+    };
+    let mix = |inner_color: u8, border_color: u8| {
+        (inner_color as f32 * inner + border_color as f32 * (outer - inner)).round() as u8
+    };
+    let (i, b) = (rr.inner_color, rr.border_color);
+    PremultipliedRgbaColor {
+        red: mix(i.red, b.red),
+        green: mix(i.green, b.green),
+        blue: mix(i.blue, b.blue),
+        alpha: mix(i.alpha, b.alpha),
+    }
+}
+
+/// How the pixels of the source of an [`EffectCommand`] are colored
+enum EffectPaint {
+    /// The pixels of the source are used as they are
+    Source,
+    /// The alpha of the source is the coverage of the gradient
+    Gradient(GradientFill),
+    /// The source is a rounded rectangle with the coverage of the background in the red channel
+    /// and the coverage of the border in the green channel. The brushes are either a color or
+    /// a gradient.
+    BorderRectangle {
+        background: (PremultipliedRgbaColor, Option<GradientFill>),
+        border: (PremultipliedRgbaColor, Option<GradientFill>),
+    },
+}
+
+impl EffectPaint {
+    /// Returns the color of the source pixel, at (x, y) in physical pixels, once painted
+    fn apply(&self, pixel: PremultipliedRgbaColor, x: f32, y: f32) -> PremultipliedRgbaColor {
+        match self {
+            Self::Source => pixel,
+            Self::Gradient(gradient) => {
+                scale_color(gradient.color_at(x, y), pixel.alpha as f32 / 255.)
+            }
+            Self::BorderRectangle { background, border } => {
+                let b = background.1.as_ref().map_or(background.0, |g| g.color_at(x, y));
+                let c = border.1.as_ref().map_or(border.0, |g| g.color_at(x, y));
+                let (inner_coverage, border_coverage) = (pixel.red as u32, pixel.green as u32);
+                // The border is blended over the background
+                let border_alpha = c.alpha as u32;
+                let mix = |b: u8, c: u8| {
+                    let border = c as u32 + b as u32 * (255 - border_alpha) / 255;
+                    ((b as u32 * inner_coverage + border * border_coverage) / 255).min(255) as u8
+                };
+                PremultipliedRgbaColor {
+                    red: mix(b.red, c.red),
+                    green: mix(b.green, c.green),
+                    blue: mix(b.blue, c.blue),
+                    alpha: mix(b.alpha, c.alpha),
+                }
+            }
+        }
+    }
+}
+
+/// A command that is painted, masked by rounded clips, or rotated.
+///
+/// The effects are computed line by line when the command is drawn, so they don't need more
+/// memory than one line of the command.
+struct EffectCommand {
+    source: EffectSource,
+    /// The geometry of the source, in physical pixels, in the rotated coordinate system if any
+    source_geometry: PhysicalRect,
+    paint: EffectPaint,
+    /// The rounded clips that mask the command
+    clip: Option<Rc<RoundedClip>>,
+    /// The rotation of the coordinate system of the source
+    rotation: Option<PhysicalRotation>,
+}
+
+impl EffectCommand {
+    /// Draws the line of the command that covers `span`, in window coordinates.
+    ///
+    /// `line_buffer` starts at x = `buffer_x`, and `scratch` is a buffer that can be reused between calls.
+    fn draw_line(
+        &self,
+        span: &PhysicalRect,
+        line: PhysicalLength,
+        scratch: &mut Vec<PremultipliedRgbaColor>,
+        line_buffer: &mut [impl TargetPixel],
+        buffer_x: i16,
+    ) {
+        let y = line.get() as f32 + 0.5;
+        let mut blend = |x: i16, color: PremultipliedRgbaColor| {
+            let color = match &self.clip {
+                Some(clip) => scale_color(color, clip.coverage(x as f32 + 0.5, y)),
+                None => color,
+            };
+            if color.alpha > 0 {
+                line_buffer[(x - buffer_x) as usize].blend(color);
+            }
+        };
+
+        match &self.rotation {
+            None => {
+                // Without rotation, the span is the source geometry
+                scratch.clear();
+                scratch.resize(span.width() as usize, PremultipliedRgbaColor::default());
+                self.source.draw_line(
+                    &PhysicalRect::new(euclid::point2(0, span.min_y()), span.size),
+                    line,
+                    scratch,
+                );
+                for (x, pixel) in span.x_range().zip(scratch.iter()) {
+                    if pixel.alpha > 0 {
+                        blend(x, self.paint.apply(*pixel, x as f32 + 0.5, y));
+                    }
+                }
+            }
+            Some(rotation) => {
+                let size = self.source_geometry.size;
+                let origin = self.source_geometry.origin.cast::<f32>();
+                for x in span.x_range() {
+                    // map the center of the target pixel back into the source
+                    let (sx, sy) = rotation.map_from_window(x as f32 + 0.5, y);
+                    let (local_x, local_y) = (sx - origin.x, sy - origin.y);
+                    let pixel = if rotation.right_angle {
+                        self.source.pixel(size, local_x.floor() as i32, local_y.floor() as i32)
+                    } else {
+                        // bilinear interpolation between the four closest pixels
+                        let (local_x, local_y) = (local_x - 0.5, local_y - 0.5);
+                        let (x0, y0) = (local_x.floor(), local_y.floor());
+                        let (fx, fy) = (local_x - x0, local_y - y0);
+                        let (x0, y0) = (x0 as i32, y0 as i32);
+                        let samples = [
+                            (self.source.pixel(size, x0, y0), (1. - fx) * (1. - fy)),
+                            (self.source.pixel(size, x0 + 1, y0), fx * (1. - fy)),
+                            (self.source.pixel(size, x0, y0 + 1), (1. - fx) * fy),
+                            (self.source.pixel(size, x0 + 1, y0 + 1), fx * fy),
+                        ];
+                        let interpolate = |component: fn(&PremultipliedRgbaColor) -> u8| {
+                            samples
+                                .iter()
+                                .map(|(c, w)| component(c) as f32 * w)
+                                .sum::<f32>()
+                                .round() as u8
+                        };
+                        PremultipliedRgbaColor {
+                            red: interpolate(|c| c.red),
+                            green: interpolate(|c| c.green),
+                            blue: interpolate(|c| c.blue),
+                            alpha: interpolate(|c| c.alpha),
+                        }
+                    };
+                    if pixel.alpha > 0 {
+                        blend(x, self.paint.apply(pixel, sx, sy));
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Multiplies all the components of the color by `factor`, between 0 and 1
+fn scale_color(color: PremultipliedRgbaColor, factor: f32) -> PremultipliedRgbaColor {
+    if factor >= 1. {
+        return color;
+    }
+    let scale = |c: u8| (c as f32 * factor).round() as u8;
+    PremultipliedRgbaColor {
+        red: scale(color.red),
+        green: scale(color.green),
+        blue: scale(color.blue),
+        alpha: scale(color.alpha),
+    }
+}
+
+/// Signed distance between the point and the edge of the rounded rectangle (negative inside)
+fn rounded_rectangle_distance(
+    rect: &euclid::Rect<f32, PhysicalPx>,
+    radius: f32,
+    x: f32,
+    y: f32,
+) -> f32 {
+    let half_width = rect.width() / 2.;
+    let half_height = rect.height() / 2.;
+    let center = rect.center();
+    let qx = (x - center.x).abs() - (half_width - radius);
+    let qy = (y - center.y).abs() - (half_height - radius);
+    (qx.max(0.) * qx.max(0.) + qy.max(0.) * qy.max(0.)).sqrt() + qx.max(qy).min(0.) - radius
+}
+
+/// The geometry of a [`GradientFill`], in physical pixels
+#[derive(Clone)]
+enum GradientShape {
+    /// The gradient goes from `start` (position 0) to `start + direction` (position 1)
+    Linear { start: euclid::Point2D<f32, PhysicalPx>, direction: euclid::Vector2D<f32, PhysicalPx> },
+    /// The gradient goes from `center` (position 0) to the circle of the given radius (position 1)
+    Radial { center: euclid::Point2D<f32, PhysicalPx>, radius: f32 },
+}
+
+/// A gradient brush covering a rectangle, which can be queried pixel per pixel
+#[derive(Clone)]
+struct GradientFill {
+    shape: GradientShape,
+    /// The position and the color of the stops, with the opacity already applied
+    stops: Vec<(f32, PremultipliedRgbaColor)>,
+}
+
+impl GradientFill {
+    /// Returns None if the brush is not a gradient
+    fn new(brush: &Brush, rect: euclid::Rect<f32, PhysicalPx>, alpha: f32) -> Option<Self> {
+        let collect_stops = |stops: &mut dyn Iterator<Item = &crate::graphics::GradientStop>| {
+            stops
+                .map(|s| {
+                    let color = s.color;
+                    let alpha = (color.alpha() as f32 * alpha) as u8;
+                    let color =
+                        Color::from_argb_u8(alpha, color.red(), color.green(), color.blue());
+                    (s.position, PremultipliedRgbaColor::from(color))
+                })
+                .collect::<Vec<_>>()
+        };
+        let (shape, stops) = match brush {
+            Brush::SolidColor(_) => return None,
+            Brush::LinearGradient(g) => {
+                let (start, end) = crate::graphics::line_for_angle(g.angle());
+                let map = |p: euclid::Point2D<f32, euclid::UnknownUnit>| {
+                    rect.origin + euclid::vec2(p.x * rect.width(), p.y * rect.height())
+                };
+                let start = map(start);
+                (
+                    GradientShape::Linear { start, direction: map(end) - start },
+                    collect_stops(&mut g.stops()),
+                )
+            }
+            Brush::RadialGradient(g) => (
+                GradientShape::Radial {
+                    center: rect.center(),
+                    radius: rect.width().max(rect.height()) / 2.,
+                },
+                collect_stops(&mut g.stops()),
+            ),
+        };
+        Some(Self { shape, stops })
+    }
+
+    /// Returns the color of the gradient at the given position in physical pixels
+    fn color_at(&self, x: f32, y: f32) -> PremultipliedRgbaColor {
+        let position = match self.shape {
+            GradientShape::Linear { start, direction } => {
+                let length = direction.square_length();
+                if length > 0. {
+                    (euclid::point2(x, y) - start).dot(direction) / length
+                } else {
+                    0.
+                }
+            }
+            GradientShape::Radial { center, radius } => {
+                if radius > 0. {
+                    (euclid::point2(x, y) - center).length() / radius
+                } else {
+                    0.
+                }
+            }
+        };
+
+        let mut previous = match self.stops.first() {
+            Some(first) if position > first.0 => *first,
+            Some(first) => return first.1,
+            None => return PremultipliedRgbaColor::default(),
+        };
+        for stop in &self.stops[1..] {
+            if position <= stop.0 {
+                let range = stop.0 - previous.0;
+                if range <= 0. {
+                    return stop.1;
+                }
+                let t = (position - previous.0) / range;
+                let mix = |a: u8, b: u8| (a as f32 + (b as f32 - a as f32) * t).round() as u8;
+                let (a, b) = (previous.1, stop.1);
+                return PremultipliedRgbaColor {
+                    red: mix(a.red, b.red),
+                    green: mix(a.green, b.green),
+                    blue: mix(a.blue, b.blue),
+                    alpha: mix(a.alpha, b.alpha),
+                };
+            }
+            previous = *stop;
+        }
+        previous.1
+    }
+
+    /// Blends the gradient on the pixels of the line `y`, for the given range of x.
+    /// The `line_buffer` starts at x = `x_range.start`.
+    fn blend_line(
+        &self,
+        y: i16,
+        x_range: core::ops::Range<i16>,
+        line_buffer: &mut [impl TargetPixel],
+    ) {
+        for (x, pixel) in x_range.zip(line_buffer.iter_mut()) {
+            pixel.blend(self.color_at(x as f32 + 0.5, y as f32 + 0.5));
+        }
+    }
+}
+
+/// A rounded rectangle outside of which nothing is drawn
+struct RoundedClip {
+    /// The rectangle, in physical pixels, in the coordinate system of `rotation` if any
+    rect: euclid::Rect<f32, PhysicalPx>,
+    radius: f32,
+    /// The rotation of the coordinate system in which the clip was set
+    rotation: Option<PhysicalRotation>,
+    /// The rounded clip of an enclosing element, if any
+    parent: Option<Rc<RoundedClip>>,
+}
+
+impl RoundedClip {
+    /// Returns the proportion of the pixel whose center is at (x, y), in window coordinates,
+    /// that is within all the clips
+    fn coverage(&self, x: f32, y: f32) -> f32 {
+        let (local_x, local_y) = match &self.rotation {
+            Some(rotation) => rotation.map_from_window(x, y),
+            None => (x, y),
+        };
+        let distance = rounded_rectangle_distance(&self.rect, self.radius, local_x, local_y);
+        let coverage = (0.5 - distance).clamp(0., 1.);
+        match &self.parent {
+            Some(parent) if coverage > 0. => coverage * parent.coverage(x, y),
+            _ => coverage,
+        }
+    }
+
+    /// Returns true if all the pixels of the rectangle, in window coordinates, are entirely within all the clips.
+    fn contains(&self, rect: &PhysicalRect) -> bool {
+        if self.rotation.is_some() {
+            return false;
+        }
+        // Since a rounded rectangle is convex, it is enough to check the corners, with
+        // a margin of half a pixel to account for the anti-aliasing
+        let rect = rect.cast::<f32>();
+        [
+            rect.min(),
+            rect.max(),
+            euclid::point2(rect.min_x(), rect.max_y()),
+            euclid::point2(rect.max_x(), rect.min_y()),
+        ]
+        .iter()
+        .all(|p| rounded_rectangle_distance(&self.rect, self.radius, p.x, p.y) <= -0.5)
+            && self.parent.as_ref().map_or(true, |parent| parent.contains(&rect.cast()))
+    }
+}
+
+/// Same as `f32::rem_euclid`, which is not available without std
+fn positive_remainder(value: f32, modulo: f32) -> f32 {
+    let r = value % modulo;
+    if r < 0. {
+        r + modulo
+    } else {
+        r
+    }
+}
+
+/// Returns the bounding box of `rect` once rotated by the angle of which `sin` and `cos` are given.
+fn rotated_bounding_box<U>(rect: euclid::Rect<f32, U>, sin: f32, cos: f32) -> euclid::Rect<f32, U> {
+    let rotate_point = |p: euclid::Point2D<f32, U>| {
+        euclid::Point2D::<f32, U>::new(p.x * cos - p.y * sin, p.x * sin + p.y * cos)
+    };
+    euclid::Box2D::from_points([
+        rotate_point(rect.min()),
+        rotate_point(euclid::point2(rect.max_x(), rect.min_y())),
+        rotate_point(euclid::point2(rect.min_x(), rect.max_y())),
+        rotate_point(rect.max()),
+    ])
+    .to_rect()
 }
 
 impl<'a, T: ProcessScene> crate::item_rendering::ItemRenderer for SceneBuilder<'a, T> {
@@ -1452,7 +2342,8 @@ fn draw_rectangle(
             };
 
             let background = rect.background();
-            if let Brush::LinearGradient(g) = background {
+            // The line based gradients can't be rotated, so use the generic gradient code in that case
+            if let (Brush::LinearGradient(g), None) = (&background, &self.current_state.rotation) {
                 let geom2 = geom.cast() * self.scale_factor;
                 let clipped2 = clipped.cast() * self.scale_factor;
                 let act_rect = (clipped.translate(self.current_state.offset.to_vector()).cast()
@@ -1543,24 +2434,31 @@ fn draw_rectangle(
                         continue;
                     }
 
-                    self.processor.process_gradient(act_rect, gr);
+                    self.process_gradient(act_rect, gr);
                 }
                 return;
             }
 
-            // FIXME: gradients
+            let geometry = (clipped.translate(self.current_state.offset.to_vector()).cast()
+                * self.scale_factor)
+                .round()
+                .cast();
+
+            if let Some(gradient) = self.gradient_fill(&background, geom) {
+                self.process_with_gradient(
+                    geometry,
+                    &gradient,
+                    EffectSource::Rectangle(Color::from_rgb_u8(255, 255, 255).into()),
+                );
+                return;
+            }
+
             let color = self.alpha_color(background.color());
 
             if color.alpha() == 0 {
                 return;
             }
-            self.processor.process_rectangle(
-                (clipped.translate(self.current_state.offset.to_vector()).cast()
-                    * self.scale_factor)
-                    .round()
-                    .cast(),
-                color.into(),
-            );
+            self.process_rectangle(geometry, color.into());
         }
     }
 
@@ -1574,10 +2472,25 @@ fn draw_border_rectangle(
         if self.should_draw(&geom) {
             let mut border = rect.border_width();
             let radius = rect.border_radius();
-            // FIXME: gradients
-            let color = self.alpha_color(rect.background().color());
+            let background = rect.background();
+            let border_brush = rect.border_color();
+            let background_gradient = self.gradient_fill(&background, geom);
+            let border_gradient =
+                self.gradient_fill(&border_brush, geom).filter(|_| border.get() as f32 > 0.01);
+            if background_gradient.is_some() || border_gradient.is_some() {
+                self.draw_border_rectangle_with_gradient(
+                    geom,
+                    radius,
+                    border,
+                    (&background, background_gradient),
+                    (&border_brush, border_gradient),
+                );
+                return;
+            }
+
+            let color = self.alpha_color(background.color());
             let border_color = if border.get() as f32 > 0.01 {
-                self.alpha_color(rect.border_color().color())
+                self.alpha_color(border_brush.color())
             } else {
                 Color::default()
             };
@@ -1615,7 +2528,7 @@ fn draw_border_rectangle(
                     let clipped2 = clipped.cast() * self.scale_factor;
                     // Add a small value to make sure that the clip is always positive despite floating point shenanigans
                     const E: f32 = 0.00001;
-                    self.processor.process_rounded_rectangle(
+                    self.process_rounded_rectangle(
                         (clipped.translate(self.current_state.offset.to_vector()).cast()
                             * self.scale_factor)
                             .round()
@@ -1648,7 +2561,7 @@ fn draw_border_rectangle(
                     .inflate(-border.get(), -border.get())
                     .intersection(&self.current_state.clip)
                 {
-                    self.processor.process_rectangle(
+                    self.process_rectangle(
                         (r.translate(self.current_state.offset.to_vector()).cast()
                             * self.scale_factor)
                             .round()
@@ -1658,11 +2571,10 @@ fn draw_border_rectangle(
                 }
             }
 
-            // FIXME: gradients
             if border_color.alpha > 0 {
                 let mut add_border = |r: LogicalRect| {
                     if let Some(r) = r.intersection(&self.current_state.clip) {
-                        self.processor.process_rectangle(
+                        self.process_rectangle(
                             (r.translate(self.current_state.offset.to_vector()).cast()
                                 * self.scale_factor)
                                 .round()
@@ -1739,7 +2651,9 @@ fn draw_text(&mut self, text: Pin<&crate::items::Text>, _: &ItemRc, size: Logica
 
         let font_request = text.font_request(self.window);
 
-        let color = self.alpha_color(text.color().color());
+        let brush = text.color();
+        let color = self.alpha_color(brush.color());
+        let gradient = self.gradient_fill(&brush, geom);
         let max_size = (geom.size.cast() * self.scale_factor).cast();
 
         // Clip glyphs not only against the global clip but also against the Text's geometry to avoid drawing outside
@@ -1771,7 +2685,14 @@ fn draw_text(&mut self, text: Pin<&crate::items::Text>, _: &ItemRc, size: Logica
                     single_line: false,
                 };
 
-                self.draw_text_paragraph(&paragraph, physical_clip, offset, color, None);
+                self.draw_text_paragraph(
+                    &paragraph,
+                    physical_clip,
+                    offset,
+                    color,
+                    gradient.as_ref(),
+                    None,
+                );
             }
             #[cfg(feature = "software-renderer-systemfonts")]
             fonts::Font::VectorFont(vf) => {
@@ -1789,7 +2710,14 @@ fn draw_text(&mut self, text: Pin<&crate::items::Text>, _: &ItemRc, size: Logica
                     single_line: false,
                 };
 
-                self.draw_text_paragraph(&paragraph, physical_clip, offset, color, None);
+                self.draw_text_paragraph(
+                    &paragraph,
+                    physical_clip,
+                    offset,
+                    color,
+                    gradient.as_ref(),
+                    None,
+                );
             }
         }
     }
@@ -1807,7 +2735,9 @@ fn draw_text_input(
 
         let font_request = text_input.font_request(&self.window.window_adapter());
 
-        let color = self.alpha_color(text_input.color().color());
+        let brush = text_input.color();
+        let color = self.alpha_color(brush.color());
+        let gradient = self.gradient_fill(&brush, geom);
         let max_size = (geom.size.cast() * self.scale_factor).cast();
 
         // Clip glyphs not only against the global clip but also against the Text's geometry to avoid drawing outside
@@ -1846,7 +2776,14 @@ fn draw_text_input(
                     single_line: text_input.single_line(),
                 };
 
-                self.draw_text_paragraph(&paragraph, physical_clip, offset, color, selection);
+                self.draw_text_paragraph(
+                    &paragraph,
+                    physical_clip,
+                    offset,
+                    color,
+                    gradient.as_ref(),
+                    selection,
+                );
 
                 text_visual_representation.cursor_position.map(|cursor_offset| {
                     (paragraph.cursor_pos_for_byte_offset(cursor_offset), pf.height())
@@ -1866,7 +2803,14 @@ fn draw_text_input(
                     single_line: text_input.single_line(),
                 };
 
-                self.draw_text_paragraph(&paragraph, physical_clip, offset, color, selection);
+                self.draw_text_paragraph(
+                    &paragraph,
+                    physical_clip,
+                    offset,
+                    color,
+                    gradient.as_ref(),
+                    selection,
+                );
 
                 text_visual_representation.cursor_position.map(|cursor_offset| {
                     (paragraph.cursor_pos_for_byte_offset(cursor_offset), vf.height())
@@ -1884,43 +2828,191 @@ fn draw_text_input(
             );
 
             if let Some(clipped_src) = cursor_rect.intersection(&physical_clip.cast()) {
-                self.processor
-                    .process_rectangle(clipped_src.translate(offset.cast()), color.into());
+                let geometry = clipped_src.translate(offset.cast());
+                match &gradient {
+                    Some(gradient) => self.process_with_gradient(
+                        geometry,
+                        gradient,
+                        EffectSource::Rectangle(Color::from_rgb_u8(255, 255, 255).into()),
+                    ),
+                    None => self.process_rectangle(geometry, color.into()),
+                }
             }
         }
     }
 
     #[cfg(feature = "std")]
-    fn draw_path(&mut self, _path: Pin<&crate::items::Path>, _: &ItemRc, _size: LogicalSize) {
-        // TODO
+    fn draw_path(&mut self, path: Pin<&crate::items::Path>, _: &ItemRc, size: LogicalSize) {
+        let geom = LogicalRect::from(size);
+        if !self.should_draw(&geom) {
+            return;
+        }
+
+        let (offset, path_events) = match path.fitted_path_events() {
+            Some(offset_and_events) => offset_and_events,
+            None => return,
+        };
+
+        let physical_clip = match self.current_state.clip.intersection(&geom) {
+            Some(logical_clip) => logical_clip.cast() * self.scale_factor,
+            None => return,
+        };
+        let global_offset = self.current_state.offset.to_vector().cast() * self.scale_factor;
+        let geometry: PhysicalRect = physical_clip.translate(global_offset).round().cast();
+        if geometry.is_empty() {
+            return;
+        }
+
+        // Maps the coordinate of the path events to the coordinates in the alpha map
+        let origin = geometry.origin.cast::<f32>() - global_offset;
+        let transform =
+            lyon_path::math::Transform::scale(self.scale_factor.get(), self.scale_factor.get())
+                .then_translate(lyon_path::math::vector(
+                    offset.x as f32 * self.scale_factor.get() - origin.x,
+                    offset.y as f32 * self.scale_factor.get() - origin.y,
+                ));
+
+        let fill = path.fill();
+        let stroke = path.stroke();
+        let stroke_width = (path.stroke_width().cast() * self.scale_factor).get();
+
+        let mut draw_alpha_map = |data: alloc::vec::Vec<u8>, brush: &Brush| {
+            let command = |color: Color| SharedBufferCommand {
+                buffer: SharedBufferData::AlphaMap {
+                    data: data.into(),
+                    width: geometry.width() as u16,
+                },
+                source_rect: PhysicalRect::new(PhysicalPoint::default(), geometry.size),
+                colorize: color,
+                // color already is mixed with global alpha
+                alpha: color.alpha(),
+            };
+            match self.gradient_fill(brush, geom) {
+                Some(gradient) => self.process_with_gradient(
+                    geometry,
+                    &gradient,
+                    EffectSource::SharedBuffer(command(Color::from_rgb_u8(255, 255, 255))),
+                ),
+                None => {
+                    let color = self.alpha_color(brush.color());
+                    self.process_shared_image_buffer(geometry, command(color))
+                }
+            }
+        };
+
+        if !fill.is_transparent() {
+            let mut rasterizer =
+                path::Rasterizer::new(geometry.width() as usize, geometry.height() as usize);
+            rasterizer.add_fill(path_events.iter(), &transform);
+            draw_alpha_map(rasterizer.rasterize(path.fill_rule()), &fill);
+        }
+
+        if !stroke.is_transparent() && stroke_width > 0. {
+            let mut rasterizer =
+                path::Rasterizer::new(geometry.width() as usize, geometry.height() as usize);
+            rasterizer.add_stroke(path_events.iter(), &transform, stroke_width);
+            draw_alpha_map(rasterizer.rasterize(crate::items::FillRule::Nonzero), &stroke);
+        }
     }
 
     fn draw_box_shadow(
         &mut self,
-        _box_shadow: Pin<&crate::items::BoxShadow>,
+        box_shadow: Pin<&crate::items::BoxShadow>,
         _: &ItemRc,
         _size: LogicalSize,
     ) {
-        // TODO
+        let offset = LogicalPoint::from_lengths(box_shadow.offset_x(), box_shadow.offset_y())
+            .cast()
+            * self.scale_factor;
+        if offset.x == 0. && offset.y == 0. && box_shadow.blur() <= LogicalLength::zero() {
+            return;
+        }
+
+        let color = self.alpha_color(box_shadow.color());
+        if color.alpha() == 0 {
+            return;
+        }
+
+        let shadow_options = match BoxShadowOptions::new(box_shadow, self.scale_factor) {
+            Some(shadow_options) => shadow_options,
+            None => return,
+        };
+        let blur = shadow_options.blur.get().max(0.);
+
+        let buffer = self.box_shadow_cache.get_or_insert_with(shadow_options, |shadow_options| {
+            let (data, width, _) = box_shadow::render_box_shadow(shadow_options);
+            SharedBufferData::AlphaMap { data: data.into(), width: width as u16 }
+        });
+        let (width, height) = match &buffer {
+            SharedBufferData::AlphaMap { data, width } => {
+                (*width as usize, data.len() / (*width as usize).max(1))
+            }
+            SharedBufferData::SharedImage(image) => {
+                (image.width() as usize, image.height() as usize)
+            }
+        };
+
+        let global_offset = self.current_state.offset.to_vector().cast() * self.scale_factor;
+        let shadow_rect = PhysicalRect::new(
+            (offset + global_offset - euclid::vec2(blur, blur)).round().cast(),
+            euclid::size2(width as i16, height as i16),
+        );
+        let physical_clip: PhysicalRect = ((self.current_state.clip.cast() * self.scale_factor)
+            .translate(global_offset))
+        .round()
+        .cast();
+
+        if let Some(geometry) = shadow_rect.intersection(&physical_clip) {
+            self.process_shared_image_buffer(
+                geometry,
+                SharedBufferCommand {
+                    buffer,
+                    source_rect: geometry.translate(-shadow_rect.origin.to_vector()),
+                    colorize: color,
+                    // color already is mixed with global alpha
+                    alpha: color.alpha(),
+                },
+            );
+        }
     }
 
     fn combine_clip(
         &mut self,
         other: LogicalRect,
-        _radius: LogicalLength,
-        _border_width: LogicalLength,
+        radius: LogicalLength,
+        border_width: LogicalLength,
     ) -> bool {
+        // Like the other renderers, the clip is in the middle of the border
+        let border_width = border_width
+            .min(other.size.width_length().min(other.size.height_length()) / 2 as Coord);
+        let other =
+            other.inflate(-border_width.get() / 2 as Coord, -border_width.get() / 2 as Coord);
         match self.current_state.clip.intersection(&other) {
             Some(r) => {
                 self.current_state.clip = r;
-                true
             }
             None => {
                 self.current_state.clip = LogicalRect::default();
-                false
+                return false;
             }
         }
-        // TODO: handle radius and border
+        let radius = radius.get().min(other.width() / 2 as Coord).min(other.height() / 2 as Coord);
+        if radius > 0 as Coord {
+            let rect = (other.translate(self.current_state.offset.to_vector()).cast()
+                * self.scale_factor)
+                .cast();
+            self.current_state.rounded_clip = Some(Rc::new(RoundedClip {
+                rect,
+                radius: radius as f32 * self.scale_factor.get(),
+                rotation: self
+                    .current_state
+                    .rotation
+                    .as_ref()
+                    .map(|rotation| rotation.physical(self.scale_factor)),
+                parent: self.current_state.rounded_clip.take(),
+            }));
+        }
+        true
     }
 
     fn get_current_clip(&self) -> LogicalRect {
@@ -1932,8 +3024,40 @@ fn translate(&mut self, distance: LogicalVector) {
         self.current_state.clip = self.current_state.clip.translate(-distance)
     }
 
-    fn rotate(&mut self, _angle_in_degrees: f32) {
-        todo!()
+    fn rotate(&mut self, angle_in_degrees: f32) {
+        if positive_remainder(angle_in_degrees, 360.) == 0. {
+            return;
+        }
+        // The rounded clips stay in `current_state.rounded_clip`: they know the coordinate system
+        // in which they were set.
+        let (pivot, window_clip, total_angle) = match &self.current_state.rotation {
+            None => (
+                self.current_state.offset,
+                self.current_state.clip.translate(self.current_state.offset.to_vector()),
+                angle_in_degrees,
+            ),
+            Some(rotation) => (
+                rotation.map_to_window(self.current_state.offset),
+                rotation.window_clip,
+                rotation.angle_in_degrees + angle_in_degrees,
+            ),
+        };
+        // The clip in the rotated coordinate system must not depend on the dirty region, otherwise
+        // the intermediate rendering (and thus the interpolation) would differ between partial
+        // and full renderings. So start from the bounding box of the whole window, and only
+        // keep the clip of the outer rotation, which is already independent of the dirty region.
+        let rotation = RotationState { angle_in_degrees: total_angle, pivot, window_clip };
+        let (sin, cos) = rotation.sin_cos();
+        let window_rect = self.window_rect.translate(-pivot.to_vector());
+        let mut clip = rotated_bounding_box(window_rect.cast(), -sin, cos).cast();
+        if self.current_state.rotation.is_some() {
+            let (sin, cos) = angle_in_degrees.to_radians().sin_cos();
+            let outer_clip = rotated_bounding_box(self.current_state.clip.cast(), -sin, cos);
+            clip = clip.intersection(&outer_clip.cast()).unwrap_or_default();
+        }
+        self.current_state.clip = clip;
+        self.current_state.offset = LogicalPoint::default();
+        self.current_state.rotation = Some(rotation);
     }
 
     fn apply_opacity(&mut self, opacity: f32) {
@@ -1941,7 +3065,7 @@ fn apply_opacity(&mut self, opacity: f32) {
     }
 
     fn save_state(&mut self) {
-        self.state_stack.push(self.current_state);
+        self.state_stack.push(self.current_state.clone());
     }
 
     fn restore_state(&mut self) {
@@ -1954,40 +3078,101 @@ fn scale_factor(&self) -> f32 {
 
     fn draw_cached_pixmap(
         &mut self,
-        _item: &ItemRc,
+        item: &ItemRc,
         update_fn: &dyn Fn(&mut dyn FnMut(u32, u32, &[u8])),
     ) {
-        // FIXME: actually cache the pixmap
-        update_fn(&mut |width, height, data| {
-            let img = SharedImageBuffer::RGBA8Premultiplied(SharedPixelBuffer::clone_from_slice(
-                data, width, height,
-            ));
-
-            let physical_clip = self.current_state.clip.cast() * self.scale_factor;
-            let src_rect = euclid::rect(0., 0., width as f32, height as f32);
-
-            if let Some(clipped_src) = src_rect.intersection(&physical_clip) {
-                let offset = self.current_state.offset.to_vector().cast() * self.scale_factor;
-                let geometry = clipped_src.translate(offset).round();
-                let origin = (geometry.origin - offset.round()).cast::<usize>();
-                let actual_x = origin.x - src_rect.origin.x as usize;
-                let actual_y = origin.y - src_rect.origin.y as usize;
-                let geometry = geometry.cast();
-
-                self.processor.process_shared_image_buffer(
-                    geometry,
-                    SharedBufferCommand {
-                        buffer: SharedBufferData::SharedImage(img),
-                        source_rect: PhysicalRect::new(
-                            PhysicalPoint::new(actual_x as _, actual_y as _),
-                            geometry.size,
-                        ),
-                        colorize: Default::default(),
-                        alpha: (self.current_state.alpha * 255.) as u8,
-                    },
-                );
+        let pixmap = self.layer_cache.get_or_update(item, self.scale_factor.get(), || {
+            let mut pixmap = None;
+            update_fn(&mut |width, height, data| {
+                pixmap = Some(SharedImageBuffer::RGBA8Premultiplied(
+                    SharedPixelBuffer::clone_from_slice(data, width, height),
+                ));
+            });
+            pixmap
+        });
+        if let Some(pixmap) = pixmap {
+            self.draw_pixmap(pixmap, euclid::Vector2D::zero());
+        }
+    }
+
+    fn visit_layer(
+        &mut self,
+        layer: Pin<&crate::items::Layer>,
+        self_rc: &ItemRc,
+        size: LogicalSize,
+    ) -> RenderingResult {
+        if !layer.cache_rendering_hint() {
+            self.layer_cache.release(self_rc);
+            return RenderingResult::ContinueRenderingChildren;
+        }
+
+        // The layer must contain the children that are outside of the geometry of the layer.
+        // Clip them to the window rather than the current clip, so that the pixmap doesn't
+        // depend on the dirty region.
+        let window_clip = self.window_rect.translate(-self.current_state.offset.to_vector());
+        let layer_rect = crate::properties::evaluate_no_tracking(|| {
+            LogicalRect::from(size).union(&crate::item_rendering::item_children_bounding_rect(
+                &self_rc.component(),
+                self_rc.index() as isize,
+                &window_clip,
+            ))
+        });
+        let physical_rect: PhysicalRect =
+            (layer_rect.cast() * self.scale_factor).round_out().cast();
+        let scale_factor = self.scale_factor;
+        let window = self.window;
+        let box_shadow_cache = self.box_shadow_cache;
+        let layer_cache = self.layer_cache;
+        let partial_cache = self.partial_cache;
+
+        let pixmap = self.layer_cache.get_or_update(self_rc, scale_factor.get(), || {
+            if physical_rect.is_empty() {
+                return None;
+            }
+            let (width, height) = (physical_rect.width() as usize, physical_rect.height() as usize);
+            let mut buffer = vec![PremultipliedRgbaColor::default(); width * height];
+            let layer_renderer = SceneBuilder::new(
+                physical_rect.size,
+                scale_factor,
+                window,
+                RenderToBuffer { buffer: &mut buffer, stride: width },
+                box_shadow_cache,
+                layer_cache,
+                partial_cache,
+            );
+            // Render the children through a PartialRenderer so that they get an entry in the
+            // partial rendering cache, otherwise compute_dirty_regions would consider them dirty
+            // on every frame.
+            let mut layer_renderer = crate::item_rendering::PartialRenderer::new(
+                partial_cache,
+                Default::default(),
+                layer_renderer,
+            );
+            let origin = physical_rect.origin.cast::<f32>() / scale_factor;
+            layer_renderer.translate(-origin.to_vector().cast::<Coord>());
+            crate::item_rendering::render_item_children(
+                &mut layer_renderer,
+                &self_rc.component(),
+                self_rc.index() as isize,
+            );
+
+            let mut pixmap =
+                SharedPixelBuffer::<crate::graphics::Rgba8Pixel>::new(width as u32, height as u32);
+            for (pixel, color) in pixmap.make_mut_slice().iter_mut().zip(buffer) {
+                *pixel = crate::graphics::Rgba8Pixel {
+                    r: color.red,
+                    g: color.green,
+                    b: color.blue,
+                    a: color.alpha,
+                };
             }
+            Some(SharedImageBuffer::RGBA8Premultiplied(pixmap))
         });
+
+        if let Some(pixmap) = pixmap {
+            self.draw_pixmap(pixmap, physical_rect.origin.to_vector().cast());
+        }
+        RenderingResult::ContinueRenderingWithoutChildren
     }
 
     fn draw_string(&mut self, _string: &str, _color: Color) {
@@ -2079,3 +3264,84 @@ fn deref(&self) -> &Self::Target {
         &self.window
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn gradient_effect_pixels() {
+        let color = |red: u8, blue: u8| PremultipliedRgbaColor { red, green: 0, blue, alpha: 255 };
+        let source = EffectSource::Gradient(
+            GradientCommand {
+                color1: color(255, 0),
+                color2: color(0, 255),
+                start: 0,
+                flags: 0,
+                left_clip: PhysicalLength::zero(),
+                right_clip: PhysicalLength::zero(),
+                top_clip: PhysicalLength::zero(),
+                bottom_clip: PhysicalLength::zero(),
+            },
+            Default::default(),
+        );
+        let size = PhysicalSize::new(3, 8);
+        for y in 0..8 {
+            let mut line = vec![PremultipliedRgbaColor::default(); 3];
+            source.draw_line(&PhysicalRect::from_size(size), PhysicalLength::new(y), &mut line);
+            for x in 0..3 {
+                let (pixel, expected) = (source.pixel(size, x, y as i32), line[x as usize]);
+                assert_eq!(
+                    (pixel.red, pixel.green, pixel.blue, pixel.alpha),
+                    (expected.red, expected.green, expected.blue, expected.alpha)
+                );
+            }
+        }
+        assert_eq!(source.pixel(size, 3, 0).alpha, 0);
+    }
+
+    #[test]
+    fn rounded_clip_in_nested_rotation() {
+        let window = MinimalSoftwareWindow::new(RepaintBufferType::NewBuffer);
+        let renderer = SoftwareRenderer::new_without_window(RepaintBufferType::NewBuffer);
+        let size = PhysicalSize::new(40, 40);
+        let mut buffer = vec![PremultipliedRgbaColor::default(); 40 * 40];
+        let mut builder = SceneBuilder::new(
+            size,
+            ScaleFactor::new(1.),
+            WindowInner::from_pub(window.window()),
+            RenderToBuffer { buffer: &mut buffer, stride: 40 },
+            &renderer.box_shadow_cache,
+            &renderer.layer_cache,
+            &renderer.partial_cache,
+        );
+        builder.translate(LogicalVector::new(20 as Coord, 20 as Coord));
+        builder.rotate(30.);
+        // A circle of radius 10 around the pivot, set in the rotated coordinate system
+        builder.combine_clip(
+            LogicalRect::new(
+                LogicalPoint::new(-10 as Coord, -10 as Coord),
+                LogicalSize::new(20 as Coord, 20 as Coord),
+            ),
+            LogicalLength::new(10 as Coord),
+            LogicalLength::zero(),
+        );
+        builder.rotate(30.);
+        let red = PremultipliedRgbaColor { red: 255, green: 0, blue: 0, alpha: 255 };
+        builder.process_rectangle(
+            PhysicalRect::new(euclid::point2(-30, -30), euclid::size2(60, 60)),
+            red,
+        );
+        drop(builder);
+
+        let alpha = |x: usize, y: usize| buffer[y * 40 + x].alpha;
+        assert_eq!(alpha(20, 20), 255);
+        assert_eq!(alpha(20, 14), 255);
+        assert_eq!(alpha(26, 20), 255);
+        // Outside of the circle set between the two rotations
+        assert_eq!(alpha(20, 5), 0);
+        assert_eq!(alpha(35, 20), 0);
+        assert_eq!(alpha(2, 2), 0);
+        assert_eq!(alpha(38, 38), 0);
+    }
+}
@@ -0,0 +1,123 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-1.0 OR LicenseRef-Slint-commercial
+
+//! This module renders the alpha map of a blurred box shadow
+
+use crate::graphics::boxshadowcache::BoxShadowOptions;
+use alloc::vec::Vec;
+#[allow(unused)]
+use num_traits::Float;
+
+/// Number of box blur passes used to approximate the gaussian blur
+const BLUR_PASSES: usize = 3;
+
+/// Renders the alpha map of the shadow described by `options`.
+///
+/// The rounded rectangle is placed at (blur, blur) so that the blurred shadow fits in the map.
+/// Returns the data and the width and height of the alpha map.
+pub(super) fn render_box_shadow(options: &BoxShadowOptions) -> (Vec<u8>, usize, usize) {
+    let blur = options.blur.get().max(0.);
+    let rect_width = options.width.get();
+    let rect_height = options.height.get();
+    let width = (rect_width + 2. * blur).ceil() as usize;
+    let height = (rect_height + 2. * blur).ceil() as usize;
+
+    let radius = options.radius.get().min(rect_width / 2.).min(rect_height / 2.).max(0.);
+    let (half_width, half_height) = (rect_width / 2., rect_height / 2.);
+    let (center_x, center_y) = (blur + half_width, blur + half_height);
+
+    let mut data = alloc::vec![0u8; width * height];
+    for (y, line) in data.chunks_exact_mut(width).enumerate() {
+        let qy = (y as f32 + 0.5 - center_y).abs() - (half_height - radius);
+        for (x, pixel) in line.iter_mut().enumerate() {
+            let qx = (x as f32 + 0.5 - center_x).abs() - (half_width - radius);
+            // signed distance to the rounded rectangle
+            let distance = (qx.max(0.) * qx.max(0.) + qy.max(0.) * qy.max(0.)).sqrt()
+                + qx.max(qy).min(0.)
+                - radius;
+            *pixel = ((0.5 - distance).clamp(0., 1.) * 255.).round() as u8;
+        }
+    }
+
+    // Same convention as skia's blur mask filter
+    let sigma = blur / 2.;
+    if sigma > 0. {
+        let mut scratch = alloc::vec![0u8; width.max(height)];
+        for box_size in box_sizes_for_gauss(sigma) {
+            let box_radius = (box_size - 1) / 2;
+            for line in data.chunks_exact_mut(width) {
+                box_blur(line, 1, width, box_radius, &mut scratch);
+            }
+            for x in 0..width {
+                box_blur(&mut data[x..], width, height, box_radius, &mut scratch);
+            }
+        }
+    }
+
+    (data, width, height)
+}
+
+/// Returns the size of the boxes of the box blur passes that approximate a gaussian blur with the
+/// given standard deviation.
+fn box_sizes_for_gauss(sigma: f32) -> [usize; BLUR_PASSES] {
+    let n = BLUR_PASSES as f32;
+    let ideal_width = (12. * sigma * sigma / n + 1.).sqrt();
+    let mut lower_width = ideal_width.floor() as usize;
+    if lower_width % 2 == 0 {
+        lower_width = lower_width.saturating_sub(1).max(1);
+    }
+    let wl = lower_width as f32;
+    let m = ((12. * sigma * sigma - n * wl * wl - 4. * n * wl - 3. * n) / (-4. * wl - 4.)).round();
+    let mut sizes = [lower_width; BLUR_PASSES];
+    for (i, size) in sizes.iter_mut().enumerate() {
+        if i as f32 >= m {
+            *size = lower_width + 2;
+        }
+    }
+    sizes
+}
+
+/// Blurs `len` values of `data` separated by `stride` with a box of the given radius.
+/// The values outside of the range are considered to be 0.
+fn box_blur(data: &mut [u8], stride: usize, len: usize, radius: usize, scratch: &mut [u8]) {
+    if radius == 0 {
+        return;
+    }
+    let scratch = &mut scratch[..len];
+    for (i, s) in scratch.iter_mut().enumerate() {
+        *s = data[i * stride];
+    }
+    let divisor = (2 * radius + 1) as u32;
+    let mut sum: u32 = scratch[..radius.min(len)].iter().map(|v| *v as u32).sum();
+    for i in 0..len {
+        if i + radius < len {
+            sum += scratch[i + radius] as u32;
+        }
+        if i > radius {
+            sum -= scratch[i - radius - 1] as u32;
+        }
+        data[i * stride] = ((sum + divisor / 2) / divisor) as u8;
+    }
+}
+
+#[test]
+fn box_shadow_blur() {
+    use crate::lengths::PhysicalPx;
+    let options = BoxShadowOptions {
+        width: euclid::Length::<f32, PhysicalPx>::new(10.),
+        height: euclid::Length::new(10.),
+        color: crate::Color::from_rgb_u8(0, 0, 0),
+        blur: euclid::Length::new(4.),
+        radius: euclid::Length::new(0.),
+    };
+    let (data, width, height) = render_box_shadow(&options);
+    assert_eq!((width, height), (18, 18));
+    // The center is still opaque, the corners are fully transparent, and it fades in between
+    assert_eq!(data[9 * width + 9], 255);
+    assert_eq!(data[0], 0);
+    let middle_of_edge = data[9 * width + 4];
+    assert!(middle_of_edge > 64 && middle_of_edge < 192, "{middle_of_edge}");
+    // symmetric
+    assert_eq!(data[9 * width + 4], data[9 * width + 13]);
+    assert_eq!(data[4 * width + 9], data[9 * width + 4]);
+}
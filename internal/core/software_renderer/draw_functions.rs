@@ -18,7 +18,7 @@ pub(super) fn draw_texture_line(
     texture: &super::SceneTexture,
     line_buffer: &mut [impl TargetPixel],
 ) {
-    let super::SceneTexture { data, format, stride, source_size, color, alpha } = *texture;
+    let super::SceneTexture { data, format, stride, source_size, alpha, .. } = *texture;
     let source_size = source_size.cast::<usize>();
     let span_size = span.size.cast::<usize>();
     let bpp = format.bpp();
@@ -31,58 +31,75 @@ pub(super) fn draw_texture_line(
         .enumerate()
     {
         let pos = y_pos + (x * source_size.width / span_size.width) * bpp;
-        let c = match format {
-            PixelFormat::Rgb => {
-                let p = &data[pos..pos + 3];
-                if alpha == 0xff {
-                    *pix = TargetPixel::from_rgb(p[0], p[1], p[2]);
-                    continue;
-                } else {
-                    PremultipliedRgbaColor::premultiply(Color::from_argb_u8(
-                        alpha, p[0], p[1], p[2],
-                    ))
+        if format == PixelFormat::Rgb && alpha == 0xff {
+            let p = &data[pos..pos + 3];
+            *pix = TargetPixel::from_rgb(p[0], p[1], p[2]);
+            continue;
+        }
+        pix.blend(texel(texture, pos));
+    }
+}
+
+/// Returns the pixel (x, y) of the texture drawn in a span of the given size
+pub(super) fn texture_pixel(
+    span_size: super::PhysicalSize,
+    texture: &super::SceneTexture,
+    x: usize,
+    y: usize,
+) -> PremultipliedRgbaColor {
+    let source_size = texture.source_size.cast::<usize>();
+    let span_size = span_size.cast::<usize>();
+    let pos = (y * source_size.height / span_size.height) * texture.stride as usize
+        + (x * source_size.width / span_size.width) * texture.format.bpp();
+    texel(texture, pos)
+}
+
+/// Returns the color of the texel at the byte position `pos` in the texture's data
+fn texel(texture: &super::SceneTexture, pos: usize) -> PremultipliedRgbaColor {
+    let super::SceneTexture { data, format, color, alpha, .. } = *texture;
+    match format {
+        PixelFormat::Rgb => {
+            let p = &data[pos..pos + 3];
+            PremultipliedRgbaColor::premultiply(Color::from_argb_u8(alpha, p[0], p[1], p[2]))
+        }
+        PixelFormat::Rgba => {
+            let alpha = ((data[pos + 3] as u16 * alpha as u16) / 255) as u8;
+            PremultipliedRgbaColor::premultiply(if color.alpha() == 0 {
+                Color::from_argb_u8(alpha, data[pos + 0], data[pos + 1], data[pos + 2])
+            } else {
+                Color::from_argb_u8(alpha, color.red(), color.green(), color.blue())
+            })
+        }
+        PixelFormat::RgbaPremultiplied => {
+            if color.alpha() > 0 {
+                PremultipliedRgbaColor::premultiply(Color::from_argb_u8(
+                    ((data[pos + 3] as u16 * alpha as u16) / 255) as u8,
+                    color.red(),
+                    color.green(),
+                    color.blue(),
+                ))
+            } else if alpha == 0xff {
+                PremultipliedRgbaColor {
+                    alpha: data[pos + 3],
+                    red: data[pos + 0],
+                    green: data[pos + 1],
+                    blue: data[pos + 2],
                 }
-            }
-            PixelFormat::Rgba => {
-                let alpha = ((data[pos + 3] as u16 * alpha as u16) / 255) as u8;
-                PremultipliedRgbaColor::premultiply(if color.alpha() == 0 {
-                    Color::from_argb_u8(alpha, data[pos + 0], data[pos + 1], data[pos + 2])
-                } else {
-                    Color::from_argb_u8(alpha, color.red(), color.green(), color.blue())
-                })
-            }
-            PixelFormat::RgbaPremultiplied => {
-                if color.alpha() > 0 {
-                    PremultipliedRgbaColor::premultiply(Color::from_argb_u8(
-                        ((data[pos + 3] as u16 * alpha as u16) / 255) as u8,
-                        color.red(),
-                        color.green(),
-                        color.blue(),
-                    ))
-                } else if alpha == 0xff {
-                    PremultipliedRgbaColor {
-                        alpha: data[pos + 3],
-                        red: data[pos + 0],
-                        green: data[pos + 1],
-                        blue: data[pos + 2],
-                    }
-                } else {
-                    PremultipliedRgbaColor {
-                        alpha: (data[pos + 3] as u16 * alpha as u16 / 255) as u8,
-                        red: (data[pos + 0] as u16 * alpha as u16 / 255) as u8,
-                        green: (data[pos + 1] as u16 * alpha as u16 / 255) as u8,
-                        blue: (data[pos + 2] as u16 * alpha as u16 / 255) as u8,
-                    }
+            } else {
+                PremultipliedRgbaColor {
+                    alpha: (data[pos + 3] as u16 * alpha as u16 / 255) as u8,
+                    red: (data[pos + 0] as u16 * alpha as u16 / 255) as u8,
+                    green: (data[pos + 1] as u16 * alpha as u16 / 255) as u8,
+                    blue: (data[pos + 2] as u16 * alpha as u16 / 255) as u8,
                 }
             }
-            PixelFormat::AlphaMap => PremultipliedRgbaColor::premultiply(Color::from_argb_u8(
-                ((data[pos] as u16 * alpha as u16) / 255) as u8,
-                color.red(),
-                color.green(),
-                color.blue(),
-            )),
-        };
-        pix.blend(c);
+        }
+        PixelFormat::AlphaMap => PremultipliedRgbaColor::premultiply(Color::from_argb_u8(
+            ((data[pos] as u16 * alpha as u16) / 255) as u8,
+            color.red(),
+            color.green(),
+            color.blue(),
+        )),
     }
 }
 
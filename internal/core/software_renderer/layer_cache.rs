@@ -0,0 +1,216 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-1.0 OR LicenseRef-Slint-commercial
+
+//! This module contains the cache of the pixmaps of the layers (the elements with
+//! `cache-rendering-hint: true`) rendered by the software renderer
+
+use crate::graphics::{CachedGraphicsData, SharedImageBuffer};
+use crate::items::ItemRc;
+use alloc::collections::BTreeMap;
+use core::cell::{Cell, RefCell};
+
+/// The default memory budget of the cache, in bytes
+pub(super) const DEFAULT_BUDGET: usize = 512 * 1024;
+
+/// The key of an entry: the pointer to the component, and the index of the item within it
+type Key = (*const vtable::Dyn, usize);
+
+struct Entry {
+    pixmap: CachedGraphicsData<Option<SharedImageBuffer>>,
+    /// The scale factor with which the pixmap was rendered
+    scale_factor: f32,
+    /// The value of [`LayerCache::usage_counter`] the last time the entry was used
+    last_used: u64,
+}
+
+impl Entry {
+    fn size_in_bytes(&self) -> usize {
+        self.pixmap.data.as_ref().map_or(0, |image| {
+            image.width() as usize * image.height() as usize * image_bytes_per_pixel(image)
+        })
+    }
+}
+
+fn image_bytes_per_pixel(image: &SharedImageBuffer) -> usize {
+    match image {
+        SharedImageBuffer::RGB8(_) => 3,
+        SharedImageBuffer::RGBA8(_) | SharedImageBuffer::RGBA8Premultiplied(_) => 4,
+    }
+}
+
+/// Cache of the rendered pixmaps, keyed by item.
+///
+/// The entries are invalidated when the properties that were accessed while rendering them change.
+/// When the size of the cached pixmaps exceeds the budget, the least recently used entries are evicted.
+pub(super) struct LayerCache {
+    entries: RefCell<BTreeMap<Key, Entry>>,
+    budget: Cell<usize>,
+    usage_counter: Cell<u64>,
+}
+
+impl Default for LayerCache {
+    fn default() -> Self {
+        Self {
+            entries: Default::default(),
+            budget: Cell::new(DEFAULT_BUDGET),
+            usage_counter: Default::default(),
+        }
+    }
+}
+
+impl LayerCache {
+    /// Returns the pixmap of the item from the cache if it is still valid. Otherwise, call
+    /// `update_fn` to render it, tracking the properties it accesses.
+    pub fn get_or_update(
+        &self,
+        item_rc: &ItemRc,
+        scale_factor: f32,
+        update_fn: impl FnOnce() -> Option<SharedImageBuffer>,
+    ) -> Option<SharedImageBuffer> {
+        self.get_or_update_with_key(item_key(item_rc), scale_factor, update_fn)
+    }
+
+    fn get_or_update_with_key(
+        &self,
+        key: Key,
+        scale_factor: f32,
+        update_fn: impl FnOnce() -> Option<SharedImageBuffer>,
+    ) -> Option<SharedImageBuffer> {
+        let usage = self.usage_counter.get() + 1;
+        self.usage_counter.set(usage);
+
+        // Take the entry out of the map while rendering, since rendering may access the cache
+        // for the nested layers
+        let existing = self.entries.borrow_mut().remove(&key);
+        let mut entry = match existing {
+            Some(mut entry) if entry.scale_factor == scale_factor => {
+                if let Some(tracker) = entry.pixmap.dependency_tracker.as_ref() {
+                    if let Some(pixmap) = tracker.as_ref().evaluate_if_dirty(update_fn) {
+                        entry.pixmap.data = pixmap;
+                    }
+                }
+                entry
+            }
+            _ => Entry { pixmap: CachedGraphicsData::new(update_fn), scale_factor, last_used: 0 },
+        };
+        entry.last_used = usage;
+        let result = entry.pixmap.data.clone();
+
+        let budget = self.budget.get();
+        let size = entry.size_in_bytes();
+        if size <= budget {
+            let mut entries = self.entries.borrow_mut();
+            evict_least_recently_used(&mut entries, budget - size);
+            entries.insert(key, entry);
+        }
+        result
+    }
+
+    /// Removes the pixmap of the item from the cache
+    pub fn release(&self, item_rc: &ItemRc) {
+        self.entries.borrow_mut().remove(&item_key(item_rc));
+    }
+
+    /// Removes all the pixmaps of the items of that component
+    pub fn component_destroyed(&self, component: crate::component::ComponentRef) {
+        let component_ptr: *const _ =
+            crate::component::ComponentRef::as_ptr(component).cast().as_ptr();
+        self.entries.borrow_mut().retain(|(c, _), _| *c != component_ptr);
+    }
+
+    /// Sets the memory budget and evicts the entries that don't fit anymore
+    pub fn set_budget(&self, budget: usize) {
+        self.budget.set(budget);
+        evict_least_recently_used(&mut self.entries.borrow_mut(), budget);
+    }
+}
+
+fn item_key(item_rc: &ItemRc) -> Key {
+    (&(*item_rc.component()) as *const _, item_rc.index())
+}
+
+/// Removes the least recently used entries until the total size of the pixmaps fits in `budget`
+fn evict_least_recently_used(entries: &mut BTreeMap<Key, Entry>, budget: usize) {
+    let mut total: usize = entries.values().map(Entry::size_in_bytes).sum();
+    while total > budget {
+        let Some((key, size)) =
+            entries.iter().min_by_key(|(_, e)| e.last_used).map(|(k, e)| (*k, e.size_in_bytes()))
+        else {
+            break;
+        };
+        entries.remove(&key);
+        total -= size;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graphics::{Rgba8Pixel, SharedPixelBuffer};
+
+    fn key(index: usize) -> Key {
+        (core::ptr::null(), index)
+    }
+
+    fn pixmap(width: u32, height: u32) -> Option<SharedImageBuffer> {
+        Some(SharedImageBuffer::RGBA8Premultiplied(SharedPixelBuffer::<Rgba8Pixel>::new(
+            width, height,
+        )))
+    }
+
+    #[test]
+    fn cache_hit() {
+        let cache = LayerCache::default();
+        let property = Box::pin(crate::Property::new(10));
+        let render_count = Cell::new(0);
+        let render = |cache: &LayerCache| {
+            cache
+                .get_or_update_with_key(key(1), 1., || {
+                    render_count.set(render_count.get() + 1);
+                    pixmap(property.as_ref().get(), 10)
+                })
+                .unwrap()
+                .width()
+        };
+        assert_eq!(render(&cache), 10);
+        assert_eq!(render(&cache), 10);
+        assert_eq!(render_count.get(), 1);
+        property.as_ref().set(20);
+        assert_eq!(render(&cache), 20);
+        assert_eq!(render(&cache), 20);
+        assert_eq!(render_count.get(), 2);
+
+        // A different scale factor must render again
+        let result = cache.get_or_update_with_key(key(1), 2., || pixmap(40, 20));
+        assert_eq!(result.unwrap().width(), 40);
+    }
+
+    #[test]
+    fn budget_eviction() {
+        let cache = LayerCache::default();
+        // Each pixmap uses 10 * 10 * 4 = 400 bytes
+        cache.set_budget(1000);
+        cache.get_or_update_with_key(key(1), 1., || pixmap(10, 10));
+        cache.get_or_update_with_key(key(2), 1., || pixmap(10, 10));
+        // Use the first entry again so that the second one is the least recently used
+        cache.get_or_update_with_key(key(1), 1., || unreachable!());
+        cache.get_or_update_with_key(key(3), 1., || pixmap(10, 10));
+        {
+            let entries = cache.entries.borrow();
+            assert!(entries.contains_key(&key(1)));
+            assert!(!entries.contains_key(&key(2)));
+            assert!(entries.contains_key(&key(3)));
+        }
+
+        // An entry that doesn't fit in the budget is not cached
+        cache.get_or_update_with_key(key(4), 1., || pixmap(20, 20));
+        assert!(!cache.entries.borrow().contains_key(&key(4)));
+
+        cache.set_budget(500);
+        assert_eq!(cache.entries.borrow().len(), 1);
+        assert!(cache.entries.borrow().contains_key(&key(3)));
+
+        cache.set_budget(0);
+        assert!(cache.entries.borrow().is_empty());
+    }
+}
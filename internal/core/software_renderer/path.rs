@@ -0,0 +1,321 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-1.0 OR LicenseRef-Slint-commercial
+
+//! This module contains a small scanline rasterizer that converts the events of a `Path`
+//! element into an anti-aliased alpha map that can then be drawn by the software renderer.
+
+use crate::items::FillRule;
+use alloc::vec::Vec;
+use lyon_path::iterator::PathIterator;
+use lyon_path::math::{Point, Transform, Vector};
+use lyon_path::PathEvent;
+#[allow(unused)]
+use num_traits::Float;
+
+/// Number of sub-scanlines sampled for each line of pixels (vertical anti-aliasing).
+/// The horizontal anti-aliasing is computed from the exact coverage of each span.
+const SUB_SCANLINES: usize = 4;
+
+/// Maximum distance between a curve and its flattened approximation, in physical pixels.
+const TOLERANCE: f32 = 0.1;
+
+#[derive(Clone, Copy, Debug)]
+struct Edge {
+    /// top point (`y0 < y1`)
+    x0: f32,
+    y0: f32,
+    /// bottom point
+    x1: f32,
+    y1: f32,
+    /// +1 if the edge was going down, -1 if it was going up
+    winding: i8,
+}
+
+/// A flattened sub path, in physical pixel coordinates
+struct Polyline {
+    points: Vec<Point>,
+    closed: bool,
+}
+
+/// Flatten the path events into polylines, after applying the transform
+fn flatten(events: impl Iterator<Item = PathEvent>, transform: &Transform) -> Vec<Polyline> {
+    let mut result = Vec::new();
+    let mut current: Option<Polyline> = None;
+    for event in events.transformed(transform).flattened(TOLERANCE) {
+        match event {
+            PathEvent::Begin { at } => {
+                result.extend(current.take());
+                current = Some(Polyline { points: alloc::vec![at], closed: false });
+            }
+            PathEvent::Line { to, .. } => {
+                if let Some(current) = current.as_mut() {
+                    if current.points.last() != Some(&to) {
+                        current.points.push(to);
+                    }
+                }
+            }
+            PathEvent::End { close, .. } => {
+                if let Some(mut polyline) = current.take() {
+                    polyline.closed = close;
+                    result.push(polyline);
+                }
+            }
+            // flattened() only produces lines
+            PathEvent::Quadratic { .. } | PathEvent::Cubic { .. } => unreachable!(),
+        }
+    }
+    result.extend(current);
+    result
+}
+
+/// Accumulate the edges of a path and produce an alpha map (one byte per pixel) out of them.
+pub(super) struct Rasterizer {
+    edges: Vec<Edge>,
+    width: usize,
+    height: usize,
+}
+
+impl Rasterizer {
+    /// Creates a rasterizer for an alpha map of the given size in physical pixels.
+    pub fn new(width: usize, height: usize) -> Self {
+        Self { edges: Vec::new(), width, height }
+    }
+
+    fn add_line(&mut self, from: Point, to: Point) {
+        // Non-finite coordinates come from degenerate paths or transforms, and can't be rasterized
+        let is_finite = |p: Point| p.x.is_finite() && p.y.is_finite();
+        if from.y == to.y || !is_finite(from) || !is_finite(to) {
+            return;
+        }
+        let (top, bottom, winding) = if from.y < to.y { (from, to, 1) } else { (to, from, -1) };
+        if bottom.y <= 0. || top.y >= self.height as f32 {
+            return;
+        }
+        self.edges.push(Edge { x0: top.x, y0: top.y, x1: bottom.x, y1: bottom.y, winding });
+    }
+
+    /// Add a closed polygon
+    fn add_polygon(&mut self, points: &[Point]) {
+        for (i, p) in points.iter().enumerate() {
+            self.add_line(*p, points[(i + 1) % points.len()]);
+        }
+    }
+
+    /// Add the interior of the path described by `events` (transformed with `transform`).
+    /// Every sub path is implicitly closed.
+    pub fn add_fill(&mut self, events: impl Iterator<Item = PathEvent>, transform: &Transform) {
+        for polyline in flatten(events, transform) {
+            if polyline.points.len() > 2 {
+                self.add_polygon(&polyline.points);
+            }
+        }
+    }
+
+    /// Add the outline of the path described by `events` (transformed with `transform`),
+    /// using round joins and butt caps.
+    ///
+    /// All the shapes that compose the stroke have the same orientation, so the result must
+    /// be rasterized with [`FillRule::Nonzero`] to get their union.
+    pub fn add_stroke(
+        &mut self,
+        events: impl Iterator<Item = PathEvent>,
+        transform: &Transform,
+        width: f32,
+    ) {
+        let half_width = width / 2.;
+        if half_width <= 0. {
+            return;
+        }
+        // Enough segments so that the approximation of the round join stays within the tolerance
+        let join_segments = (core::f32::consts::PI
+            / (1. - TOLERANCE / half_width.max(TOLERANCE)).acos().max(0.01))
+        .ceil()
+        .clamp(4., 64.) as usize;
+        let join_polygon = (0..join_segments)
+            .map(|i| {
+                // Clockwise, to match the orientation of the segment quads
+                let angle = -(i as f32) * 2. * core::f32::consts::PI / join_segments as f32;
+                Vector::new(angle.cos() * half_width, angle.sin() * half_width)
+            })
+            .collect::<Vec<_>>();
+
+        let mut polygon = Vec::with_capacity(join_segments);
+        for polyline in flatten(events, transform) {
+            let points = &polyline.points;
+            let segment_count = if polyline.closed { points.len() } else { points.len() - 1 };
+            for i in 0..segment_count {
+                let a = points[i];
+                let b = points[(i + 1) % points.len()];
+                let d = b - a;
+                let len = d.length();
+                if len <= 0. {
+                    continue;
+                }
+                let n = Vector::new(-d.y, d.x) * (half_width / len);
+                self.add_polygon(&[a + n, b + n, b - n, a - n]);
+
+                let is_join = polyline.closed || i + 1 < segment_count;
+                if is_join {
+                    polygon.clear();
+                    polygon.extend(join_polygon.iter().map(|v| b + *v));
+                    self.add_polygon(&polygon);
+                }
+            }
+        }
+    }
+
+    /// Compute the coverage of every pixel and return the alpha map, with `width` bytes per line.
+    pub fn rasterize(mut self, fill_rule: FillRule) -> Vec<u8> {
+        let width = self.width;
+        let mut result = alloc::vec![0u8; width * self.height];
+        if self.edges.is_empty() || width == 0 {
+            return result;
+        }
+
+        self.edges.sort_unstable_by(|a, b| a.y0.total_cmp(&b.y0));
+
+        let mut coverage = alloc::vec![0f32; width];
+        let mut active: Vec<Edge> = Vec::new();
+        let mut crossings: Vec<(f32, i8)> = Vec::new();
+        let mut next_edge = 0;
+
+        for (row, line) in result.chunks_exact_mut(width).enumerate() {
+            if next_edge >= self.edges.len() && active.is_empty() {
+                break;
+            }
+            coverage.fill(0.);
+            let mut touched = false;
+
+            for sub in 0..SUB_SCANLINES {
+                let y = row as f32 + (sub as f32 + 0.5) / SUB_SCANLINES as f32;
+                while next_edge < self.edges.len() && self.edges[next_edge].y0 <= y {
+                    active.push(self.edges[next_edge]);
+                    next_edge += 1;
+                }
+                active.retain(|e| e.y1 > y);
+
+                crossings.clear();
+                crossings.extend(
+                    active
+                        .iter()
+                        .filter(|e| e.y0 <= y)
+                        .map(|e| (e.x0 + (y - e.y0) * (e.x1 - e.x0) / (e.y1 - e.y0), e.winding)),
+                );
+                if crossings.is_empty() {
+                    continue;
+                }
+                crossings.sort_unstable_by(|a, b| a.0.total_cmp(&b.0));
+
+                let mut winding = 0i32;
+                for i in 0..crossings.len() - 1 {
+                    winding += crossings[i].1 as i32;
+                    let inside = match fill_rule {
+                        FillRule::Nonzero => winding != 0,
+                        FillRule::Evenodd => winding % 2 != 0,
+                    };
+                    if inside {
+                        add_span(&mut coverage, crossings[i].0, crossings[i + 1].0);
+                        touched = true;
+                    }
+                }
+            }
+
+            if touched {
+                for (pixel, cov) in line.iter_mut().zip(coverage.iter()) {
+                    *pixel = (cov * 255. / SUB_SCANLINES as f32).round().min(255.) as u8;
+                }
+            }
+        }
+        result
+    }
+}
+
+/// Add the coverage of the horizontal span from `x0` to `x1` in the coverage line
+fn add_span(coverage: &mut [f32], x0: f32, x1: f32) {
+    let x0 = x0.max(0.);
+    let x1 = x1.min(coverage.len() as f32);
+    if x0 >= x1 {
+        return;
+    }
+    let ix0 = x0 as usize;
+    let ix1 = x1 as usize;
+    if ix0 == ix1 {
+        coverage[ix0] += x1 - x0;
+        return;
+    }
+    coverage[ix0] += (ix0 + 1) as f32 - x0;
+    for c in &mut coverage[ix0 + 1..ix1] {
+        *c += 1.;
+    }
+    if ix1 < coverage.len() {
+        coverage[ix1] += x1 - ix1 as f32;
+    }
+}
+
+#[test]
+fn rasterize_square() {
+    let mut rasterizer = Rasterizer::new(4, 4);
+    let events = [
+        PathEvent::Begin { at: Point::new(1., 1.) },
+        PathEvent::Line { from: Point::new(1., 1.), to: Point::new(3., 1.) },
+        PathEvent::Line { from: Point::new(3., 1.), to: Point::new(3., 3.) },
+        PathEvent::Line { from: Point::new(3., 3.), to: Point::new(1., 3.) },
+        PathEvent::End { last: Point::new(1., 3.), first: Point::new(1., 1.), close: true },
+    ];
+    rasterizer.add_fill(events.iter().copied(), &Transform::identity());
+    let map = rasterizer.rasterize(FillRule::Nonzero);
+    assert_eq!(map, [0, 0, 0, 0, 0, 255, 255, 0, 0, 255, 255, 0, 0, 0, 0, 0]);
+}
+
+#[test]
+fn rasterize_evenodd() {
+    // Two nested squares with the same orientation: nonzero fills the hole, evenodd doesn't
+    let square = |min: f32, max: f32| {
+        [
+            PathEvent::Begin { at: Point::new(min, min) },
+            PathEvent::Line { from: Point::new(min, min), to: Point::new(max, min) },
+            PathEvent::Line { from: Point::new(max, min), to: Point::new(max, max) },
+            PathEvent::Line { from: Point::new(max, max), to: Point::new(min, max) },
+            PathEvent::End { last: Point::new(min, max), first: Point::new(min, min), close: true },
+        ]
+    };
+    let events = || square(0., 3.).into_iter().chain(square(1., 2.));
+
+    let mut rasterizer = Rasterizer::new(3, 3);
+    rasterizer.add_fill(events(), &Transform::identity());
+    assert_eq!(rasterizer.rasterize(FillRule::Nonzero)[4], 255);
+
+    let mut rasterizer = Rasterizer::new(3, 3);
+    rasterizer.add_fill(events(), &Transform::identity());
+    let map = rasterizer.rasterize(FillRule::Evenodd);
+    assert_eq!(map[4], 0);
+    assert_eq!(map[0], 255);
+}
+
+#[test]
+fn rasterize_non_finite() {
+    let mut rasterizer = Rasterizer::new(4, 4);
+    let events = [
+        PathEvent::Begin { at: Point::new(1., 1.) },
+        PathEvent::Line { from: Point::new(1., 1.), to: Point::new(f32::NAN, 3.) },
+        PathEvent::Line { from: Point::new(f32::NAN, 3.), to: Point::new(1., 3.) },
+        PathEvent::End { last: Point::new(1., 3.), first: Point::new(1., 1.), close: true },
+    ];
+    rasterizer.add_fill(events.iter().copied(), &Transform::identity());
+    assert_eq!(rasterizer.rasterize(FillRule::Nonzero), [0; 16]);
+}
+
+#[test]
+fn rasterize_stroke() {
+    let mut rasterizer = Rasterizer::new(5, 5);
+    let events = [
+        PathEvent::Begin { at: Point::new(0., 2.5) },
+        PathEvent::Line { from: Point::new(0., 2.5), to: Point::new(5., 2.5) },
+        PathEvent::End { last: Point::new(5., 2.5), first: Point::new(0., 2.5), close: false },
+    ];
+    rasterizer.add_stroke(events.iter().copied(), &Transform::identity(), 1.);
+    let map = rasterizer.rasterize(FillRule::Nonzero);
+    assert_eq!(&map[10..15], &[255; 5]);
+    assert_eq!(&map[5..10], &[0; 5]);
+    assert_eq!(&map[15..20], &[0; 5]);
+}
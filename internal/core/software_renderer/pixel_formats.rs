@@ -0,0 +1,358 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-1.0 OR LicenseRef-Slint-commercial
+
+//! This module contains the implementations of [`TargetPixel`] for the pixel formats
+//! commonly used by displays, as well as the ordered dithering used when quantizing
+//! to low bit depths.
+
+use super::{LineBufferProvider, PremultipliedRgbaColor, Rgb565Pixel, TargetPixel};
+use crate::graphics::Rgb8Pixel;
+use alloc::vec::Vec;
+
+/// Luminance of a color with the BT.601 coefficients.
+fn luma(red: u8, green: u8, blue: u8) -> u8 {
+    ((red as u32 * 77 + green as u32 * 150 + blue as u32 * 29 + 128) >> 8) as u8
+}
+
+/// Blend a single 8 bit channel with a premultiplied component of the given alpha.
+fn blend_channel(value: u8, component: u8, alpha: u8) -> u8 {
+    (value as u16 * (u8::MAX - alpha) as u16 / 255) as u8 + component
+}
+
+/// 4x4 Bayer matrix used for ordered dithering
+const BAYER_4X4: [[u8; 4]; 4] = [[0, 8, 2, 10], [12, 4, 14, 6], [3, 11, 1, 9], [15, 7, 13, 5]];
+
+/// Quantize an 8 bit value to `bits` bits, using the threshold of the Bayer matrix at the
+/// position (x, y). Returns a value between 0 and `(1 << bits) - 1`
+fn dither(value: u8, bits: u32, x: usize, y: usize) -> u8 {
+    let levels = (1u32 << bits) - 1;
+    let threshold = BAYER_4X4[y % 4][x % 4] as u32;
+    ((value as u32 * levels * 16 + (2 * threshold + 1) * 255 / 2) / (255 * 16)) as u8
+}
+
+/// Quantize an 8 bit value to `bits` bits with ordered dithering, and shift the result
+/// back to the most significant bits of a u8
+fn dither_to_msb(value: u8, bits: u32, x: usize, y: usize) -> u8 {
+    dither(value, bits, x, y) << (8 - bits)
+}
+
+/// A pixel format for which the quantization from 8 bit per channel can be done with
+/// ordered dithering. This is used by the [`DitheringLineBuffer`].
+pub trait DitheredPixel: TargetPixel {
+    /// Create the pixel at position (`x`, `y`) from the red, green, blue component in the range 0..=255,
+    /// dithering the components that lose precision.
+    fn from_rgb_dithered(red: u8, green: u8, blue: u8, x: usize, y: usize) -> Self;
+    /// Convert the pixel back to 8 bit per channel
+    fn to_rgb(self) -> Rgb8Pixel;
+}
+
+impl DitheredPixel for Rgb565Pixel {
+    fn from_rgb_dithered(red: u8, green: u8, blue: u8, x: usize, y: usize) -> Self {
+        Self::from_rgb(
+            dither_to_msb(red, 5, x, y),
+            dither_to_msb(green, 6, x, y),
+            dither_to_msb(blue, 5, x, y),
+        )
+    }
+
+    fn to_rgb(self) -> Rgb8Pixel {
+        self.into()
+    }
+}
+
+/// A 32 bit pixel with 8 bits for each of the red, green and blue components, in the
+/// `0xXXRRGGBB` layout. The most significant byte is not used by the display: it is set
+/// to `0xff` by [`TargetPixel::from_rgb`] and preserved when blending.
+#[repr(transparent)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Default)]
+pub struct Xrgb8888Pixel(pub u32);
+
+impl Xrgb8888Pixel {
+    fn components(self) -> [u8; 4] {
+        self.0.to_be_bytes()
+    }
+}
+
+impl TargetPixel for Xrgb8888Pixel {
+    fn blend(&mut self, color: PremultipliedRgbaColor) {
+        let [x, r, g, b] = self.components();
+        self.0 = u32::from_be_bytes([
+            x,
+            blend_channel(r, color.red, color.alpha),
+            blend_channel(g, color.green, color.alpha),
+            blend_channel(b, color.blue, color.alpha),
+        ]);
+    }
+
+    fn from_rgb(red: u8, green: u8, blue: u8) -> Self {
+        Self(u32::from_be_bytes([0xff, red, green, blue]))
+    }
+}
+
+impl From<Rgb8Pixel> for Xrgb8888Pixel {
+    fn from(p: Rgb8Pixel) -> Self {
+        Self::from_rgb(p.r, p.g, p.b)
+    }
+}
+
+impl From<Xrgb8888Pixel> for Rgb8Pixel {
+    fn from(p: Xrgb8888Pixel) -> Self {
+        let [_, r, g, b] = p.components();
+        Rgb8Pixel { r, g, b }
+    }
+}
+
+/// A 16bit pixel that has 5 blue bits, 6 green bits and 5 red bits, stored with its
+/// two bytes swapped compared to the native endianness.
+///
+/// This is the format expected by the displays that take their pixels in BGR order and
+/// in a different byte order than the CPU, so that the buffer can be sent as is.
+#[repr(transparent)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Default)]
+pub struct Bgr565SwappedPixel(pub u16);
+
+impl Bgr565SwappedPixel {
+    /// Swapping the red and blue bits turns a RGB565 value into a BGR565 value and vice versa
+    fn swap_red_blue(v: u16) -> u16 {
+        ((v & 0x1f) << 11) | (v & 0x07e0) | (v >> 11)
+    }
+
+    fn to_rgb565(self) -> Rgb565Pixel {
+        Rgb565Pixel(Self::swap_red_blue(self.0.swap_bytes()))
+    }
+}
+
+impl From<Rgb565Pixel> for Bgr565SwappedPixel {
+    fn from(p: Rgb565Pixel) -> Self {
+        Self(Self::swap_red_blue(p.0).swap_bytes())
+    }
+}
+
+impl From<Bgr565SwappedPixel> for Rgb565Pixel {
+    fn from(p: Bgr565SwappedPixel) -> Self {
+        p.to_rgb565()
+    }
+}
+
+impl TargetPixel for Bgr565SwappedPixel {
+    fn blend(&mut self, color: PremultipliedRgbaColor) {
+        let mut pix = self.to_rgb565();
+        pix.blend(color);
+        *self = pix.into();
+    }
+
+    fn from_rgb(red: u8, green: u8, blue: u8) -> Self {
+        Rgb565Pixel::from_rgb(red, green, blue).into()
+    }
+}
+
+impl DitheredPixel for Bgr565SwappedPixel {
+    fn from_rgb_dithered(red: u8, green: u8, blue: u8, x: usize, y: usize) -> Self {
+        Rgb565Pixel::from_rgb_dithered(red, green, blue, x, y).into()
+    }
+
+    fn to_rgb(self) -> Rgb8Pixel {
+        self.to_rgb565().into()
+    }
+}
+
+/// A 8 bit grayscale pixel, where 0 is black and 255 is white.
+///
+/// Colors are converted to their luminance.
+#[repr(transparent)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Default)]
+pub struct Gray8Pixel(pub u8);
+
+impl TargetPixel for Gray8Pixel {
+    fn blend(&mut self, color: PremultipliedRgbaColor) {
+        // The components are premultiplied, so is their luminance
+        self.0 = blend_channel(self.0, luma(color.red, color.green, color.blue), color.alpha);
+    }
+
+    fn from_rgb(red: u8, green: u8, blue: u8) -> Self {
+        Self(luma(red, green, blue))
+    }
+}
+
+impl DitheredPixel for Gray8Pixel {
+    fn from_rgb_dithered(red: u8, green: u8, blue: u8, _x: usize, _y: usize) -> Self {
+        Self::from_rgb(red, green, blue)
+    }
+
+    fn to_rgb(self) -> Rgb8Pixel {
+        Rgb8Pixel { r: self.0, g: self.0, b: self.0 }
+    }
+}
+
+/// A 1 bit monochrome pixel, as used by e-paper displays: `true` is white and `false` is black.
+///
+/// Each pixel takes one byte in the buffer. Pack the line into bits before sending it to the display.
+/// Rendering directly in this format thresholds the luminance at 50%. Use a [`DitheringLineBuffer`]
+/// to render with anti-aliasing and shades of gray approximated by ordered dithering.
+#[repr(transparent)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Default)]
+pub struct MonochromePixel(pub bool);
+
+impl MonochromePixel {
+    fn from_gray(gray: u8) -> Self {
+        Self(gray >= 0x80)
+    }
+
+    fn gray(self) -> u8 {
+        if self.0 {
+            u8::MAX
+        } else {
+            0
+        }
+    }
+}
+
+impl TargetPixel for MonochromePixel {
+    fn blend(&mut self, color: PremultipliedRgbaColor) {
+        let mut gray = Gray8Pixel(self.gray());
+        gray.blend(color);
+        *self = Self::from_gray(gray.0);
+    }
+
+    fn from_rgb(red: u8, green: u8, blue: u8) -> Self {
+        Self::from_gray(luma(red, green, blue))
+    }
+}
+
+impl DitheredPixel for MonochromePixel {
+    fn from_rgb_dithered(red: u8, green: u8, blue: u8, x: usize, y: usize) -> Self {
+        Self(dither(luma(red, green, blue), 1, x, y) != 0)
+    }
+
+    fn to_rgb(self) -> Rgb8Pixel {
+        let gray = self.gray();
+        Rgb8Pixel { r: gray, g: gray, b: gray }
+    }
+}
+
+/// A [`LineBufferProvider`] that renders each line in 8 bit per channel, and then quantizes it
+/// into the pixels of the wrapped `LineBufferProvider` with ordered dithering.
+///
+/// This avoids the banding of gradients and keeps the anti-aliasing on displays with a low bit depth.
+///
+/// ```rust
+/// # use i_slint_core::software_renderer::{
+/// #     DitheringLineBuffer, LineBufferProvider, MonochromePixel, SoftwareRenderer,
+/// # };
+/// # fn xxx(renderer: &SoftwareRenderer, display_width: usize) {
+/// struct EPaper { frame_buffer: Vec<MonochromePixel>, stride: usize }
+/// impl LineBufferProvider for &mut EPaper {
+///     type TargetPixel = MonochromePixel;
+///     fn process_line(
+///         &mut self,
+///         line: usize,
+///         range: core::ops::Range<usize>,
+///         render_fn: impl FnOnce(&mut [Self::TargetPixel]),
+///     ) {
+///         let line_begin = line * self.stride;
+///         render_fn(&mut self.frame_buffer[line_begin..][range]);
+///     }
+/// }
+/// let mut display = EPaper { frame_buffer: vec![Default::default(); display_width * 128], stride: display_width };
+/// renderer.render_by_line(DitheringLineBuffer::new(&mut display));
+/// # }
+/// ```
+pub struct DitheringLineBuffer<B> {
+    inner: B,
+    line: Vec<Rgb8Pixel>,
+}
+
+impl<B: LineBufferProvider> DitheringLineBuffer<B>
+where
+    B::TargetPixel: DitheredPixel,
+{
+    /// Wraps a line buffer provider
+    pub fn new(inner: B) -> Self {
+        Self { inner, line: Vec::new() }
+    }
+
+    /// Returns the wrapped line buffer provider
+    pub fn into_inner(self) -> B {
+        self.inner
+    }
+}
+
+impl<B: LineBufferProvider> LineBufferProvider for DitheringLineBuffer<B>
+where
+    B::TargetPixel: DitheredPixel,
+{
+    type TargetPixel = Rgb8Pixel;
+
+    fn process_line(
+        &mut self,
+        line: usize,
+        range: core::ops::Range<usize>,
+        render_fn: impl FnOnce(&mut [Self::TargetPixel]),
+    ) {
+        let buffer = &mut self.line;
+        self.inner.process_line(line, range.clone(), |target| {
+            buffer.clear();
+            buffer.extend(target.iter().map(|p| p.to_rgb()));
+            render_fn(buffer);
+            for ((x, pixel), rgb) in range.zip(target.iter_mut()).zip(buffer.iter()) {
+                *pixel = DitheredPixel::from_rgb_dithered(rgb.r, rgb.g, rgb.b, x, line);
+            }
+        });
+    }
+}
+
+#[test]
+fn bgr565_swapped() {
+    let pix = Bgr565SwappedPixel::from_rgb(0xff, 0, 0);
+    assert_eq!(pix.0, 0x1f00);
+    let pix = Bgr565SwappedPixel::from_rgb(0, 0, 0xff);
+    assert_eq!(pix.0, 0x00f8);
+
+    let mut pix = Bgr565SwappedPixel::from_rgb(0x56, 0x42, 0xe3);
+    let mut reference = Rgb565Pixel::from_rgb(0x56, 0x42, 0xe3);
+    let color = PremultipliedRgbaColor { red: 0x20, green: 0x10, blue: 0x08, alpha: 0x40 };
+    pix.blend(color);
+    reference.blend(color);
+    assert_eq!(Rgb565Pixel::from(pix), reference);
+}
+
+#[test]
+fn xrgb8888() {
+    let mut pix = Xrgb8888Pixel::from_rgb(0x12, 0x34, 0x56);
+    assert_eq!(pix.0, 0xff123456);
+    pix.blend(PremultipliedRgbaColor { red: 0x80, green: 0x80, blue: 0x80, alpha: 0x80 });
+    assert_eq!(Rgb8Pixel::from(pix), Rgb8Pixel { r: 0x88, g: 0x99, b: 0xaa });
+    assert_eq!(pix.0 >> 24, 0xff);
+}
+
+#[test]
+fn gray_and_mono() {
+    assert_eq!(Gray8Pixel::from_rgb(0xff, 0xff, 0xff).0, 0xff);
+    assert_eq!(Gray8Pixel::from_rgb(0, 0, 0).0, 0);
+    assert!(Gray8Pixel::from_rgb(0, 0xff, 0).0 > Gray8Pixel::from_rgb(0xff, 0, 0).0);
+
+    let mut pix = MonochromePixel::from_rgb(0, 0, 0);
+    assert!(!pix.0);
+    pix.blend(PremultipliedRgbaColor { red: 0xc0, green: 0xc0, blue: 0xc0, alpha: 0xc0 });
+    assert!(pix.0);
+}
+
+#[test]
+fn ordered_dithering() {
+    for bits in [1, 5, 6] {
+        let max = (1 << bits) - 1;
+        for y in 0..4 {
+            for x in 0..4 {
+                assert_eq!(dither(0, bits, x, y), 0);
+                assert_eq!(dither(255, bits, x, y), max);
+            }
+        }
+    }
+
+    // A 50% gray is rendered with half of the pixels white
+    let white = (0..4)
+        .flat_map(|y| (0..4).map(move |x| (x, y)))
+        .filter(|(x, y)| MonochromePixel::from_rgb_dithered(0x80, 0x80, 0x80, *x, *y).0)
+        .count();
+    assert_eq!(white, 8);
+}
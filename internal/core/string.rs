@@ -272,6 +272,59 @@ pub fn format(args: core::fmt::Arguments<'_>) -> SharedString {
     output
 }
 
+/// Returns the number of characters in the string. This is the `length` of a string in the `.slint` language.
+pub fn character_count(s: &str) -> usize {
+    s.chars().count()
+}
+
+/// Returns the part of the string from the character at index `start` up to, but not including,
+/// the character at index `end`. The indices are clamped to the string, and an empty string
+/// is returned if `end` is not after `start`.
+pub fn substring(s: &str, start: i32, end: i32) -> SharedString {
+    let byte_offset =
+        |index: i32| s.char_indices().nth(index.max(0) as usize).map_or(s.len(), |(i, _)| i);
+    let (start, end) = (byte_offset(start), byte_offset(end));
+    if end > start {
+        s[start..end].into()
+    } else {
+        SharedString::default()
+    }
+}
+
+/// Splits the string at each occurrence of `separator`.
+/// An empty separator splits the string into its characters.
+pub fn split(s: &str, separator: &str) -> alloc::vec::Vec<SharedString> {
+    if separator.is_empty() {
+        s.chars().map(SharedString::from).collect()
+    } else {
+        s.split(separator).map(SharedString::from).collect()
+    }
+}
+
+/// Formats the number with exactly `precision` digits after the decimal point.
+/// The precision is clamped to the range 0..=20.
+pub fn format_number_fixed(n: f64, precision: i32) -> SharedString {
+    crate::format!("{:.*}", precision.clamp(0, 20) as usize, n)
+}
+
+#[test]
+fn string_functions() {
+    assert_eq!(character_count("h€llo 😎"), 7);
+    assert_eq!(substring("h€llo 😎", 1, 4), "€ll");
+    assert_eq!(substring("h€llo 😎", 4, 100), "o 😎");
+    assert_eq!(substring("hello", -3, 2), "he");
+    assert_eq!(substring("hello", 3, 2), "");
+    assert_eq!(split("a,b,,c", ","), ["a", "b", "", "c"]);
+    assert_eq!(split("", ","), [""]);
+    assert_eq!(split("a€c", ""), ["a", "€", "c"]);
+    assert_eq!(format_number_fixed(3.14159, 2), "3.14");
+    assert_eq!(format_number_fixed(2.6, 0), "3");
+    assert_eq!(format_number_fixed(7., 3), "7.000");
+    assert_eq!(format_number_fixed(-1.25, -1), "-1");
+    assert_eq!(format_number_fixed(0.5, 1000), "0.50000000000000000000");
+    assert_eq!(format_number_fixed(1., i32::MAX), format_number_fixed(1., 20));
+}
+
 #[test]
 fn simple_test() {
     let x = SharedString::from("hello world!");
@@ -425,6 +478,62 @@ fn test_slint_shared_string_append() {
         append("!");
         assert_eq!(s.as_str(), "Hello, world!");
     }
+
+    /// Replace `out` with the number formatted with `precision` digits after the decimal point.
+    #[no_mangle]
+    pub extern "C" fn slint_shared_string_from_number_fixed(
+        out: &mut SharedString,
+        n: f64,
+        precision: i32,
+    ) {
+        *out = format_number_fixed(n, precision);
+    }
+
+    /// Replace `out` with the upper case version of `ss`
+    #[no_mangle]
+    pub extern "C" fn slint_shared_string_to_uppercase(out: &mut SharedString, ss: &SharedString) {
+        *out = ss.to_uppercase().into();
+    }
+
+    /// Replace `out` with the lower case version of `ss`
+    #[no_mangle]
+    pub extern "C" fn slint_shared_string_to_lowercase(out: &mut SharedString, ss: &SharedString) {
+        *out = ss.to_lowercase().into();
+    }
+
+    /// Replace `out` with `ss` without leading and trailing whitespace
+    #[no_mangle]
+    pub extern "C" fn slint_shared_string_trim(out: &mut SharedString, ss: &SharedString) {
+        *out = ss.trim().into();
+    }
+
+    /// Returns the number of characters in the string
+    #[no_mangle]
+    pub extern "C" fn slint_shared_string_character_count(ss: &SharedString) -> usize {
+        character_count(ss)
+    }
+
+    /// Replace `out` with the characters of `ss` between `start` and `end`
+    #[no_mangle]
+    pub extern "C" fn slint_shared_string_substring(
+        out: &mut SharedString,
+        ss: &SharedString,
+        start: i32,
+        end: i32,
+    ) {
+        *out = substring(ss, start, end);
+    }
+
+    /// Replace `out` with `ss` where all occurrences of `from` are replaced by `to`
+    #[no_mangle]
+    pub extern "C" fn slint_shared_string_replace(
+        out: &mut SharedString,
+        ss: &SharedString,
+        from: &SharedString,
+        to: &SharedString,
+    ) {
+        *out = ss.replace(from.as_str(), to).into();
+    }
 }
 
 #[cfg(feature = "serde")]
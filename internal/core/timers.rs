@@ -144,6 +144,13 @@ pub fn running(&self) -> bool {
             .map(|timer_id| CURRENT_TIMERS.with(|timers| timers.borrow().timers[timer_id].running))
             .unwrap_or(false)
     }
+
+    /// Returns the interval of the timer, or `None` if the timer was never started.
+    pub fn interval(&self) -> Option<core::time::Duration> {
+        self.id
+            .get()
+            .map(|timer_id| CURRENT_TIMERS.with(|timers| timers.borrow().timers[timer_id].duration))
+    }
 }
 
 impl Drop for Timer {
@@ -464,6 +471,18 @@ pub extern "C" fn slint_timer_running(id: i64) -> bool {
         timer.id.take(); // Make sure that dropping the Timer doesn't unregister it. C++ will call destroy() in the destructor.
         running
     }
+
+    /// Returns the interval in milliseconds. 0 when the timer was never started.
+    #[no_mangle]
+    pub extern "C" fn slint_timer_interval(id: i64) -> u64 {
+        if id == -1 {
+            return 0;
+        }
+        let timer = Timer { id: Cell::new(Some(id as _)) };
+        let duration = timer.interval().unwrap_or_default();
+        timer.id.take(); // Make sure that dropping the Timer doesn't unregister it. C++ will call destroy() in the destructor.
+        duration.as_millis() as u64
+    }
 }
 
 /**
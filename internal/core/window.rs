@@ -16,8 +16,8 @@
     key_codes, ClickState, InternalKeyboardModifierState, KeyEvent, KeyEventType, KeyInputEvent,
     KeyboardModifiers, MouseEvent, MouseInputState, TextCursorBlinker,
 };
-use crate::item_tree::ItemRc;
-use crate::items::{ItemRef, MouseCursor};
+use crate::item_tree::{ItemRc, ItemTreeNode, ItemWeak};
+use crate::items::{ItemRef, MouseCursor, Shortcut};
 use crate::lengths::{LogicalLength, LogicalPoint, LogicalRect, LogicalSize, SizeLengths};
 use crate::properties::{Property, PropertyTracker};
 use crate::renderer::Renderer;
@@ -37,6 +37,15 @@ fn previous_focus_item(item: ItemRc) -> ItemRc {
     item.previous_focus_item()
 }
 
+/// Returns the component at the root of the tree of components that contains `item`.
+fn root_component_of(item: &ItemRc) -> ComponentRc {
+    let mut root = item.clone();
+    while let Some(parent) = root.parent_item() {
+        root = parent;
+    }
+    root.component()
+}
+
 /// Transforms a `KeyInputEvent` into an `KeyEvent` with the given `KeyboardModifiers`.
 fn input_as_key_event(input: KeyInputEvent, modifiers: KeyboardModifiers) -> KeyEvent {
     KeyEvent {
@@ -48,6 +57,33 @@ fn input_as_key_event(input: KeyInputEvent, modifiers: KeyboardModifiers) -> Key
     }
 }
 
+/// Calls `visitor` with each `Shortcut` item of the component and of its repeated components,
+/// following the shortcut index built by the compiler.
+fn visit_shortcuts(component: &ComponentRc, visitor: &mut dyn FnMut(ItemRc)) {
+    let comp_ref_pin = vtable::VRc::borrow_pin(component);
+    let item_tree = crate::item_tree::ComponentItemTree::new(&comp_ref_pin);
+    for index in comp_ref_pin.as_ref().get_shortcut_index().iter() {
+        match item_tree.get(*index as usize) {
+            Some(ItemTreeNode::Item { .. }) => visitor(ItemRc::new(component.clone(), *index as _)),
+            Some(ItemTreeNode::DynamicTree { index: repeater_index, .. }) => {
+                let range = comp_ref_pin.as_ref().get_subtree_range(*repeater_index);
+                for subtree_index in core::ops::Range::from(range) {
+                    let mut sub_component = Default::default();
+                    comp_ref_pin.as_ref().get_subtree_component(
+                        *repeater_index,
+                        subtree_index,
+                        &mut sub_component,
+                    );
+                    if let Some(sub_component) = sub_component.upgrade() {
+                        visit_shortcuts(&sub_component, visitor);
+                    }
+                }
+            }
+            None => {}
+        }
+    }
+}
+
 /// This trait represents the adaptation layer between the [`Window`] API, and the
 /// internal type from the backend that provides functionality such as device-independent pixels,
 /// window resizing, and other typically windowing system related tasks.
@@ -259,8 +295,9 @@ fn notify(&self) {
 
 /// This enum describes the different ways a popup can be rendered by the back-end.
 enum PopupWindowLocation {
-    /// The popup is rendered in its own top-level window that is know to the windowing system.
-    TopLevel(Rc<dyn WindowAdapter>),
+    /// The popup is rendered in its own top-level window that is know to the windowing system,
+    /// at the given position relative to the window.
+    TopLevel(Rc<dyn WindowAdapter>, LogicalPoint),
     /// The popup is rendered as an embedded child window at the given position.
     ChildWindow(LogicalPoint),
 }
@@ -275,6 +312,14 @@ struct PopupWindow {
     /// If true, Slint will close the popup after any mouse click within the popup.
     /// Set to false and call close() on the PopupWindow to close it manually.
     close_on_click: bool,
+    /// The item from which the popup was shown.
+    parent_item: ItemWeak,
+    /// The item that had the focus before the popup was shown. It gets the focus back when the
+    /// popup is closed while an item of the popup has the focus.
+    focus_item_before: ItemWeak,
+    /// A tooltip is always the top-most popup. It doesn't receive mouse events, and closes when
+    /// a key or a mouse button is pressed.
+    is_tooltip: bool,
 }
 
 #[pin_project::pin_project]
@@ -297,14 +342,20 @@ pub struct WindowInner {
     window_adapter_weak: Weak<dyn WindowAdapter>,
     component: RefCell<ComponentWeak>,
     mouse_input_state: Cell<MouseInputState>,
+    /// The component (the window's or a popup's) that received the last mouse event.
+    mouse_input_component: RefCell<ComponentWeak>,
     pub(crate) modifiers: Cell<InternalKeyboardModifierState>,
 
     /// itemRC will retrieve on wasms
     pub focus_item: RefCell<crate::item_tree::ItemWeak>,
+    /// The item that had the focus before `focus_item`.
+    last_focus_item: RefCell<crate::item_tree::ItemWeak>,
     cursor_blinker: RefCell<pin_weak::rc::PinWeak<crate::input::TextCursorBlinker>>,
 
     pinned_fields: Pin<Box<WindowPinnedFields>>,
-    active_popup: RefCell<Option<PopupWindow>>,
+    /// The popups that are shown, from the bottom to the top. Each popup was shown from
+    /// an item of the window's component or of one of the popups below it.
+    active_popups: RefCell<alloc::vec::Vec<PopupWindow>>,
     close_requested: Callback<(), CloseRequestResponse>,
     click_state: ClickState,
 }
@@ -342,6 +393,7 @@ pub fn new(window_adapter_weak: Weak<dyn WindowAdapter>) -> Self {
             window_adapter_weak,
             component: Default::default(),
             mouse_input_state: Default::default(),
+            mouse_input_component: Default::default(),
             modifiers: Default::default(),
             pinned_fields: Box::pin(WindowPinnedFields {
                 redraw_tracker: redraw_tracker,
@@ -354,8 +406,9 @@ pub fn new(window_adapter_weak: Weak<dyn WindowAdapter>) -> Self {
                 ),
             }),
             focus_item: Default::default(),
+            last_focus_item: Default::default(),
             cursor_blinker: Default::default(),
-            active_popup: Default::default(),
+            active_popups: Default::default(),
             close_requested: Default::default(),
             click_state: ClickState::default(),
         }
@@ -367,6 +420,7 @@ pub fn set_component(&self, component: &ComponentRc) {
         self.close_popup();
         self.focus_item.replace(Default::default());
         self.mouse_input_state.replace(Default::default());
+        self.mouse_input_component.replace(Default::default());
         self.modifiers.replace(Default::default());
         self.component.replace(ComponentRc::downgrade(component));
         self.pinned_fields.window_properties_tracker.set_dirty(); // component changed, layout constraints for sure must be re-calculated
@@ -402,6 +456,12 @@ pub fn try_component(&self) -> Option<ComponentRc> {
         self.component.borrow().upgrade()
     }
 
+    /// Returns the components of the popups that are currently shown, from the bottom-most to the
+    /// top-most one.
+    pub fn popup_components(&self) -> alloc::vec::Vec<ComponentRc> {
+        self.active_popups.borrow().iter().map(|popup| popup.component.clone()).collect()
+    }
+
     /// Receive a mouse event and pass it to the items of the component to
     /// change their state.
     ///
@@ -415,53 +475,87 @@ pub fn process_mouse_input(&self, mut event: MouseEvent) {
         // handle multiple press release
         event = self.click_state.check_repeat(event);
 
-        let close_popup_after_click = self.close_popup_after_click();
+        if matches!(event, MouseEvent::Pressed { .. }) {
+            self.close_tooltip();
+        }
 
-        let embedded_popup_component =
-            self.active_popup.borrow().as_ref().and_then(|popup| match popup.location {
-                PopupWindowLocation::TopLevel(_) => None,
-                PopupWindowLocation::ChildWindow(coordinates) => {
-                    Some((popup.component.clone(), coordinates))
-                }
-            });
+        let mut popups = self.embedded_popups();
+        popups.retain(|(index, _, _)| !self.active_popups.borrow()[*index].is_tooltip);
+        let mut mouse_input_state = self.mouse_input_state.take();
+        let previous_component = self.mouse_input_component.borrow().upgrade();
+        let previous_popup = previous_component.as_ref().and_then(|previous| {
+            popups.iter().position(|(_, component, _)| vtable::VRc::ptr_eq(component, previous))
+        });
 
-        let component = embedded_popup_component
-            .as_ref()
-            .and_then(|(popup_component, coordinates)| {
-                event.translate(-coordinates.to_vector());
-
-                if let MouseEvent::Pressed { position, .. } = &event {
-                    // close the popup if one press outside the popup
-                    let geom = ComponentRc::borrow_pin(popup_component)
-                        .as_ref()
-                        .get_item_ref(0)
-                        .as_ref()
-                        .geometry();
-                    if !geom.contains(*position) {
+        let target_popup = if mouse_input_state.is_grabbed() {
+            // The component that grabbed the mouse keeps receiving the events
+            previous_popup
+        } else if let Some(position) = event.position() {
+            let popup_under_mouse = popups.iter().rposition(|(_, popup_component, coordinates)| {
+                ComponentRc::borrow_pin(popup_component)
+                    .as_ref()
+                    .get_item_ref(0)
+                    .as_ref()
+                    .geometry()
+                    .contains(position - coordinates.to_vector())
+            });
+            match popup_under_mouse {
+                None if !popups.is_empty() => {
+                    if matches!(event, MouseEvent::Pressed { .. }) {
+                        // close the popups if one press outside of them
+                        crate::input::send_exit_events_to_all(
+                            mouse_input_state,
+                            &self.window_adapter(),
+                        );
                         self.close_popup();
-                        return None;
+                        return;
                     }
+                    popups.len().checked_sub(1)
                 }
-                Some(popup_component.clone())
-            })
-            .or_else(|| self.component.borrow().upgrade());
-
-        let component = if let Some(component) = component {
-            component
+                popup_under_mouse => popup_under_mouse,
+            }
         } else {
-            return;
+            previous_popup
+        };
+
+        let (component, coordinates, close_on_click) = match target_popup {
+            Some(index) => {
+                let (popup_index, component, coordinates) = &popups[index];
+                if matches!(event, MouseEvent::Pressed { .. }) {
+                    // a press in a popup closes the popups that were shown from within it
+                    self.close_popups_from(popup_index + 1);
+                }
+                let close_on_click = self.active_popups.borrow()[*popup_index].close_on_click;
+                (component.clone(), *coordinates, close_on_click)
+            }
+            None => match self.component.borrow().upgrade() {
+                Some(component) => (component, LogicalPoint::default(), false),
+                None => return,
+            },
         };
 
+        if previous_component.map_or(true, |previous| !vtable::VRc::ptr_eq(&previous, &component)) {
+            crate::input::send_exit_events_to_all(mouse_input_state, &self.window_adapter());
+            mouse_input_state = Default::default();
+            self.mouse_input_component.replace(ComponentRc::downgrade(&component));
+        }
+
+        event.translate(-coordinates.to_vector());
         self.mouse_input_state.set(crate::input::process_mouse_input(
-            component,
+            component.clone(),
             event,
             &self.window_adapter(),
-            self.mouse_input_state.take(),
+            mouse_input_state,
         ));
 
-        if embedded_popup_component.is_some() && close_popup_after_click {
-            if matches!(event, MouseEvent::Released { .. }) {
-                self.close_popup();
+        if close_on_click && matches!(event, MouseEvent::Released { .. }) {
+            let popup_index = self
+                .active_popups
+                .borrow()
+                .iter()
+                .position(|popup| vtable::VRc::ptr_eq(&popup.component, &component));
+            if let Some(popup_index) = popup_index {
+                self.close_popups_from(popup_index);
             }
         }
     }
@@ -490,6 +584,10 @@ pub fn process_key_input(&self, event: KeyInputEvent) {
             self.modifiers.set(updated_modifier);
         }
 
+        if event.event_type == KeyEventType::KeyPressed {
+            self.close_tooltip();
+        }
+
         let event = input_as_key_event(event, self.modifiers.get().into());
 
         let mut item = self.focus_item.borrow().clone().upgrade();
@@ -510,6 +608,10 @@ pub fn process_key_input(&self, event: KeyInputEvent) {
             item = focus_item.parent_item();
         }
 
+        if self.activate_shortcut(&event) {
+            return;
+        }
+
         // Make Tab/Backtab handle keyboard focus
         if event.text.starts_with(key_codes::Tab)
             && !event.modifiers.shift
@@ -524,6 +626,53 @@ pub fn process_key_input(&self, event: KeyInputEvent) {
         }
     }
 
+    /// Invokes the `activated` callback of the `Shortcut` element that matches the key event.
+    /// When several shortcuts match, the one that shares the closest ancestor with the focus
+    /// item wins, so that a shortcut declared near the focused element has precedence over one
+    /// declared elsewhere in the window.
+    /// Returns true if a shortcut was activated.
+    fn activate_shortcut(&self, event: &KeyEvent) -> bool {
+        if event.event_type != KeyEventType::KeyPressed {
+            return false;
+        }
+        let focus_chain =
+            core::iter::successors(self.focus_item.borrow().upgrade(), ItemRc::parent_item)
+                .collect::<alloc::vec::Vec<_>>();
+        let components = self
+            .active_popups
+            .borrow()
+            .iter()
+            .rev()
+            .map(|popup| popup.component.clone())
+            .collect::<alloc::vec::Vec<_>>()
+            .into_iter()
+            .chain(self.try_component());
+
+        let mut best: Option<(usize, ItemRc)> = None;
+        for component in components {
+            visit_shortcuts(&component, &mut |item_rc| {
+                let matches = ItemRef::downcast_pin::<Shortcut>(item_rc.borrow())
+                    .map_or(false, |shortcut| shortcut.matches(event));
+                if !matches || !item_rc.is_visible() {
+                    return;
+                }
+                // The distance from the focus item to the closest common ancestor
+                let distance = core::iter::successors(item_rc.parent_item(), ItemRc::parent_item)
+                    .find_map(|ancestor| focus_chain.iter().position(|f| *f == ancestor))
+                    .unwrap_or(usize::MAX);
+                if best.as_ref().map_or(true, |(best_distance, _)| distance < *best_distance) {
+                    best = Some((distance, item_rc));
+                }
+            });
+        }
+
+        let Some((_, item_rc)) = best else { return false };
+        let item = item_rc.borrow();
+        let shortcut = ItemRef::downcast_pin::<Shortcut>(item).unwrap();
+        Shortcut::FIELD_OFFSETS.activated.apply_pin(shortcut).call(&());
+        true
+    }
+
     /// Installs a binding on the specified property that's toggled whenever the text cursor is supposed to be visible or not.
     pub fn set_cursor_blink_binding(&self, prop: &crate::Property<bool>) {
         let existing_blinker = self.cursor_blinker.borrow().clone();
@@ -542,6 +691,9 @@ pub fn set_cursor_blink_binding(&self, prop: &crate::Property<bool>) {
     /// currently focused item.
     pub fn set_focus_item(&self, focus_item: &ItemRc) {
         let old = self.take_focus_item();
+        if let Some(old) = old.as_ref().filter(|old| *old != focus_item) {
+            self.last_focus_item.replace(old.downgrade());
+        }
         let new = self.move_focus(focus_item.clone(), next_focus_item);
         let window_adapter = self.window_adapter();
         if let Some(window_adapter) = window_adapter.internal(crate::InternalToken) {
@@ -671,7 +823,7 @@ pub fn active(&self) -> bool {
     /// for example, with the properties known to the windowing system.
     pub fn update_window_properties(&self) {
         let window_adapter = self.window_adapter();
-        let Some(window_adapter) = window_adapter.internal(crate::InternalToken) else {return};
+        let Some(window_adapter) = window_adapter.internal(crate::InternalToken) else { return };
 
         // No `if !dirty { return; }` check here because the backend window may be newly mapped and not up-to-date, so force
         // an evaluation.
@@ -699,25 +851,20 @@ pub fn draw_contents<T>(
         &self,
         render_components: impl FnOnce(&[(&ComponentRc, LogicalPoint)]) -> T,
     ) -> Option<T> {
+        crate::properties::ChangeTracker::run_change_handlers();
         let draw_fn = || {
             let component_rc = self.try_component()?;
 
-            let popup_component =
-                self.active_popup.borrow().as_ref().and_then(|popup| match popup.location {
-                    PopupWindowLocation::TopLevel(_) => None,
-                    PopupWindowLocation::ChildWindow(coordinates) => {
-                        Some((popup.component.clone(), coordinates))
-                    }
-                });
+            let popup_components = self.embedded_popups();
+            let components = core::iter::once((&component_rc, LogicalPoint::default()))
+                .chain(
+                    popup_components
+                        .iter()
+                        .map(|(_, component, coordinates)| (component, *coordinates)),
+                )
+                .collect::<alloc::vec::Vec<_>>();
 
-            Some(if let Some((popup_component, popup_coordinates)) = popup_component {
-                render_components(&[
-                    (&component_rc, LogicalPoint::default()),
-                    (&popup_component, popup_coordinates),
-                ])
-            } else {
-                render_components(&[(&component_rc, LogicalPoint::default())])
-            })
+            Some(render_components(&components))
         };
 
         self.pinned_fields
@@ -766,9 +913,123 @@ pub fn show_popup(
         close_on_click: bool,
         parent_item: &ItemRc,
     ) {
-        let position = parent_item.map_to_window(
+        // A popup shown from within another popup is nested in it, and replaces the popups that
+        // were shown from within that popup before. Otherwise, it replaces all active popups.
+        let parent_popup = self.popup_index_of_item(parent_item);
+        let replaced_focus_item = self.close_popups_from(parent_popup.map_or(0, |index| index + 1));
+
+        let position = self.popup_position(parent_item, position);
+        let size = Self::prepare_popup_size(popup_componentrc);
+
+        let location = match self
+            .window_adapter()
+            .internal(crate::InternalToken)
+            .and_then(|x| x.create_popup(LogicalRect::new(position, size)))
+        {
+            None => {
+                self.window_adapter().request_redraw();
+                PopupWindowLocation::ChildWindow(position)
+            }
+
+            Some(window_adapter) => {
+                WindowInner::from_pub(window_adapter.window()).set_component(popup_componentrc);
+                PopupWindowLocation::TopLevel(window_adapter, position)
+            }
+        };
+
+        // The popup may already have taken the focus when it was initialized.
+        let mut focus_item_before = match self.focus_item.borrow().upgrade() {
+            Some(focus_item)
+                if vtable::VRc::ptr_eq(&root_component_of(&focus_item), popup_componentrc) =>
+            {
+                self.last_focus_item.borrow().clone()
+            }
+            Some(focus_item) => focus_item.downgrade(),
+            None => Default::default(),
+        };
+        if !focus_item_before.upgrade().map_or(false, |item| self.is_item_shown(&item)) {
+            // It was in a popup that this one replaces: inherit where that one gives the focus back
+            if let Some(replaced_focus_item) = replaced_focus_item {
+                focus_item_before = replaced_focus_item;
+            }
+        }
+
+        self.active_popups.borrow_mut().push(PopupWindow {
+            location,
+            component: popup_componentrc.clone(),
+            close_on_click,
+            parent_item: parent_item.downgrade(),
+            focus_item_before,
+            is_tooltip: false,
+        });
+    }
+
+    /// Show a tooltip at the given position relative to the item. The tooltip is rendered
+    /// within the window, and moved so that it doesn't extend past the window's edges.
+    /// It replaces the tooltip that was shown before, if any, but doesn't close other popups.
+    pub fn show_tooltip(
+        &self,
+        tooltip_componentrc: &ComponentRc,
+        position: Point,
+        parent_item: &ItemRc,
+    ) {
+        self.close_tooltip();
+
+        let mut position = self.popup_position(parent_item, position);
+        let size = Self::prepare_popup_size(tooltip_componentrc);
+
+        if let Some(window_item) = self.window_item() {
+            let window_item = window_item.as_pin_ref();
+            let max_x = (window_item.width() - size.width_length()).max(LogicalLength::zero());
+            let max_y = (window_item.height() - size.height_length()).max(LogicalLength::zero());
+            position.x = position.x.min(max_x.get()).max(0 as Coord);
+            position.y = position.y.min(max_y.get()).max(0 as Coord);
+        }
+
+        self.window_adapter().request_redraw();
+        self.active_popups.borrow_mut().push(PopupWindow {
+            location: PopupWindowLocation::ChildWindow(position),
+            component: tooltip_componentrc.clone(),
+            close_on_click: false,
+            parent_item: parent_item.downgrade(),
+            focus_item_before: Default::default(),
+            is_tooltip: true,
+        });
+    }
+
+    /// Closes the tooltip, if one is shown.
+    pub fn close_tooltip(&self) {
+        let tooltip_index = match self.active_popups.borrow().last() {
+            Some(popup) if popup.is_tooltip => self.active_popups.borrow().len() - 1,
+            _ => return,
+        };
+        self.close_popups_from(tooltip_index);
+    }
+
+    /// Returns the position in the window of a popup that is shown at `position` relative to
+    /// `parent_item`.
+    fn popup_position(&self, parent_item: &ItemRc, position: Point) -> LogicalPoint {
+        self.map_to_window(
+            parent_item,
             parent_item.geometry().origin + LogicalPoint::from_untyped(position).to_vector(),
-        );
+        )
+    }
+
+    /// Maps `position`, relative to the parent of `item`, to the window. Unlike
+    /// [`ItemRc::map_to_window`], this includes the position of the popup that contains `item`.
+    pub fn map_to_window(&self, item: &ItemRc, position: LogicalPoint) -> LogicalPoint {
+        let mut position = item.map_to_window(position);
+        if let Some(popup) = self.popup_index_of_item(item) {
+            position += match self.active_popups.borrow()[popup].location {
+                PopupWindowLocation::TopLevel(_, coordinates)
+                | PopupWindowLocation::ChildWindow(coordinates) => coordinates.to_vector(),
+            };
+        }
+        position
+    }
+
+    /// Computes the size of a popup from its layout, and sets it on the popup's window item.
+    fn prepare_popup_size(popup_componentrc: &ComponentRc) -> LogicalSize {
         let popup_component = ComponentRc::borrow_pin(popup_componentrc);
         let popup_root = popup_component.as_ref().get_item_ref(0);
 
@@ -805,37 +1066,60 @@ pub fn show_popup(
             height_property.set(size.height_length());
         };
 
-        let location = match self
-            .window_adapter()
-            .internal(crate::InternalToken)
-            .and_then(|x| x.create_popup(LogicalRect::new(position, size)))
-        {
-            None => {
-                self.window_adapter().request_redraw();
-                PopupWindowLocation::ChildWindow(position)
-            }
+        size
+    }
 
-            Some(window_adapter) => {
-                WindowInner::from_pub(window_adapter.window()).set_component(popup_componentrc);
-                PopupWindowLocation::TopLevel(window_adapter)
+    /// Removes all active popups.
+    pub fn close_popup(&self) {
+        self.close_popups_from(0);
+    }
+
+    /// Closes the popup that was shown from `parent_item`, along with the popups that were
+    /// shown from within it. Does nothing if no such popup is active.
+    pub fn close_popup_from(&self, parent_item: &ItemRc) {
+        let popup_index = self
+            .active_popups
+            .borrow()
+            .iter()
+            .position(|popup| popup.parent_item.upgrade().as_ref() == Some(parent_item));
+        if let Some(popup_index) = popup_index {
+            self.close_popups_from(popup_index);
+        }
+    }
+
+    /// Closes the popups starting at `index` in the stack of active popups, and returns the item
+    /// that had the focus before the first of them was shown.
+    fn close_popups_from(&self, index: usize) -> Option<ItemWeak> {
+        let closed_popups = {
+            let mut active_popups = self.active_popups.borrow_mut();
+            if index >= active_popups.len() {
+                return None;
             }
+            active_popups.split_off(index)
         };
+        let focus_item_before = closed_popups[0].focus_item_before.clone();
+
+        let focus_item = self.focus_item.borrow().upgrade();
+        if let Some(focus_item) = focus_item {
+            let focus_component = root_component_of(&focus_item);
+            if closed_popups
+                .iter()
+                .any(|popup| vtable::VRc::ptr_eq(&popup.component, &focus_component))
+            {
+                match focus_item_before.upgrade() {
+                    Some(item) if self.is_item_shown(&item) => self.set_focus_item(&item),
+                    _ => {
+                        self.take_focus_item();
+                    }
+                }
+            }
+        }
 
-        self.active_popup.replace(Some(PopupWindow {
-            location,
-            component: popup_componentrc.clone(),
-            close_on_click,
-        }));
-    }
-
-    /// Removes any active popup.
-    /// TODO: this function should take a component ref as parameter, to close a specific popup - i.e. when popup menus create a hierarchy of popups.
-    pub fn close_popup(&self) {
-        if let Some(current_popup) = self.active_popup.replace(None) {
-            if let PopupWindowLocation::ChildWindow(offset) = current_popup.location {
+        for popup in closed_popups.into_iter().rev() {
+            if let PopupWindowLocation::ChildWindow(offset) = popup.location {
                 // Refresh the area that was previously covered by the popup.
                 let popup_region = crate::properties::evaluate_no_tracking(|| {
-                    let popup_component = ComponentRc::borrow_pin(&current_popup.component);
+                    let popup_component = ComponentRc::borrow_pin(&popup.component);
                     popup_component.as_ref().get_item_ref(0).as_ref().geometry()
                 })
                 .translate(offset.to_vector());
@@ -847,11 +1131,50 @@ pub fn close_popup(&self) {
                 }
             }
         }
+
+        Some(focus_item_before)
+    }
+
+    /// Returns the index in the stack of active popups of the popup that contains `item`,
+    /// or None if the item is not in a popup.
+    fn popup_index_of_item(&self, item: &ItemRc) -> Option<usize> {
+        let component = root_component_of(item);
+        self.active_popups
+            .borrow()
+            .iter()
+            .position(|popup| vtable::VRc::ptr_eq(&popup.component, &component))
+    }
+
+    /// Returns true if `item` is in the window's component or in one of the active popups.
+    fn is_item_shown(&self, item: &ItemRc) -> bool {
+        let component = root_component_of(item);
+        self.component.borrow().upgrade().map_or(false, |c| vtable::VRc::ptr_eq(&c, &component))
+            || self
+                .active_popups
+                .borrow()
+                .iter()
+                .any(|popup| vtable::VRc::ptr_eq(&popup.component, &component))
+    }
+
+    /// Returns the popups that are rendered within the window, from the bottom to the top,
+    /// with their index in the stack of active popups and their position in the window.
+    fn embedded_popups(&self) -> alloc::vec::Vec<(usize, ComponentRc, LogicalPoint)> {
+        self.active_popups
+            .borrow()
+            .iter()
+            .enumerate()
+            .filter_map(|(index, popup)| match popup.location {
+                PopupWindowLocation::TopLevel(..) => None,
+                PopupWindowLocation::ChildWindow(coordinates) => {
+                    Some((index, popup.component.clone(), coordinates))
+                }
+            })
+            .collect()
     }
 
-    /// Returns true if the currently active popup is configured to close on click. None if there is no active popup.
+    /// Returns true if the top-most popup is configured to close on click. False if there is no active popup.
     pub fn close_popup_after_click(&self) -> bool {
-        self.active_popup.borrow().as_ref().map_or(false, |popup| popup.close_on_click)
+        self.active_popups.borrow().last().map_or(false, |popup| popup.close_on_click)
     }
 
     /// Returns the scale factor set on the window, as provided by the windowing system.
@@ -1086,11 +1409,25 @@ pub enum GraphicsAPI {
             parent_item,
         );
     }
-    /// Close the current popup
+    /// Show a tooltip.
     #[no_mangle]
-    pub unsafe extern "C" fn slint_windowrc_close_popup(handle: *const WindowAdapterRcOpaque) {
+    pub unsafe extern "C" fn slint_windowrc_show_tooltip(
+        handle: *const WindowAdapterRcOpaque,
+        tooltip: &ComponentRc,
+        position: crate::graphics::Point,
+        parent_item: &ItemRc,
+    ) {
+        let window_adapter = &*(handle as *const Rc<dyn WindowAdapter>);
+        WindowInner::from_pub(window_adapter.window()).show_tooltip(tooltip, position, parent_item);
+    }
+    /// Close the popup that was shown from the parent item
+    #[no_mangle]
+    pub unsafe extern "C" fn slint_windowrc_close_popup(
+        handle: *const WindowAdapterRcOpaque,
+        parent_item: &ItemRc,
+    ) {
         let window_adapter = &*(handle as *const Rc<dyn WindowAdapter>);
-        WindowInner::from_pub(window_adapter.window()).close_popup();
+        WindowInner::from_pub(window_adapter.window()).close_popup_from(parent_item);
     }
 
     /// C binding to the set_rendering_notifier() API of Window
@@ -115,6 +115,9 @@ pub enum Value {
     EnumerationValue(String, String) = 10,
     #[doc(hidden)]
     LayoutCache(SharedVector<f32>) = 11,
+    /// The absence of a value for an optional type (`T?` in .slint).
+    /// An optional type that holds a value is represented by the value itself.
+    None = 12,
 }
 
 impl Value {
@@ -157,6 +160,7 @@ fn eq(&self, other: &Self) -> bool {
                 matches!(other, Value::EnumerationValue(rhs_name, rhs_value) if lhs_name == rhs_name && lhs_value == rhs_value)
             }
             Value::LayoutCache(lhs) => matches!(other, Value::LayoutCache(rhs) if lhs == rhs),
+            Value::None => matches!(other, Value::None),
         }
     }
 }
@@ -180,6 +184,7 @@ fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
             Value::EasingCurve(c) => write!(f, "Value::EasingCurve({:?})", c),
             Value::EnumerationValue(n, v) => write!(f, "Value::EnumerationValue({:?}, {:?})", n, v),
             Value::LayoutCache(v) => write!(f, "Value::LayoutCache({:?})", v),
+            Value::None => write!(f, "Value::None"),
         }
     }
 }
@@ -252,6 +257,8 @@ fn try_from(v: Value) -> Result<$name, Self::Error> {
 }
 
 declare_value_struct_conversion!(struct i_slint_core::model::StandardListViewItem { text , ..Default::default()});
+declare_value_struct_conversion!(struct i_slint_core::model::StandardMenuItem { text, shortcut, checkable, checked, is_separator, level, ..Default::default()});
+declare_value_struct_conversion!(struct i_slint_core::model::StandardTreeViewItem { text, level, has_children, expanded, ..Default::default()});
 declare_value_struct_conversion!(struct i_slint_core::model::TableColumn { title, min_width, horizontal_stretch, sort_order, width, ..Default::default()  });
 declare_value_struct_conversion!(struct i_slint_core::properties::StateInfo { current_state, previous_state, change_time });
 declare_value_struct_conversion!(struct i_slint_core::input::KeyboardModifiers { control, alt, shift, meta });
@@ -316,6 +323,12 @@ fn try_from(v: Value) -> Result<i_slint_core::animations::Instant, Self::Error>
     }
 }
 
+impl<T: Into<Value>> From<Option<T>> for Value {
+    fn from(v: Option<T>) -> Self {
+        v.map_or(Value::None, Into::into)
+    }
+}
+
 impl From<()> for Value {
     #[inline]
     fn from(_: ()) -> Self {
@@ -435,12 +448,16 @@ pub struct ComponentCompiler {
 
 impl Default for ComponentCompiler {
     fn default() -> Self {
-        Self {
-            config: i_slint_compiler::CompilerConfiguration::new(
-                i_slint_compiler::generator::OutputFormat::Interpreter,
-            ),
-            diagnostics: vec![],
+        #[allow(unused_mut)]
+        let mut config = i_slint_compiler::CompilerConfiguration::new(
+            i_slint_compiler::generator::OutputFormat::Interpreter,
+        );
+        // The testing API needs the ids and type names of the elements
+        #[cfg(any(test, feature = "testing"))]
+        {
+            config.debug_info = true;
         }
+        Self { config, diagnostics: vec![] }
     }
 }
 
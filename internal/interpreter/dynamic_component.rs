@@ -210,6 +210,14 @@ fn accessible_string_property(
     ) {
         self.borrow().as_ref().accessible_string_property(index, what, result)
     }
+
+    fn item_element_infos(self: Pin<&Self>, index: usize, result: &mut SharedString) -> bool {
+        self.borrow().as_ref().item_element_infos(index, result)
+    }
+
+    fn get_shortcut_index(self: Pin<&Self>) -> Slice<u32> {
+        get_shortcut_index(self.get_ref().borrow())
+    }
 }
 
 i_slint_core::ComponentVTable_static!(static COMPONENT_BOX_VT for ErasedComponentBox);
@@ -238,6 +246,10 @@ pub(crate) struct ComponentExtraData {
     pub(crate) self_weak: OnceCell<vtable::VWeak<ComponentVTable, ErasedComponentBox>>,
     // resource id -> file path
     pub(crate) embedded_file_resources: OnceCell<HashMap<usize, String>>,
+    /// The trackers for the `changed` callbacks of the elements
+    pub(crate) change_trackers: OnceCell<Vec<i_slint_core::properties::ChangeTracker>>,
+    /// The timers of the component, in the same order as in `Component::timers`
+    pub(crate) timers: OnceCell<Vec<i_slint_core::timers::Timer>>,
     #[cfg(target_arch = "wasm32")]
     pub(crate) canvas_id: OnceCell<String>,
 }
@@ -320,6 +332,8 @@ pub struct ComponentDescription<'id> {
     /// INVARIANT: both dynamic_type and item_tree have the same lifetime id. Here it is erased to 'static
     dynamic_type: Rc<dynamic_type::TypeInfo<'id>>,
     item_tree: Vec<ItemTreeNode>,
+    /// The indices in the item_tree of the Shortcut items, and of the repeaters that contain some
+    shortcut_index: Vec<u32>,
     item_array:
         Vec<vtable::VOffset<crate::dynamic_type::Instance<'id>, ItemVTable, vtable::AllowPin>>,
     pub(crate) items: HashMap<String, ItemWithinComponent>,
@@ -778,6 +792,7 @@ pub(crate) fn generate_component<'id>(
                 rtti_for::<BorderRectangle>(),
                 rtti_for::<TouchArea>(),
                 rtti_for::<FocusScope>(),
+                rtti_for::<Shortcut>(),
                 rtti_for::<Path>(),
                 rtti_for::<Flickable>(),
                 rtti_for::<WindowItem>(),
@@ -814,6 +829,7 @@ fn push(rtti: &mut HashMap<&str, Rc<ItemRTTI>>) {
 
     struct TreeBuilder<'id> {
         tree_array: Vec<ItemTreeNode>,
+        shortcut_index: Vec<u32>,
         item_array:
             Vec<vtable::VOffset<crate::dynamic_type::Instance<'id>, ItemVTable, vtable::AllowPin>>,
         original_elements: Vec<ElementRc>,
@@ -833,16 +849,20 @@ fn push_repeated_item(
             parent_index: u32,
             _component_state: &Self::SubComponentState,
         ) {
-            self.tree_array
-                .push(ItemTreeNode::DynamicTree { index: repeater_count as usize, parent_index });
-            self.original_elements.push(item_rc.clone());
             let item = item_rc.borrow();
             let base_component = item.base_type.as_component();
             self.repeater_names.insert(item.id.clone(), self.repeater.len());
             generativity::make_guard!(guard);
+            let component_to_repeat = generate_component(base_component, guard);
+            if !component_to_repeat.shortcut_index.is_empty() {
+                self.shortcut_index.push(self.tree_array.len() as u32);
+            }
+            self.tree_array
+                .push(ItemTreeNode::DynamicTree { index: repeater_count as usize, parent_index });
+            self.original_elements.push(item_rc.clone());
             self.repeater.push(
                 RepeaterWithinComponent {
-                    component_to_repeat: generate_component(base_component, guard),
+                    component_to_repeat,
                     offset: self.type_builder.add_field_type::<Repeater<ErasedComponentBox>>(),
                     model: item.repeated.as_ref().unwrap().model.clone(),
                 }
@@ -873,6 +893,9 @@ fn push_native_item(
             } else {
                 self.type_builder.add_field(rt.type_info)
             };
+            if item.base_type.as_native().class_name == "Shortcut" {
+                self.shortcut_index.push(self.tree_array.len() as u32);
+            }
             self.tree_array.push(ItemTreeNode::Item {
                 is_accessible: !item.accessibility_props.0.is_empty(),
                 children_index: child_offset,
@@ -912,6 +935,7 @@ fn enter_component_children(
 
     let mut builder = TreeBuilder {
         tree_array: vec![],
+        shortcut_index: vec![],
         item_array: vec![],
         original_elements: vec![],
         items_types: HashMap::new(),
@@ -985,6 +1009,7 @@ fn animated_property_info<T: Clone + Default + InterpolatedPropertyValue + 'stat
             }
             Type::Struct { .. } => property_info::<Value>(),
             Type::Array(_) => property_info::<Value>(),
+            Type::Optional(_) => property_info::<Value>(),
             Type::Easing => property_info::<i_slint_core::animations::EasingCurve>(),
             Type::Percent => property_info::<f32>(),
             Type::Enumeration(e) => {
@@ -1095,6 +1120,8 @@ macro_rules! match_enum_type {
         subtree_index,
         accessible_role,
         accessible_string_property,
+        item_element_infos,
+        get_shortcut_index,
         drop_in_place,
         dealloc,
     };
@@ -1102,6 +1129,7 @@ macro_rules! match_enum_type {
         ct: t,
         dynamic_type: builder.type_builder.build(),
         item_tree: builder.tree_array,
+        shortcut_index: builder.shortcut_index,
         item_array: builder.item_array,
         items: builder.items_types,
         custom_properties,
@@ -1305,7 +1333,7 @@ pub fn instantiate(
     for (prop_name, decl) in &component_type.original.root_element.borrow().property_declarations {
         if !matches!(
             decl.property_type,
-            Type::Struct { .. } | Type::Array(_) | Type::Enumeration(_)
+            Type::Struct { .. } | Type::Array(_) | Type::Enumeration(_) | Type::Optional(_)
         ) || decl.is_alias.is_some()
         {
             continue;
@@ -1526,6 +1554,89 @@ pub fn run_setup_code(&self) {
                 &mut eval::EvalLocalContext::from_component_instance(instance_ref),
             );
         }
+
+        let mut change_trackers = Vec::new();
+        object_tree::recurse_elem(
+            &self.0.component_type.original.root_element,
+            &(),
+            &mut |elem, _| {
+                for (nr, handler) in &elem.borrow().change_callbacks {
+                    let self_weak = instance_ref.self_weak().get().unwrap().clone();
+                    let handler_self_weak = self_weak.clone();
+                    let (nr, handler) = (nr.clone(), handler.clone());
+                    let tracker = i_slint_core::properties::ChangeTracker::default();
+                    tracker.init(
+                        move || {
+                            let Some(self_rc) = self_weak.upgrade() else { return Value::Void };
+                            generativity::make_guard!(guard);
+                            let c = self_rc.unerase(guard);
+                            eval::load_property(c.borrow_instance(), &nr.element(), nr.name())
+                                .unwrap()
+                        },
+                        move || {
+                            let Some(self_rc) = handler_self_weak.upgrade() else { return };
+                            generativity::make_guard!(guard);
+                            let c = self_rc.unerase(guard);
+                            eval::eval_expression(
+                                &handler,
+                                &mut eval::EvalLocalContext::from_component_instance(
+                                    c.borrow_instance(),
+                                ),
+                            );
+                        },
+                    );
+                    change_trackers.push(tracker);
+                }
+            },
+        );
+        let extra_data = instance_ref.component_type.extra_data_offset.apply(instance_ref.as_ref());
+        extra_data.change_trackers.set(change_trackers).ok();
+
+        let timer_count = self.0.component_type.original.timers.borrow().len();
+        if timer_count > 0 {
+            extra_data.timers.set((0..timer_count).map(|_| Default::default()).collect()).ok();
+            update_timers(instance_ref);
+        }
+    }
+}
+
+/// Start, restart or stop the timers of the component according to the value of their
+/// `running` and `interval` properties
+pub(crate) fn update_timers(instance_ref: InstanceRef) {
+    let extra_data = instance_ref.component_type.extra_data_offset.apply(instance_ref.as_ref());
+    let Some(timers) = extra_data.timers.get() else { return };
+    for (timer, tmr) in
+        timers.iter().zip(instance_ref.component_type.original.timers.borrow().iter())
+    {
+        let running =
+            eval::load_property(instance_ref, &tmr.running.element(), tmr.running.name()).unwrap();
+        let interval =
+            eval::load_property(instance_ref, &tmr.interval.element(), tmr.interval.name())
+                .unwrap();
+        let interval = match interval {
+            Value::Number(interval) => interval as i64,
+            _ => 0,
+        };
+        if matches!(running, Value::Bool(true)) && interval > 0 {
+            let interval = core::time::Duration::from_millis(interval as u64);
+            if !timer.running() || timer.interval() != Some(interval) {
+                let self_weak = instance_ref.self_weak().get().unwrap().clone();
+                let triggered = tmr.triggered.clone();
+                timer.start(i_slint_core::timers::TimerMode::Repeated, interval, move || {
+                    let Some(self_rc) = self_weak.upgrade() else { return };
+                    generativity::make_guard!(guard);
+                    let c = self_rc.unerase(guard);
+                    eval::invoke_callback(
+                        eval::ComponentInstance::InstanceRef(c.borrow_instance()),
+                        &triggered.element(),
+                        triggered.name(),
+                        &[],
+                    );
+                });
+            }
+        } else {
+            timer.stop();
+        }
     }
 }
 impl<'id> From<ComponentBox<'id>> for ErasedComponentBox {
@@ -1704,6 +1815,28 @@ extern "C" fn accessible_string_property(
     }
 }
 
+extern "C" fn item_element_infos(
+    component: ComponentRefPin,
+    item_index: usize,
+    result: &mut SharedString,
+) -> bool {
+    generativity::make_guard!(guard);
+    let instance_ref = unsafe { InstanceRef::from_pin_ref(component, guard) };
+    let elem = instance_ref.component_type.original_elements[item_index].borrow();
+    if elem.debug.is_empty() {
+        return false;
+    }
+    *result = elem.debug.iter().map(|info| info.encode()).collect::<Vec<_>>().join("\n").into();
+    true
+}
+
+extern "C" fn get_shortcut_index(component: ComponentRefPin) -> Slice<u32> {
+    generativity::make_guard!(guard);
+    let instance_ref = unsafe { InstanceRef::from_pin_ref(component, guard) };
+    let index = instance_ref.component_type.shortcut_index.as_slice();
+    unsafe { core::mem::transmute::<&[u32], &[u32]>(index) }.into()
+}
+
 unsafe extern "C" fn drop_in_place(component: vtable::VRefMut<ComponentVTable>) -> vtable::Layout {
     let instance_ptr = component.as_ptr() as *mut Instance<'static>;
     let layout = (*instance_ptr).type_info().layout();
@@ -1858,10 +1991,10 @@ pub fn show_popup(
         Default::default(),
     );
     inst.run_setup_code();
-    WindowInner::from_pub(parent_window_adapter.window()).show_popup(
-        &vtable::VRc::into_dyn(inst),
-        pos,
-        close_on_click,
-        parent_item,
-    );
+    let window = WindowInner::from_pub(parent_window_adapter.window());
+    if popup.is_tooltip {
+        window.show_tooltip(&vtable::VRc::into_dyn(inst), pos, parent_item);
+    } else {
+        window.show_popup(&vtable::VRc::into_dyn(inst), pos, close_on_click, parent_item);
+    }
 }
@@ -11,7 +11,8 @@
 use corelib::rtti::AnimatedBindingKind;
 use corelib::{Brush, Color, PathData, SharedString, SharedVector};
 use i_slint_compiler::expression_tree::{
-    BuiltinFunction, EasingCurve, Expression, Path as ExprPath, PathElement as ExprPathElement,
+    ArrayOperation, BuiltinFunction, EasingCurve, Expression, Path as ExprPath,
+    PathElement as ExprPathElement,
 };
 use i_slint_compiler::langtype::Type;
 use i_slint_compiler::object_tree::ElementRc;
@@ -129,6 +130,37 @@ pub fn from_function_arguments(
     }
 }
 
+/// Returns a function that evaluates the `body` of the lambda of an array function with the
+/// local variable `item` set to its argument.
+/// It doesn't borrow `local_context` because the model adapters call it after the evaluation
+/// of the binding.
+fn array_lambda(
+    item: &str,
+    body: &Expression,
+    local_context: &EvalLocalContext,
+) -> impl Fn(&Value) -> Value + 'static {
+    let self_weak = match local_context.component_instance {
+        ComponentInstance::InstanceRef(instance) => instance.self_weak().get().unwrap().clone(),
+        ComponentInstance::GlobalComponent(_) => unimplemented!(),
+    };
+    let local_variables = local_context.local_variables.clone();
+    let function_arguments = local_context.function_arguments.clone();
+    let (item, body) = (item.to_string(), body.clone());
+    move |value| {
+        let Some(self_rc) = self_weak.upgrade() else { return Value::Void };
+        generativity::make_guard!(guard);
+        let c = self_rc.unerase(guard);
+        let mut local_context = EvalLocalContext {
+            local_variables: local_variables.clone(),
+            function_arguments: function_arguments.clone(),
+            component_instance: ComponentInstance::InstanceRef(c.borrow_instance()),
+            return_value: None,
+        };
+        local_context.local_variables.insert(item.clone(), value.clone());
+        eval_expression(&body, &mut local_context)
+    }
+}
+
 /// Evaluate an expression and return a Value as the result of this expression
 pub fn eval_expression(expression: &Expression, local_context: &mut EvalLocalContext) -> Value {
     if let Some(r) = &local_context.return_value {
@@ -139,6 +171,7 @@ pub fn eval_expression(expression: &Expression, local_context: &mut EvalLocalCon
         Expression::Uncompiled(_) => panic!("uncompiled expression while evaluating"),
         Expression::StringLiteral(s) => Value::String(s.into()),
         Expression::NumberLiteral(n, unit) => Value::Number(unit.normalize(*n)),
+        Expression::NoneLiteral => Value::None,
         Expression::BoolLiteral(b) => Value::Bool(*b),
         Expression::CallbackReference { .. } => panic!("callback in expression"),
         Expression::FunctionReference { .. } => panic!("function in expression"),
@@ -190,15 +223,7 @@ pub fn eval_expression(expression: &Expression, local_context: &mut EvalLocalCon
         }
         Expression::Cast { from, to } => {
             let v = eval_expression(&*from, local_context);
-            match (v, to) {
-                (Value::Number(n), Type::Int32) => Value::Number(n.round()),
-                (Value::Number(n), Type::String) => {
-                    Value::String(i_slint_core::format!("{}", n))
-                }
-                (Value::Number(n), Type::Color) => Color::from_argb_encoded(n as u32).into(),
-                (Value::Brush(brush), Type::Color) => brush.color().into(),
-                (v, _) => v,
-            }
+            cast_value(v, to)
         }
         Expression::CodeBlock(sub) => {
             let mut v = Value::Void;
@@ -318,6 +343,20 @@ pub fn eval_expression(expression: &Expression, local_context: &mut EvalLocalCon
                 _ => local_context.return_value.clone().expect("conditional expression did not evaluate to boolean"),
             }
         }
+        Expression::Match { value, arms, default } => {
+            let en = match value.ty() {
+                Type::Enumeration(en) => en,
+                _ => panic!("match over a value that is not an enumeration"),
+            };
+            let value = match eval_expression(value, local_context) {
+                Value::EnumerationValue(_, value) => value,
+                _ => return local_context.return_value.clone().expect("match value is not an enumeration"),
+            };
+            match arms.iter().find(|(values, _)| values.iter().any(|v| en.values[*v] == value)) {
+                Some((_, e)) => eval_expression(e, local_context),
+                None => eval_expression(default.as_deref().expect("match expression is not exhaustive"), local_context),
+            }
+        }
         Expression::Array { values, .. } => Value::Model(
             ModelRc::new(corelib::model::SharedVectorModel::from(
                 values.iter().map(|e| eval_expression(e, local_context)).collect::<SharedVector<_>>()
@@ -340,6 +379,50 @@ pub fn eval_expression(expression: &Expression, local_context: &mut EvalLocalCon
         Expression::ReadLocalVariable { name, .. } => {
             local_context.local_variables.get(name).unwrap().clone()
         }
+        Expression::ArrayOperation { array, operation, lambda } => {
+            let Value::Model(model) = eval_expression(array, local_context) else {
+                return match operation {
+                    ArrayOperation::FindIndex => Value::Number(-1.),
+                    _ => Value::Void,
+                };
+            };
+            let Some((item, body)) = lambda else {
+                return Value::Model(corelib::model::reverse_rows(&model));
+            };
+            let eval_body = array_lambda(item, body, local_context);
+            match operation {
+                ArrayOperation::Filter => Value::Model(corelib::model::filter_rows(&model, move |value| {
+                    eval_body(value) == Value::Bool(true)
+                })),
+                ArrayOperation::Map => {
+                    Value::Model(corelib::model::map_rows(&model, move |value| eval_body(&value)))
+                }
+                ArrayOperation::SortBy if body.ty() == Type::String => {
+                    Value::Model(corelib::model::sort_rows_by_key(&model, move |value| {
+                        SharedString::try_from(eval_body(value)).unwrap()
+                    }))
+                }
+                ArrayOperation::SortBy => {
+                    Value::Model(corelib::model::sort_rows_by_key(&model, move |value| {
+                        f64::try_from(eval_body(value)).unwrap()
+                    }))
+                }
+                ArrayOperation::FindIndex => Value::Number(
+                    corelib::model::find_row(&model, |value| eval_body(value) == Value::Bool(true))
+                        as f64,
+                ),
+                ArrayOperation::Reverse => unreachable!(),
+            }
+        }
+        Expression::WhileLoop { condition, body } => {
+            while eval_expression(condition, local_context) == Value::Bool(true) {
+                eval_expression(body, local_context);
+                if let Some(r) = &local_context.return_value {
+                    return r.clone();
+                }
+            }
+            Value::Void
+        }
         Expression::EasingCurve(curve) => Value::EasingCurve(match curve {
             EasingCurve::Linear => corelib::animations::EasingCurve::Linear,
             EasingCurve::CubicBezier(a, b, c, d) => {
@@ -480,6 +563,29 @@ fn call_builtin_function(
             let y: f64 = eval_expression(&arguments[1], local_context).try_into().unwrap();
             Value::Number(x.powf(y))
         }
+        BuiltinFunction::ATan2 => {
+            let y: f64 = eval_expression(&arguments[0], local_context).try_into().unwrap();
+            let x: f64 = eval_expression(&arguments[1], local_context).try_into().unwrap();
+            Value::Number(y.atan2(x).to_degrees())
+        }
+        BuiltinFunction::Ln => {
+            let x: f64 = eval_expression(&arguments[0], local_context).try_into().unwrap();
+            Value::Number(x.ln())
+        }
+        BuiltinFunction::Exp => {
+            let x: f64 = eval_expression(&arguments[0], local_context).try_into().unwrap();
+            Value::Number(x.exp())
+        }
+        BuiltinFunction::Sign => {
+            let x: f64 = eval_expression(&arguments[0], local_context).try_into().unwrap();
+            Value::Number(if x == 0. { 0. } else { x.signum() })
+        }
+        BuiltinFunction::Clamp => {
+            let x: f64 = eval_expression(&arguments[0], local_context).try_into().unwrap();
+            let min: f64 = eval_expression(&arguments[1], local_context).try_into().unwrap();
+            let max: f64 = eval_expression(&arguments[2], local_context).try_into().unwrap();
+            Value::Number(x.max(min).min(max))
+        }
         BuiltinFunction::SetFocusItem => {
             if arguments.len() != 1 {
                 panic!("internal error: incorrect argument count to SetFocusItem")
@@ -579,17 +685,47 @@ fn call_builtin_function(
                 panic!("internal error: argument to SetFocusItem must be an element")
             }
         }
+        BuiltinFunction::UpdateTimers => {
+            match local_context.component_instance {
+                ComponentInstance::InstanceRef(c) => crate::dynamic_component::update_timers(c),
+                ComponentInstance::GlobalComponent(_) => {
+                    panic!("Timer cannot be in a global component")
+                }
+            }
+            Value::Void
+        }
         BuiltinFunction::ClosePopupWindow => {
+            if arguments.len() != 1 {
+                panic!("internal error: incorrect argument count to ClosePopupWindow")
+            }
             let component = match local_context.component_instance {
                 ComponentInstance::InstanceRef(c) => c,
                 ComponentInstance::GlobalComponent(_) => {
                     panic!("Cannot show popup from a global component")
                 }
             };
+            if let Expression::ElementReference(popup_window) = &arguments[0] {
+                let popup_window = popup_window.upgrade().unwrap();
+                let pop_comp = popup_window.borrow().enclosing_component.upgrade().unwrap();
+                let parent_element = pop_comp.parent_element.upgrade().unwrap();
 
-            component.access_window(|window| window.close_popup());
+                generativity::make_guard!(guard);
+                let enclosing_component =
+                    enclosing_component_for_element(&parent_element, component, guard);
+                let parent_item_info =
+                    &enclosing_component.component_type.items[parent_element.borrow().id.as_str()];
+                let parent_item_comp =
+                    enclosing_component.self_weak().get().unwrap().upgrade().unwrap();
+                let parent_item = corelib::items::ItemRc::new(
+                    vtable::VRc::into_dyn(parent_item_comp),
+                    parent_item_info.item_index(),
+                );
 
-            Value::Void
+                component.access_window(|window| window.close_popup_from(&parent_item));
+                Value::Void
+            } else {
+                panic!("internal error: argument to ClosePopupWindow must be an element")
+            }
         }
         BuiltinFunction::ItemMemberFunction(name) => {
             if arguments.len() != 1 {
@@ -628,6 +764,8 @@ fn call_builtin_function(
                         "cut" => textinput.cut(&window_adapter, &item_rc),
                         "copy" => textinput.copy(&window_adapter, &item_rc),
                         "paste" => textinput.paste(&window_adapter, &item_rc),
+                        "undo" => textinput.undo(&window_adapter, &item_rc),
+                        "redo" => textinput.redo(&window_adapter, &item_rc),
                         _ => panic!("internal: Unknown member function {name} called on TextInput"),
                     }
                 } else {
@@ -662,6 +800,64 @@ fn call_builtin_function(
                 panic!("Argument not a string");
             }
         }
+        BuiltinFunction::StringCharacterCount => {
+            let s: SharedString = eval_expression(&arguments[0], local_context).try_into().unwrap();
+            Value::Number(corelib::string::character_count(&s) as f64)
+        }
+        BuiltinFunction::StringToUppercase => {
+            let s: SharedString = eval_expression(&arguments[0], local_context).try_into().unwrap();
+            Value::String(s.to_uppercase().into())
+        }
+        BuiltinFunction::StringToLowercase => {
+            let s: SharedString = eval_expression(&arguments[0], local_context).try_into().unwrap();
+            Value::String(s.to_lowercase().into())
+        }
+        BuiltinFunction::StringTrim => {
+            let s: SharedString = eval_expression(&arguments[0], local_context).try_into().unwrap();
+            Value::String(s.trim().into())
+        }
+        BuiltinFunction::StringContains
+        | BuiltinFunction::StringStartsWith
+        | BuiltinFunction::StringEndsWith => {
+            let s: SharedString = eval_expression(&arguments[0], local_context).try_into().unwrap();
+            let pattern: SharedString =
+                eval_expression(&arguments[1], local_context).try_into().unwrap();
+            Value::Bool(match f {
+                BuiltinFunction::StringContains => s.contains(pattern.as_str()),
+                BuiltinFunction::StringStartsWith => s.starts_with(pattern.as_str()),
+                _ => s.ends_with(pattern.as_str()),
+            })
+        }
+        BuiltinFunction::StringSubstring => {
+            let s: SharedString = eval_expression(&arguments[0], local_context).try_into().unwrap();
+            let start: i32 = eval_expression(&arguments[1], local_context).try_into().unwrap();
+            let end: i32 = eval_expression(&arguments[2], local_context).try_into().unwrap();
+            Value::String(corelib::string::substring(&s, start, end))
+        }
+        BuiltinFunction::StringReplace => {
+            let s: SharedString = eval_expression(&arguments[0], local_context).try_into().unwrap();
+            let from: SharedString =
+                eval_expression(&arguments[1], local_context).try_into().unwrap();
+            let to: SharedString =
+                eval_expression(&arguments[2], local_context).try_into().unwrap();
+            Value::String(s.replace(from.as_str(), &to).into())
+        }
+        BuiltinFunction::StringSplit => {
+            let s: SharedString = eval_expression(&arguments[0], local_context).try_into().unwrap();
+            let separator: SharedString =
+                eval_expression(&arguments[1], local_context).try_into().unwrap();
+            Value::Model(ModelRc::new(corelib::model::SharedVectorModel::from(
+                corelib::string::split(&s, &separator)
+                    .into_iter()
+                    .map(Value::String)
+                    .collect::<SharedVector<_>>(),
+            )))
+        }
+        BuiltinFunction::NumberToFixed => {
+            let n: f64 = eval_expression(&arguments[0], local_context).try_into().unwrap();
+            let precision: i32 = eval_expression(&arguments[1], local_context).try_into().unwrap();
+            Value::String(corelib::string::format_number_fixed(n, precision))
+        }
         BuiltinFunction::ColorBrighter => {
             if arguments.len() != 2 {
                 panic!("internal error: incorrect argument count to ColorBrighter")
@@ -743,6 +939,42 @@ fn call_builtin_function(
                 panic!("First argument not a color");
             }
         }
+        BuiltinFunction::ColorHsvaStruct => {
+            if arguments.len() != 1 {
+                panic!("internal error: incorrect argument count to ColorHsvaStruct")
+            }
+            if let Value::Brush(brush) = eval_expression(&arguments[0], local_context) {
+                let hsva = brush.color().to_hsva();
+                let values = IntoIterator::into_iter([
+                    ("hue".to_string(), Value::Number(hsva.hue as f64)),
+                    ("saturation".to_string(), Value::Number(hsva.saturation as f64)),
+                    ("value".to_string(), Value::Number(hsva.value as f64)),
+                    ("alpha".to_string(), Value::Number(hsva.alpha as f64)),
+                ])
+                .collect();
+                Value::Struct(values)
+            } else {
+                panic!("First argument not a color");
+            }
+        }
+        BuiltinFunction::ColorHslaStruct => {
+            if arguments.len() != 1 {
+                panic!("internal error: incorrect argument count to ColorHslaStruct")
+            }
+            if let Value::Brush(brush) = eval_expression(&arguments[0], local_context) {
+                let hsla = brush.color().to_hsla();
+                let values = IntoIterator::into_iter([
+                    ("hue".to_string(), Value::Number(hsla.hue as f64)),
+                    ("saturation".to_string(), Value::Number(hsla.saturation as f64)),
+                    ("lightness".to_string(), Value::Number(hsla.lightness as f64)),
+                    ("alpha".to_string(), Value::Number(hsla.alpha as f64)),
+                ])
+                .collect();
+                Value::Struct(values)
+            } else {
+                panic!("First argument not a color");
+            }
+        }
         BuiltinFunction::ImageSize => {
             if arguments.len() != 1 {
                 panic!("internal error: incorrect argument count to ImageSize")
@@ -784,6 +1016,21 @@ fn call_builtin_function(
             let a: u8 = (255. * a).max(0.).min(255.) as u8;
             Value::Brush(Brush::SolidColor(Color::from_argb_u8(a, r, g, b)))
         }
+        BuiltinFunction::Hsv | BuiltinFunction::Hsl => {
+            let mut to_f32 = |e| -> f32 { eval_expression(e, local_context).try_into().unwrap() };
+            let (h, s, x, a) = (
+                to_f32(&arguments[0]),
+                to_f32(&arguments[1]),
+                to_f32(&arguments[2]),
+                to_f32(&arguments[3]),
+            );
+            let color = if f == BuiltinFunction::Hsv {
+                Color::from_hsva(h, s, x, a)
+            } else {
+                Color::from_hsla(h, s, x, a)
+            };
+            Value::Brush(Brush::SolidColor(color))
+        }
         BuiltinFunction::DarkColorScheme => match local_context.component_instance {
             ComponentInstance::InstanceRef(component) => Value::Bool(
                 component
@@ -1059,6 +1306,14 @@ fn eval_assignment(lhs: &Expression, op: char, rhs: Value, local_context: &mut E
                 }
             }
         }
+        Expression::ReadLocalVariable { name, .. } => {
+            let value = if op == '=' {
+                rhs
+            } else {
+                eval(local_context.local_variables.get(name).unwrap().clone())
+            };
+            local_context.local_variables.insert(name.clone(), value);
+        }
         _ => panic!("typechecking should make sure this was a PropertyReference"),
     }
 }
@@ -1201,6 +1456,20 @@ fn check_value_type(value: &Value, ty: &Type) -> bool {
             matches!(value, Value::EnumerationValue(name, _) if name == en.name.as_str())
         }
         Type::LayoutCache => matches!(value, Value::LayoutCache(_)),
+        Type::Optional(inner) => matches!(value, Value::None) || check_value_type(value, inner),
+    }
+}
+
+fn cast_value(v: Value, to: &Type) -> Value {
+    match (v, to) {
+        (Value::Number(n), Type::Int32) => Value::Number(n.round()),
+        (Value::Number(n), Type::String) => Value::String(i_slint_core::format!("{}", n)),
+        (Value::Number(n), Type::Color) => Color::from_argb_encoded(n as u32).into(),
+        (Value::Brush(brush), Type::Color) => brush.color().into(),
+        (Value::None, Type::Optional(_)) => Value::None,
+        (Value::None, to) => default_value_for_type(to),
+        (v, Type::Optional(inner)) => cast_value(v, inner),
+        (v, _) => v,
     }
 }
 
@@ -1486,6 +1755,7 @@ pub fn default_value_for_type(ty: &Type) -> Value {
         Type::UnitProduct(_) => Value::Number(0.),
         Type::PathData => Value::PathData(Default::default()),
         Type::LayoutCache => Value::LayoutCache(Default::default()),
+        Type::Optional(_) => Value::None,
         Type::InferredProperty
         | Type::InferredCallback
         | Type::ElementReference
@@ -419,7 +419,7 @@ fn add_highlight_items(doc: &Document) {
             root_element: Rc::new(RefCell::new(Element {
                 enclosing_component: comp.clone(),
                 id: "$Highlight".into(),
-                base_type: doc.local_registry.lookup_builtin_element("Rectangle").unwrap(),
+                base_type: doc.local_registry.borrow().lookup_builtin_element("Rectangle").unwrap(),
                 bindings,
                 ..Default::default()
             })),
@@ -498,7 +498,7 @@ fn add_current_item_callback(doc: &Document) {
     let element = Rc::new(RefCell::new(Element {
         enclosing_component: Rc::downgrade(&doc.root_component),
         id: "$DesignModeArea".into(),
-        base_type: doc.local_registry.lookup_builtin_element("TouchArea").unwrap(),
+        base_type: doc.local_registry.borrow().lookup_builtin_element("TouchArea").unwrap(),
         ..Default::default()
     }));
 
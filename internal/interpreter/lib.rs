@@ -90,6 +90,22 @@
 #[doc(inline)]
 pub use i_slint_core::{Brush, Color, SharedString, SharedVector};
 
+/// This module contains functions to find the elements of a component and interact with them, and to
+/// render windows headless and compare them with reference images, for use in tests.
+///
+/// Call [`testing::init()`] at the beginning of the test, before creating any component.
+/// With this feature, the [`ComponentCompiler`] keeps the ids and type names of the elements, so they can be found with
+/// [`testing::ElementHandle::find_by_element_id()`] and [`testing::ElementHandle::find_by_element_type_name()`].
+#[cfg(feature = "testing")]
+pub mod testing {
+    #[cfg(feature = "software-renderer-systemfonts")]
+    pub use i_slint_backend_testing::init_with_font_metrics;
+    pub use i_slint_backend_testing::{
+        compare_with_reference_png, init, take_snapshot, AccessibleRole, ElementHandle,
+        ImageComparisonError,
+    };
+}
+
 /// One need to use at least one function in each module in order to get them
 /// exported in the final binary.
 /// This only use functions from modules which are not otherwise used.
@@ -43,3 +43,51 @@ fn reuse_window() {
         instance
     };
 }
+
+#[test]
+fn find_elements() {
+    i_slint_backend_testing::init();
+    use crate::{ComponentCompiler, ComponentHandle, Value};
+    use i_slint_backend_testing::ElementHandle;
+    let code = r#"
+        component Item inherits Rectangle {
+            in property <int> index;
+            width: 20px;
+            height: 10px;
+        }
+        export component MainWindow inherits Window {
+            width: 100px;
+            height: 100px;
+            in-out property <int> count: 3;
+            in-out property <int> clicked;
+            for i in count: Item { index: i; y: i * 10px; }
+            button := Rectangle {
+                x: 50px;
+                width: 20px;
+                height: 20px;
+                TouchArea { clicked => { root.clicked += 1; } }
+            }
+        }
+    "#;
+    let mut compiler = ComponentCompiler::default();
+    compiler.set_style("fluent".into());
+    let definition = spin_on::spin_on(compiler.build_from_source(code.into(), Default::default()));
+    assert!(compiler.diagnostics().is_empty(), "{:?}", compiler.diagnostics());
+    let instance = definition.unwrap().create().unwrap();
+
+    let button = ElementHandle::find_by_element_id(&instance, "button");
+    assert_eq!(button.len(), 1);
+    assert_eq!(button[0].id().as_deref(), Some("button"));
+    assert_eq!(button[0].type_name().as_deref(), Some("Rectangle"));
+    button[0].click();
+    assert_eq!(instance.get_property("clicked").unwrap(), Value::from(1));
+
+    let items = ElementHandle::find_by_element_type_name(&instance, "Item");
+    assert_eq!(items.len(), 3);
+    assert_eq!(items[2].absolute_position(), i_slint_core::api::LogicalPosition::new(40., 20.));
+    assert_eq!(ElementHandle::find_by_element_type_name(&instance, "TouchArea").len(), 1);
+
+    instance.set_property("count", Value::from(1)).unwrap();
+    assert_eq!(ElementHandle::find_by_element_type_name(&instance, "Item").len(), 1);
+    assert!(!items[2].is_valid());
+}
@@ -185,10 +185,15 @@ pub(crate) fn completion_at(
         SyntaxKind::BindingExpression
             | SyntaxKind::CodeBlock
             | SyntaxKind::ReturnStatement
+            | SyntaxKind::LetStatement
+            | SyntaxKind::ForStatement
+            | SyntaxKind::WhileStatement
             | SyntaxKind::Expression
             | SyntaxKind::FunctionCallExpression
             | SyntaxKind::SelfAssignment
             | SyntaxKind::ConditionalExpression
+            | SyntaxKind::MatchExpression
+            | SyntaxKind::MatchArm
             | SyntaxKind::BinaryExpression
             | SyntaxKind::UnaryOpExpression
             | SyntaxKind::Array
@@ -230,8 +235,8 @@ pub(crate) fn completion_at(
                 let tr = q
                     .source_file()
                     .and_then(|sf| document_cache.documents.get_document(sf.path()))
-                    .map(|doc| &doc.local_registry)
-                    .unwrap_or(&global_tr);
+                    .map(|doc| doc.local_registry.borrow())
+                    .unwrap_or(global_tr);
 
                 let mut result = tr
                     .all_elements()
@@ -248,7 +253,7 @@ pub(crate) fn completion_at(
                     })
                     .collect::<Vec<_>>();
 
-                drop(global_tr);
+                drop(tr);
 
                 if snippet_support {
                     let available_types = result.iter().map(|c| c.label.clone()).collect();
@@ -342,9 +347,9 @@ fn resolve_element_scope(
     let tr = element
         .source_file()
         .and_then(|sf| document_cache.documents.get_document(sf.path()))
-        .map(|doc| &doc.local_registry)
-        .unwrap_or(&global_tr);
-    let element_type = lookup_current_element_type((*element).clone(), tr).unwrap_or_default();
+        .map(|doc| doc.local_registry.borrow())
+        .unwrap_or(global_tr);
+    let element_type = lookup_current_element_type((*element).clone(), &tr).unwrap_or_default();
     let mut result = element_type
         .property_list()
         .into_iter()
@@ -462,8 +467,8 @@ fn resolve_type_scope(
     let tr = token
         .source_file()
         .and_then(|sf| document_cache.documents.get_document(sf.path()))
-        .map(|doc| &doc.local_registry)
-        .unwrap_or(&global_tr);
+        .map(|doc| doc.local_registry.borrow())
+        .unwrap_or(global_tr);
     Some(
         tr.all_types()
             .into_iter()
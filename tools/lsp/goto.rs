@@ -27,7 +27,7 @@ pub fn goto_definition(
                 SyntaxKind::Type => {
                     let qual = i_slint_compiler::object_tree::QualifiedTypeName::from_node(n);
                     let doc = document_cache.documents.get_document(node.source_file.path())?;
-                    match doc.local_registry.lookup_qualified(&qual.members) {
+                    match doc.local_registry.borrow().lookup_qualified(&qual.members) {
                         Type::Struct { node: Some(node), .. } => goto_node(node.parent().as_ref()?),
                         Type::Enumeration(e) => goto_node(e.node.as_ref()?),
                         _ => None,
@@ -36,7 +36,7 @@ pub fn goto_definition(
                 SyntaxKind::Element => {
                     let qual = i_slint_compiler::object_tree::QualifiedTypeName::from_node(n);
                     let doc = document_cache.documents.get_document(node.source_file.path())?;
-                    match doc.local_registry.lookup_element(&qual.to_string()) {
+                    match doc.local_registry.borrow().lookup_element(&qual.to_string()) {
                         Ok(ElementType::Component(c)) => {
                             goto_node(&*c.root_element.borrow().node.as_ref()?)
                         }
@@ -107,7 +107,7 @@ pub fn goto_definition(
         } else if let Some(n) = syntax_nodes::ImportIdentifier::new(node.clone()) {
             let doc = document_cache.documents.get_document(node.source_file.path())?;
             let imp_name = i_slint_compiler::typeloader::ImportedName::from_node(n);
-            return match doc.local_registry.lookup_element(&imp_name.internal_name) {
+            return match doc.local_registry.borrow().lookup_element(&imp_name.internal_name) {
                 Ok(ElementType::Component(c)) => {
                     goto_node(&*c.root_element.borrow().node.as_ref()?)
                 }
@@ -190,10 +190,10 @@ fn find_property_declaration_in_base(
     let tr = element
         .source_file()
         .and_then(|sf| document_cache.documents.get_document(sf.path()))
-        .map(|doc| &doc.local_registry)
-        .unwrap_or(&global_tr);
+        .map(|doc| doc.local_registry.borrow())
+        .unwrap_or(global_tr);
 
-    let mut element_type = crate::util::lookup_current_element_type((*element).clone(), tr)?;
+    let mut element_type = crate::util::lookup_current_element_type((*element).clone(), &tr)?;
     while let ElementType::Component(com) = element_type {
         if let Some(p) = com.root_element.borrow().property_declarations.get(prop_name) {
             return p.node.clone();
@@ -67,20 +67,26 @@ pub fn get_semantic_tokens(
                     SyntaxKind::Expression => None,
                     SyntaxKind::StatePropertyChange => Some((self::PROPERTY, 0)),
                     SyntaxKind::PropertyAnimation => Some((self::PROPERTY, 0)),
+                    SyntaxKind::MatchArm => Some((self::ENUM_MEMBER, 0)),
                     _ => None,
                 },
                 SyntaxKind::DeclaredIdentifier => {
                     match token.parent().parent()?.kind() {
-                        SyntaxKind::Component => Some((self::TYPE, 1 << self::DEFINITION)),
+                        SyntaxKind::Component | SyntaxKind::GenericParameters => {
+                            Some((self::TYPE, 1 << self::DEFINITION))
+                        }
                         SyntaxKind::RepeatedElement => {
                             Some((self::PROPERTY, 1 << self::DEFINITION))
                         }
                         SyntaxKind::CallbackDeclaration => {
                             Some((self::FUNCTION, 1 << self::DEFINITION))
                         }
-                        SyntaxKind::CallbackConnection => {
+                        SyntaxKind::CallbackConnection | SyntaxKind::Lambda => {
                             Some((self::PARAMETER, 1 << self::DEFINITION))
                         }
+                        SyntaxKind::LetStatement | SyntaxKind::ForStatement => {
+                            Some((self::VARIABLE, 1 << self::DEFINITION))
+                        }
                         SyntaxKind::PropertyDeclaration => {
                             Some((self::PROPERTY, 1 << self::DEFINITION))
                         }
@@ -95,11 +101,15 @@ pub fn get_semantic_tokens(
                 }
                 SyntaxKind::ChildrenPlaceholder => Some((self::MACRO, 0)),
                 SyntaxKind::Binding | SyntaxKind::TwoWayBinding => Some((self::PROPERTY, 0)),
-                SyntaxKind::ReturnStatement => Some((self::KEYWORD, 0)),
+                SyntaxKind::ReturnStatement
+                | SyntaxKind::LetStatement
+                | SyntaxKind::ForStatement
+                | SyntaxKind::WhileStatement => Some((self::KEYWORD, 0)),
                 SyntaxKind::AtImageUrl => Some((self::MACRO, 0)),
                 SyntaxKind::AtGradient => Some((self::MACRO, 0)),
                 SyntaxKind::AtTr => Some((self::MACRO, 0)),
                 SyntaxKind::ConditionalExpression => Some((self::KEYWORD, 0)),
+                SyntaxKind::MatchExpression => Some((self::KEYWORD, 0)),
                 SyntaxKind::ObjectMember => Some((self::PROPERTY, 1 << self::DECLARATION)),
                 SyntaxKind::States => Some((self::KEYWORD, 0)),
                 SyntaxKind::State => Some((self::KEYWORD, 0)),
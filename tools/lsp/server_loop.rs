@@ -1048,6 +1048,7 @@ fn get_document_symbols(
 
     let mut r = inner_components
         .iter()
+        .filter(|c| c.generic_instance.is_none())
         .filter_map(|c| {
             let root_element = c.root_element.borrow();
             let element_node = root_element.node.as_ref()?;
@@ -1137,7 +1138,11 @@ fn get_code_lenses(
         let mut r = vec![];
 
         // Handle preview lens
-        r.extend(inner_components.iter().filter(|c| !c.is_global()).filter_map(|c| {
+        // Generic components and their instances cannot be previewed
+        let previewable = inner_components
+            .iter()
+            .filter(|c| !c.is_global() && c.generic.is_none() && c.generic_instance.is_none());
+        r.extend(previewable.filter_map(|c| {
             Some(CodeLens {
                 range: map_node(c.root_element.borrow().node.as_ref()?)?,
                 command: Some(create_show_preview_command(true, uri, c.id.as_str())),
@@ -108,8 +108,8 @@ pub fn with_property_lookup_ctx<R>(
     let tr = element
         .source_file()
         .and_then(|sf| document_cache.documents.get_document(sf.path()))
-        .map(|doc| &doc.local_registry)
-        .unwrap_or(&global_tr);
+        .map(|doc| doc.local_registry.borrow())
+        .unwrap_or(global_tr);
 
     let component = {
         let mut n = element.parent()?;
@@ -146,11 +146,11 @@ pub fn with_property_lookup_ctx<R>(
                 .then(|| p)
         })
         .and_then(|p| p.Type())
-        .map(|n| object_tree::type_from_node(n, &mut Default::default(), tr))
+        .map(|n| object_tree::type_from_node(n, &mut Default::default(), &tr))
         .or_else(|| scope.last().map(|e| e.borrow().lookup_property(prop_name).property_type));
 
     let mut build_diagnostics = Default::default();
-    let mut lookup_context = LookupCtx::empty_context(tr, &mut build_diagnostics);
+    let mut lookup_context = LookupCtx::empty_context(&tr, &mut build_diagnostics);
     lookup_context.property_name = Some(prop_name);
     lookup_context.property_type = ty.unwrap_or_default();
     lookup_context.component_scope = &scope;
@@ -258,8 +258,8 @@ pub(crate) fn with_lookup_ctx<R>(
     f: impl FnOnce(&mut LookupCtx) -> R,
 ) -> Option<R> {
     let mut build_diagnostics = Default::default();
-    let tr = &state.current_doc.as_ref()?.local_registry;
-    let mut lookup_context = LookupCtx::empty_context(tr, &mut build_diagnostics);
+    let tr = state.current_doc.as_ref()?.local_registry.borrow();
+    let mut lookup_context = LookupCtx::empty_context(&tr, &mut build_diagnostics);
 
     let ty = state
         .current_elem